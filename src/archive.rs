@@ -0,0 +1,89 @@
+//! Moves articles out of the articles database into a separate archive, keeping the articles
+//! list lean while archived articles can still be searched
+//!
+//! Archived articles are not added back by later refreshes (their links are checked against
+//! the archive, see [`crate::store_fetched_feeds`]), and keep their article id, which is never
+//! assigned again.
+//!
+
+use crate::config::data_path;
+use crate::db_writer::{lock_db_writes, write_db_file};
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::html::clean_html;
+use crate::{read_articles_db, Articles, ARTICLE_DB_FILE};
+use std::fs;
+use std::path::Path;
+
+/// JSON file name for archived articles, in the data folder (`data_dir` in config)
+pub const ARCHIVE_DB_FILE: &str = "archive_db.json";
+
+/// Reads the archived articles from JSON files, none until the first article is archived
+pub fn read_archive_db() -> Vec<Articles> {
+    if !Path::new(&data_path(ARCHIVE_DB_FILE)).exists() {
+        return Vec::new();
+    }
+
+    let db_content = fs::read_to_string(data_path(ARCHIVE_DB_FILE)).unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+
+    let parsed: Vec<Articles> = serde_json::from_str(&db_content).unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+    parsed
+}
+
+/// Archives given article, returning whether it was found
+pub fn archive_article(article_id: usize) -> bool {
+    move_to_archive(|r| r.article_id == article_id) > 0
+}
+
+/// Archives the read articles of given RSS feed, returning the number of articles archived
+///
+/// Bookmarked articles are left in the articles list.
+pub fn archive_read_articles(rss_id: usize) -> usize {
+    move_to_archive(|r| r.rss_id == rss_id && r.read && !r.bookmarked)
+}
+
+/// Moves the articles matching given filter from the articles database to the archive,
+/// returning the number of articles moved
+fn move_to_archive<F: Fn(&Articles) -> bool>(filter: F) -> usize {
+    let _db_lock = lock_db_writes();
+    let (archived, articles_list): (Vec<Articles>, Vec<Articles>) =
+        read_articles_db().into_iter().partition(|r| filter(r));
+    if archived.is_empty() {
+        return 0;
+    }
+
+    let mut archive_list = read_archive_db();
+    let num_archived = archived.len();
+    for article in archived {
+        if !archive_list
+            .iter()
+            .any(|r| r.article_link == article.article_link)
+        {
+            archive_list.push(article);
+        }
+    }
+
+    write_db_file(&data_path(ARCHIVE_DB_FILE), &archive_list);
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    num_archived
+}
+
+/// Searches the archived articles whose title or summary contains given text, ignoring case,
+/// most recent first
+pub fn search_archive(query: &str) -> Vec<Articles> {
+    let query = query.trim().to_lowercase();
+    let mut archive_list: Vec<Articles> = read_archive_db()
+        .into_iter()
+        .filter(|r| {
+            r.title.to_lowercase().contains(&query)
+                || clean_html(&r.summary).to_lowercase().contains(&query)
+        })
+        .collect();
+    archive_list.sort_by_key(|r| std::cmp::Reverse(r.pub_date));
+    archive_list
+}
@@ -0,0 +1,70 @@
+//! Opens article links in the default web browser of the host machine, and `mailto:` links
+//! in its default mail client
+//!
+
+use crate::config::get_config;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::{is_valid_feed_url, mark_articles_read, Articles};
+use std::process::{Command, Stdio};
+
+/// Opens given URL in the default web browser
+pub fn open_in_browser(url: &str) -> Result<(), ErrorMessages> {
+    if !is_valid_feed_url(url) {
+        return Err(ErrorMessages::new(ErrorCodes::E0033_BROWSER_OPEN_FAILURE));
+    }
+    open_with_default_app(url.trim())
+}
+
+/// Opens given `mailto:` URL in the default mail client
+pub fn open_in_mail_client(mailto_url: &str) -> Result<(), ErrorMessages> {
+    if !mailto_url.starts_with("mailto:") {
+        return Err(ErrorMessages::new(ErrorCodes::E0033_BROWSER_OPEN_FAILURE));
+    }
+    open_with_default_app(mailto_url)
+}
+
+/// Hands given URL over to the application registered for it on the host machine
+fn open_with_default_app(url: &str) -> Result<(), ErrorMessages> {
+    let mut command = if cfg!(target_os = "windows") {
+        Command::new("explorer")
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else {
+        Command::new("xdg-open")
+    };
+
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_child| ())
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0033_BROWSER_OPEN_FAILURE))
+}
+
+/// Opens the links of given articles in the default web browser, and marks them as read unless
+/// `mark_read_on_open` is turned off
+///
+/// Returns the number of articles opened, along with the last error met, if any.
+pub fn open_articles_in_browser(articles: &[Articles]) -> (usize, Option<ErrorMessages>) {
+    let mut opened_article_ids: Vec<usize> = Vec::new();
+    let mut last_error: Option<ErrorMessages> = None;
+
+    for article in articles.iter() {
+        match open_in_browser(&article.article_link) {
+            Ok(()) => opened_article_ids.push(article.article_id),
+            Err(err_msg) => last_error = Some(err_msg),
+        }
+    }
+
+    mark_opened_articles_read(&opened_article_ids);
+    (opened_article_ids.len(), last_error)
+}
+
+/// Marks given articles, just opened, as read unless `mark_read_on_open` is turned off
+pub fn mark_opened_articles_read(article_ids: &[usize]) {
+    if get_config().mark_read_on_open {
+        mark_articles_read(article_ids);
+    }
+}
@@ -0,0 +1,130 @@
+//! Parses the command line arguments passed to the application
+//!
+
+use byte_bite::error_db::{ErrorCodes, ErrorMessages};
+
+const USAGE: &str = "Usage: byte_bite [--config <file>] [--serve <port> [--headless]] [--import-opml <file>] [--export-opml <file>] [--add-feeds <file>] [--import-newsboat <file>] [--export-history <file>] [--sequential-fetch] [--check-db] [--repair-db] [--reset-db]
+
+Options:
+  --config <file>          Read the configuration from given TOML file
+  --serve <port>           Serve feeds and articles as read-only JSON over HTTP (requires the `serve` feature)
+  --headless               Run only the HTTP server, without the terminal UI
+  --import-opml <file>     Import RSS feeds from an OPML file, refresh them and exit
+  --export-opml <file>     Export RSS feeds to an OPML file and exit
+  --add-feeds <file>       Add the RSS feeds listed in a text file (\"-\" for stdin), one \"category|name|url\" or \"url\" per line, and exit
+  --import-newsboat <file> Add the RSS feeds listed in a Newsboat \"urls\" file (first tag as category), and exit
+  --export-history <file>  Export the read history (viewed articles) to a CSV file, or JSON file if ending in .json, and exit
+  --sequential-fetch       Fetch RSS feeds one at a time with each fetch logged (DEBUG level), for troubleshooting
+  --check-db               Report the articles whose RSS feed no longer exists, and exit
+  --repair-db              Remove the articles whose RSS feed no longer exists, and exit
+  --reset-db               Back up, then clear all RSS feeds and articles (asks for confirmation), and exit
+  --help                   Print this help message";
+
+/// Defines the options passed to the application on the command line
+pub struct CliArgs {
+    /// Config file to read the configuration from, instead of searching for one
+    pub config_path: Option<String>,
+    /// Port to serve the read-only HTTP/JSON endpoints on
+    pub serve_port: Option<u16>,
+    /// Run only the HTTP server, without the terminal UI
+    pub headless: bool,
+    /// OPML file to import RSS feeds from
+    pub import_opml: Option<String>,
+    /// OPML file to export RSS feeds to
+    pub export_opml: Option<String>,
+    /// Text file listing RSS feeds to add ("-" for stdin)
+    pub add_feeds: Option<String>,
+    /// Newsboat `urls` file listing RSS feeds to add
+    pub import_newsboat: Option<String>,
+    /// CSV or JSON file to export the read history to
+    pub export_history: Option<String>,
+    /// Fetch RSS feeds one at a time with each fetch logged
+    pub sequential_fetch: bool,
+    /// Report the orphaned articles, removing them if repair is set
+    pub check_db: Option<bool>,
+    /// Back up, then clear all RSS feeds and articles
+    pub reset_db: bool,
+}
+
+/// Parses the command line arguments, exiting the process on invalid input
+pub fn parse_args() -> CliArgs {
+    let mut cli_args = CliArgs {
+        config_path: None,
+        serve_port: None,
+        headless: false,
+        import_opml: None,
+        export_opml: None,
+        add_feeds: None,
+        import_newsboat: None,
+        export_history: None,
+        sequential_fetch: false,
+        check_db: None,
+        reset_db: false,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--serve" => {
+                let port = args.next().and_then(|p| p.parse::<u16>().ok());
+                match port {
+                    Some(t) => cli_args.serve_port = Some(t),
+                    None => exit_with_usage(),
+                }
+            }
+            "--config" => match args.next() {
+                Some(file_path) => cli_args.config_path = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--headless" => cli_args.headless = true,
+            "--import-opml" => match args.next() {
+                Some(file_path) => cli_args.import_opml = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--export-opml" => match args.next() {
+                Some(file_path) => cli_args.export_opml = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--add-feeds" => match args.next() {
+                Some(file_path) => cli_args.add_feeds = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--import-newsboat" => match args.next() {
+                Some(file_path) => cli_args.import_newsboat = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--export-history" => match args.next() {
+                Some(file_path) => cli_args.export_history = Some(file_path),
+                None => exit_with_usage(),
+            },
+            "--sequential-fetch" => cli_args.sequential_fetch = true,
+            "--check-db" => cli_args.check_db = Some(false),
+            "--repair-db" => cli_args.check_db = Some(true),
+            "--reset-db" => cli_args.reset_db = true,
+            "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => exit_with_usage(),
+        }
+    }
+
+    if cli_args.headless && cli_args.serve_port.is_none() {
+        exit_with_usage();
+    }
+
+    if cfg!(not(feature = "serve")) && cli_args.serve_port.is_some() {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0026_SERVE_FEATURE_DISABLED);
+        eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+        std::process::exit(2);
+    }
+
+    cli_args
+}
+
+fn exit_with_usage() -> ! {
+    let err_msg = ErrorMessages::new(ErrorCodes::E0024_CLI_ARGUMENT_PARSE_FAILURE);
+    eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    eprintln!("{}", USAGE);
+    std::process::exit(2);
+}
@@ -0,0 +1,25 @@
+//! Copies text into and reads text from the system clipboard
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use arboard::Clipboard;
+
+/// Copies given text into the system clipboard
+pub fn copy_to_clipboard(text: &str) -> Result<(), ErrorMessages> {
+    let mut clipboard = Clipboard::new()
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0028_CLIPBOARD_WRITE_FAILURE))?;
+
+    clipboard
+        .set_text(text.to_string())
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0028_CLIPBOARD_WRITE_FAILURE))
+}
+
+/// Reads the text currently held in the system clipboard
+pub fn read_from_clipboard() -> Result<String, ErrorMessages> {
+    let mut clipboard = Clipboard::new()
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0046_CLIPBOARD_READ_FAILURE))?;
+
+    clipboard
+        .get_text()
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0046_CLIPBOARD_READ_FAILURE))
+}
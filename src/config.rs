@@ -0,0 +1,397 @@
+//! Defines the user configurable settings of the application and the helpers to load them
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::theme::{Theme, COLOR_NAMES, MODIFIER_NAMES};
+use crate::RSSFeed;
+use chrono::format::{Item, StrftimeItems};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// JSON file path for application configuration, used when no TOML config file is found
+pub const CONFIG_PATH: &str = "config/config.json";
+
+/// TOML config file name, looked up in the config search paths
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+/// Defines the columns which can be shown in the articles pane
+pub enum ArticleColumn {
+    /// Publication date of the article
+    Date,
+    /// Name of the RSS feed the article comes from
+    Source,
+    /// Title of the article
+    Title,
+}
+
+impl ArticleColumn {
+    /// Returns the name of the column shown in the header of the articles pane
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArticleColumn::Date => "Date",
+            ArticleColumn::Source => "Source",
+            ArticleColumn::Title => "Title",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+/// Defines the TLS implementations used for HTTPS requests
+pub enum TlsBackend {
+    /// TLS library of the operating system (SChannel, Secure Transport, OpenSSL)
+    #[default]
+    Native,
+    /// Rust TLS implementation (requires the "rustls" feature)
+    Rustls,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+/// Defines the article counts shown next to each RSS feed in the RSS feeds list
+pub enum FeedCountFormat {
+    /// Number of unread articles (e.g. "12"), left out when there are none
+    #[default]
+    Unread,
+    /// Number of articles (e.g. "340")
+    Total,
+    /// Number of unread articles and number of articles (e.g. "12/340")
+    UnreadTotal,
+}
+
+/// TLS versions accepted for `min_tls_version`
+pub const TLS_VERSIONS: [&str; 4] = ["1.0", "1.1", "1.2", "1.3"];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+/// Defines the metadata for storing application configuration
+pub struct Config {
+    /// `chrono` format string used for rendering timestamps (e.g. "%Y-%m-%d %H:%M")
+    pub date_format: Option<String>,
+    /// Extra `chrono` format strings tried, in order, for the non-standard publishing dates of RSS feeds
+    pub date_formats: Vec<String>,
+    /// Render timestamps in the local timezone instead of UTC
+    pub use_local_timezone: bool,
+    /// Show hidden articles in the articles list (useful for debugging)
+    pub show_hidden_articles: bool,
+    /// Directory where articles are exported as Markdown files
+    pub export_dir: String,
+    /// Directory of the JSON files (RSS feeds, articles, archive, display preferences, ...)
+    pub data_dir: String,
+    /// Log file written by the application and shown in the log viewer
+    pub log_file: String,
+    /// Maximum number of RSS feeds fetched at the same time (refresh all, OPML import)
+    pub max_concurrency: usize,
+    /// Minimum delay in milliseconds between two requests to the same host (0 to disable)
+    pub host_request_delay_ms: u64,
+    /// Fetch the RSS feeds one at a time with each fetch logged, for troubleshooting refreshes
+    pub sequential_fetch: bool,
+    /// Maximum size in megabytes of a response body, larger responses being rejected
+    pub max_response_size_mb: u64,
+    /// TLS implementation used for HTTPS requests
+    pub tls_backend: TlsBackend,
+    /// Minimum TLS version accepted for HTTPS requests (e.g. "1.2"), the TLS backend default if not set
+    pub min_tls_version: Option<String>,
+    /// Number of browser tabs opened at once without asking for confirmation
+    pub max_open_tabs: usize,
+    /// Mark articles as read when opening them in the browser
+    pub mark_read_on_open: bool,
+    /// Columns shown in the articles pane, in order
+    pub article_columns: Vec<ArticleColumn>,
+    /// Rename RSS feeds after their channel title when it changes (e.g. site rebrand)
+    pub auto_rename_feeds: bool,
+    /// Remove tracking query parameters (e.g. `utm_source`) from the displayed article links
+    pub strip_tracking_params: bool,
+    /// Remove tracking query parameters from the links of new articles before storing them
+    pub strip_tracking_params_on_store: bool,
+    /// Maximum length of the displayed article links, longer links are elided in the middle (0 to disable)
+    pub max_link_display_length: usize,
+    /// Maximum number of articles stored across all RSS feeds, oldest are evicted first (0 for no limit)
+    pub max_articles: usize,
+    /// Show a desktop notification when refreshes find new articles (requires the "notifications" feature)
+    pub desktop_notifications: bool,
+    /// Maximum number of desktop notifications shown per refresh, the remaining RSS feeds are summarized
+    pub max_notifications_per_refresh: usize,
+    /// JSON file of named secrets, referenced as `${NAME}` in the request headers of RSS feeds
+    pub secrets_file: String,
+    /// Read-only JSON file of base RSS feeds (same format as the RSS feeds database), layered
+    /// under the RSS feeds database for packaged installs (see [`crate::feed_layers`])
+    pub base_feeds_file: Option<String>,
+    /// Show the details of the selected RSS feed in the summary pane while browsing the RSS feeds list
+    pub show_feed_details_on_focus: bool,
+    /// Keywords muting the articles of all the RSS feeds which mention them
+    pub muted_keywords: Vec<String>,
+    /// Keywords flagging the articles of all the RSS feeds which mention them
+    pub alert_keywords: Vec<String>,
+    /// Show a desktop notification for new articles matching the alert keywords
+    pub alert_notifications: bool,
+    /// Match muted and alert keywords as whole words only, instead of anywhere in the text
+    pub whole_word_keywords: bool,
+    /// Show the license line in the footer when there's no status message, or else only show the
+    /// footer while there's a status message and give its rows to the RSS feeds and articles
+    pub show_footer: bool,
+    /// Go from the last RSS feed back to the first one (and the other way round) when jumping to
+    /// the next RSS feed with unread articles
+    pub wrap_feed_navigation: bool,
+    /// Show the publishing date (and viewing date) line in the summary of articles
+    pub show_summary_date: bool,
+    /// Show the link line in the summary of articles
+    pub show_summary_link: bool,
+    /// Show the legend of the styling conventions in the footer, instead of the license line
+    pub show_legend: bool,
+    /// Article counts shown next to each RSS feed in the RSS feeds list
+    pub feed_count_format: FeedCountFormat,
+    /// Colors and markers of the RSS feeds and articles lists
+    pub theme: Theme,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            date_format: None,
+            date_formats: Vec::new(),
+            use_local_timezone: false,
+            show_hidden_articles: false,
+            export_dir: String::from("exports"),
+            data_dir: String::from("data"),
+            log_file: String::from("logs/byte_bite.log"),
+            max_concurrency: 8,
+            host_request_delay_ms: 1000,
+            sequential_fetch: false,
+            max_response_size_mb: 20,
+            tls_backend: TlsBackend::Native,
+            min_tls_version: None,
+            max_open_tabs: 10,
+            mark_read_on_open: true,
+            article_columns: vec![
+                ArticleColumn::Date,
+                ArticleColumn::Source,
+                ArticleColumn::Title,
+            ],
+            auto_rename_feeds: false,
+            strip_tracking_params: false,
+            strip_tracking_params_on_store: false,
+            max_link_display_length: 100,
+            max_articles: 0,
+            desktop_notifications: false,
+            max_notifications_per_refresh: 3,
+            secrets_file: String::from("config/secrets.json"),
+            base_feeds_file: None,
+            show_feed_details_on_focus: false,
+            muted_keywords: Vec::new(),
+            alert_keywords: Vec::new(),
+            alert_notifications: false,
+            whole_word_keywords: false,
+            show_footer: true,
+            wrap_feed_navigation: true,
+            show_summary_date: true,
+            show_summary_link: true,
+            show_legend: false,
+            feed_count_format: FeedCountFormat::Unread,
+            theme: Theme::default(),
+        }
+    }
+}
+
+/// Returns the application configuration, loading it from disk on first use
+pub fn get_config() -> &'static Config {
+    CONFIG.get_or_init(|| read_config(find_config_file().as_deref()))
+}
+
+/// Returns the path of given file of the data folder (`data_dir` in config)
+pub fn data_path(file_name: &str) -> String {
+    Path::new(&get_config().data_dir)
+        .join(file_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Loads the application configuration once at startup, from given file or else from the
+/// first file found in the config search paths
+///
+/// Returns an error if given config file doesn't exist or can't be read or parsed. Config
+/// files of the search paths which can't be read or parsed are reported, and fall back to the
+/// default configuration, as missing ones do.
+pub fn load_config(config_path: Option<&str>) -> Result<&'static Config, ErrorMessages> {
+    let config = match config_path {
+        Some(t) => {
+            if !Path::new(t).is_file() {
+                return Err(ErrorMessages::new(ErrorCodes::E0038_CONFIG_FILE_NOT_FOUND));
+            }
+            parse_config_file(Path::new(t))?
+        }
+        None => read_config(find_config_file().as_deref()),
+    };
+
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Lists the config files searched, in order, when no config file is given on the command line:
+/// `$XDG_CONFIG_HOME/byte_bite/config.toml`, `~/.config/byte_bite/config.toml`, `./config.toml`
+/// and finally the legacy JSON config file
+pub fn config_search_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(t) = std::env::var_os("XDG_CONFIG_HOME").filter(|t| !t.is_empty()) {
+        paths.push(PathBuf::from(t).join("byte_bite").join(CONFIG_FILE_NAME));
+    }
+    if let Some(t) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+        paths.push(
+            PathBuf::from(t)
+                .join(".config")
+                .join("byte_bite")
+                .join(CONFIG_FILE_NAME),
+        );
+    }
+    paths.push(PathBuf::from(CONFIG_FILE_NAME));
+    paths.push(PathBuf::from(CONFIG_PATH));
+
+    paths
+}
+
+/// Finds the first existing file in the config search paths
+fn find_config_file() -> Option<PathBuf> {
+    config_search_paths().into_iter().find(|t| t.is_file())
+}
+
+/// Reads the application configuration from given TOML (or JSON) file, falling back to defaults
+fn read_config(config_path: Option<&Path>) -> Config {
+    match config_path {
+        Some(t) => parse_config_file(t).unwrap_or_default(),
+        None => Config::default(),
+    }
+}
+
+/// Reads and validates the application configuration from given TOML (or JSON) file, reporting
+/// the reason when it can't be read or parsed
+fn parse_config_file(config_path: &Path) -> Result<Config, ErrorMessages> {
+    let report_failure = |error_code: ErrorCodes, reason: &str| {
+        let err_msg = ErrorMessages::new(error_code);
+        eprintln!(
+            "{:?} - {} ({}: {})",
+            err_msg.error_code,
+            err_msg.error_message,
+            config_path.display(),
+            reason.trim()
+        );
+        err_msg
+    };
+
+    let db_content = fs::read_to_string(config_path).map_err(|err| {
+        report_failure(ErrorCodes::E0051_CONFIG_FILE_READ_FAILURE, &err.to_string())
+    })?;
+
+    let parsed_config = if config_path.extension().is_some_and(|t| t == "json") {
+        serde_json::from_str::<Config>(&db_content).map_err(|err| err.to_string())
+    } else {
+        toml::from_str::<Config>(&db_content).map_err(|err| err.to_string())
+    };
+
+    let mut config = parsed_config
+        .map_err(|err| report_failure(ErrorCodes::E0022_CONFIG_PARSE_FAILURE, &err))?;
+
+    validate_config(&mut config, config_path);
+    Ok(config)
+}
+
+/// Resets invalid settings of the application configuration to their defaults, reporting them
+fn validate_config(config: &mut Config, config_path: &Path) {
+    let default_config = Config::default();
+    let report = |setting: &str| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0039_INVALID_CONFIG_VALUE);
+        eprintln!(
+            "{:?} - {} ({}: {})",
+            err_msg.error_code,
+            err_msg.error_message,
+            config_path.display(),
+            setting
+        );
+    };
+
+    if let Some(date_format) = &config.date_format {
+        if !is_valid_date_format(date_format) {
+            let err_msg = ErrorMessages::new(ErrorCodes::E0023_DATE_FORMAT_PARSE_FAILURE);
+            eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+            config.date_format = None;
+        }
+    }
+    config.date_formats.retain(|date_format| {
+        let is_valid = is_valid_date_format(date_format);
+        if !is_valid {
+            report(&format!(
+                "date_formats entry \"{}\" is not a valid format",
+                date_format
+            ));
+        }
+        is_valid
+    });
+    if let Some(min_tls_version) = &config.min_tls_version {
+        if !TLS_VERSIONS.contains(&min_tls_version.as_str()) {
+            report(&format!(
+                "min_tls_version must be one of {}",
+                TLS_VERSIONS.join(", ")
+            ));
+            config.min_tls_version = None;
+        }
+    }
+    if config.max_concurrency == 0 {
+        report("max_concurrency must be at least 1");
+        config.max_concurrency = default_config.max_concurrency;
+    }
+    if config.max_response_size_mb == 0 {
+        report("max_response_size_mb must be at least 1");
+        config.max_response_size_mb = default_config.max_response_size_mb;
+    }
+    for setting in config.theme.invalid_colors() {
+        report(&format!(
+            "theme {} must be one of {} or \"#rrggbb\"",
+            setting,
+            COLOR_NAMES.join(", ")
+        ));
+        let default_theme = Theme::default();
+        match setting {
+            "unread_color" => config.theme.unread_color = default_theme.unread_color,
+            "read_color" => config.theme.read_color = default_theme.read_color,
+            "highlight_fg" => config.theme.highlight_fg = default_theme.highlight_fg,
+            "highlight_bg" => config.theme.highlight_bg = default_theme.highlight_bg,
+            _ => config.theme.alert_color = default_theme.alert_color,
+        }
+    }
+    let invalid_modifiers = config.theme.invalid_modifiers();
+    if !invalid_modifiers.is_empty() {
+        report(&format!(
+            "theme highlight_modifiers \"{}\" must be among {}",
+            invalid_modifiers.join("\", \""),
+            MODIFIER_NAMES.join(", ")
+        ));
+        config.theme.highlight_modifiers = Theme::default().highlight_modifiers;
+    }
+    if config.article_columns.is_empty() {
+        report("article_columns must list at least one column");
+        config.article_columns = default_config.article_columns;
+    }
+    if let Some(base_feeds_file) = &config.base_feeds_file {
+        let is_valid = fs::read_to_string(base_feeds_file)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Vec<RSSFeed>>(&content).ok())
+            .is_some();
+        if !is_valid {
+            report(&format!(
+                "base_feeds_file \"{}\" must be a JSON list of RSS feeds",
+                base_feeds_file
+            ));
+            config.base_feeds_file = None;
+        }
+    }
+}
+
+/// Checks whether the given `chrono` format string can be used for formatting
+fn is_valid_date_format(date_format: &str) -> bool {
+    !StrftimeItems::new(date_format).any(|item| matches!(item, Item::Error))
+}
@@ -0,0 +1,69 @@
+//! Checks that the articles database agrees with the RSS feeds database, and repairs it
+//!
+//! Articles referencing an RSS feed which no longer exists (e.g. after editing
+//! "data/rss_db.json" by hand) are orphaned: they are never shown in the terminal UI, but still
+//! take up space in the articles database. Repairing removes them.
+//!
+
+use crate::config::data_path;
+use crate::db_writer::{lock_db_writes, write_db_file};
+use crate::{read_articles_db, read_rss_db, Articles, ARTICLE_DB_FILE};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug, Default)]
+/// Defines the outcome of a consistency check
+pub struct ConsistencyReport {
+    /// Number of articles whose RSS feed no longer exists
+    pub num_orphaned: usize,
+    /// RSS ids referenced by the orphaned articles
+    pub missing_rss_ids: BTreeSet<usize>,
+    /// Number of orphaned articles removed from the articles database
+    pub num_removed: usize,
+}
+
+impl ConsistencyReport {
+    /// Returns a one line summary of the check, e.g. "Found 12 orphaned articles (RSS ids 4, 9),
+    /// removed 12"
+    pub fn summary(&self) -> String {
+        if self.num_orphaned == 0 {
+            return String::from("Found no orphaned articles, the databases agree");
+        }
+
+        let missing_rss_ids: Vec<String> = self
+            .missing_rss_ids
+            .iter()
+            .map(|rss_id| rss_id.to_string())
+            .collect();
+        let outcome = match self.num_removed {
+            0 => String::from("none removed"),
+            num_removed => format!("removed {}", num_removed),
+        };
+        format!(
+            "Found {} orphaned articles (RSS ids {}), {}",
+            self.num_orphaned,
+            missing_rss_ids.join(", "),
+            outcome
+        )
+    }
+}
+
+/// Finds the articles whose RSS feed no longer exists, removing them when `repair` is set
+pub fn check_db_consistency(repair: bool) -> ConsistencyReport {
+    let _db_lock = lock_db_writes();
+    let rss_ids: BTreeSet<usize> = read_rss_db().iter().map(|r| r.rss_id).collect();
+    let (orphaned, articles_list): (Vec<Articles>, Vec<Articles>) = read_articles_db()
+        .into_iter()
+        .partition(|r| !rss_ids.contains(&r.rss_id));
+
+    let mut report = ConsistencyReport {
+        num_orphaned: orphaned.len(),
+        missing_rss_ids: orphaned.iter().map(|r| r.rss_id).collect(),
+        num_removed: 0,
+    };
+
+    if repair && !orphaned.is_empty() {
+        write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+        report.num_removed = orphaned.len();
+    }
+    report
+}
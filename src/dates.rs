@@ -0,0 +1,140 @@
+//! Parses the publishing dates of RSS articles, including the non-standard formats some feeds use
+//!
+
+use crate::config::get_config;
+use chrono::prelude::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use log::debug;
+
+/// Formats tried in order when a date is neither RFC 2822 nor RFC 3339, before the formats
+/// added in config (`date_formats`). Dates without a timezone are read as UTC, and dates
+/// without a time as midnight.
+const KNOWN_DATE_FORMATS: [&str; 13] = [
+    "%Y-%m-%d %H:%M:%S %z",
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%a, %d %b %Y %H:%M %z",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%a, %d %b %Y %H:%M:%S",
+    "%d %b %Y %H:%M:%S",
+    "%Y/%m/%d %H:%M:%S",
+    "%Y-%m-%d",
+    "%a, %d %b %Y",
+    "%d %b %Y",
+    "%B %d, %Y",
+];
+
+/// Parses the publishing date of an RSS article, falling back to the current time
+///
+/// RFC 2822 (the RSS standard) and RFC 3339 (Atom) are tried first, then the known date
+/// formats, then the formats added in config.
+pub fn parse_pub_date(raw_date: &str) -> DateTime<Utc> {
+    let raw_date = raw_date.trim();
+    if raw_date.is_empty() {
+        return Utc::now();
+    }
+
+    if let Ok(t) = DateTime::parse_from_rfc2822(raw_date) {
+        return t.with_timezone(&Utc);
+    }
+    if let Ok(t) = DateTime::parse_from_rfc3339(raw_date) {
+        return t.with_timezone(&Utc);
+    }
+
+    let user_formats = get_config().date_formats.iter().map(|t| t.as_str());
+    for date_format in KNOWN_DATE_FORMATS.into_iter().chain(user_formats) {
+        if let Some(t) = parse_with_format(raw_date, date_format) {
+            debug!(
+                "Parsed date \"{}\" with format \"{}\"",
+                raw_date, date_format
+            );
+            return t;
+        }
+    }
+
+    debug!(
+        "Unable to parse date \"{}\", using the current time",
+        raw_date
+    );
+    Utc::now()
+}
+
+/// Parses a date with given `chrono` format, with or without timezone and time
+fn parse_with_format(raw_date: &str, date_format: &str) -> Option<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_str(raw_date, date_format) {
+        return Some(t.with_timezone(&Utc));
+    }
+    if let Ok(t) = NaiveDateTime::parse_from_str(raw_date, date_format) {
+        return Some(t.and_utc());
+    }
+    NaiveDate::parse_from_str(raw_date, date_format)
+        .ok()
+        .and_then(|t| t.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parses_standard_dates() {
+        assert_eq!(
+            parse_pub_date("Tue, 02 Jan 2024 15:04:05 GMT"),
+            utc("2024-01-02T15:04:05Z")
+        );
+        assert_eq!(
+            parse_pub_date("2024-01-02T15:04:05+02:00"),
+            utc("2024-01-02T13:04:05Z")
+        );
+    }
+
+    #[test]
+    fn parses_non_standard_dates() {
+        let dates = [
+            ("2024-01-02 15:04:05", "2024-01-02T15:04:05Z"),
+            ("2024-01-02 15:04:05 +0100", "2024-01-02T14:04:05Z"),
+            ("2024-01-02T15:04:05.123", "2024-01-02T15:04:05.123Z"),
+            ("2024-01-02 15:04", "2024-01-02T15:04:00Z"),
+            ("Tue, 02 Jan 2024 15:04 +0000", "2024-01-02T15:04:00Z"),
+            ("Tue, 2 Jan 2024 15:04:05", "2024-01-02T15:04:05Z"),
+            ("02 Jan 2024 15:04:05", "2024-01-02T15:04:05Z"),
+            ("2024/01/02 15:04:05", "2024-01-02T15:04:05Z"),
+            ("2024-01-02", "2024-01-02T00:00:00Z"),
+            ("Tue, 2 Jan 2024", "2024-01-02T00:00:00Z"),
+            ("2 Jan 2024", "2024-01-02T00:00:00Z"),
+            ("January 2, 2024", "2024-01-02T00:00:00Z"),
+        ];
+        for (raw_date, expected) in dates {
+            assert_eq!(parse_pub_date(raw_date), utc(expected), "{}", raw_date);
+        }
+    }
+
+    #[test]
+    fn parses_dates_with_added_formats() {
+        assert_eq!(
+            parse_with_format("02.01.2024 15:04", "%d.%m.%Y %H:%M"),
+            Some(utc("2024-01-02T15:04:00Z"))
+        );
+        assert_eq!(
+            parse_with_format("02.01.2024", "%d.%m.%Y"),
+            Some(utc("2024-01-02T00:00:00Z"))
+        );
+        assert_eq!(parse_with_format("yesterday", "%d.%m.%Y"), None);
+    }
+
+    #[test]
+    fn falls_back_to_the_current_time() {
+        for raw_date in ["", "   ", "sometime last week"] {
+            let before = Utc::now();
+            let parsed = parse_pub_date(raw_date);
+            assert!(before <= parsed && parsed <= Utc::now(), "{:?}", raw_date);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Serializes all writes to the JSON databases
+//!
+//! ## Concurrency model:
+//! The RSS feeds and articles databases are shared by the terminal UI, the background
+//! refresh threads and the HTTP server. Every change to a database is a read-modify-write
+//! cycle performed while holding a single process wide write lock (see [`lock_db_writes`]),
+//! so no two paths ever write the JSON files at the same time, and no change is lost to a
+//! concurrent writer. Slow work (e.g. fetching RSS feeds over HTTP) is done before taking
+//! the lock.
+//!
+//! Files are written to a temporary file first and then renamed over the original, so
+//! readers never observe a partially written database. Reads do not take the lock.
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::sync::{Mutex, MutexGuard};
+
+static DB_WRITE_LOCK: Mutex<()> = Mutex::new(());
+static DB_WRITE_COUNTS: Mutex<BTreeMap<String, usize>> = Mutex::new(BTreeMap::new());
+
+/// Acquires the database write lock, which must be held across every read-modify-write cycle
+pub fn lock_db_writes() -> MutexGuard<'static, ()> {
+    DB_WRITE_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Stores the given data into the JSON file provided, replacing its contents atomically
+///
+/// Callers are expected to hold the database write lock.
+pub fn write_db_file<T: Serialize + ?Sized>(file_path: &str, data: &T) {
+    let parsed_serde: Vec<u8> = serde_json::to_vec(data).unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+
+    let temp_file_path = format!("{}.tmp", file_path);
+
+    fs::write(&temp_file_path, parsed_serde)
+        .and_then(|_| fs::rename(&temp_file_path, file_path))
+        .unwrap_or_else(|_err| {
+            let err_msg = ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE);
+            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+        });
+
+    *lock_db_write_counts()
+        .entry(file_path.to_string())
+        .or_default() += 1;
+}
+
+/// Returns the number of times given JSON file was written since startup, e.g. for checking
+/// that a batch of changes is written at once
+pub fn count_db_writes(file_path: &str) -> usize {
+    lock_db_write_counts().get(file_path).copied().unwrap_or(0)
+}
+
+fn lock_db_write_counts() -> MutexGuard<'static, BTreeMap<String, usize>> {
+    DB_WRITE_COUNTS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,183 @@
+//! Discovers the RSS feeds advertised by a website, for adding feeds from a homepage URL
+//!
+//! Websites advertise their feeds with `<link rel="alternate">` tags in the page head, e.g.
+//! `<link rel="alternate" type="application/rss+xml" href="/feed.xml">`.
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::html::clean_html;
+use crate::http_client::{http_client, http_request_error, read_response_body};
+use reqwest::Url;
+use rss::Channel;
+
+/// MIME types of the feeds looked up in the website pages
+const FEED_MIME_TYPES: [&str; 2] = ["application/rss+xml", "application/atom+xml"];
+
+/// Fetches given page and lists the RSS feed URLs it advertises, resolved to absolute URLs
+///
+/// A URL which is already an RSS feed is returned as is. Fails with
+/// `E0035_FEED_DISCOVERY_FAILURE` when the page advertises no RSS feeds.
+pub async fn discover_feeds(page_url: &str) -> Result<Vec<String>, ErrorMessages> {
+    let page_url = page_url.trim();
+    let response = http_client()
+        .get(page_url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| http_request_error(&err))?;
+    let content = read_response_body(response).await?;
+
+    if Channel::read_from(&content[..]).is_ok() {
+        return Ok(vec![page_url.to_string()]);
+    }
+
+    let feed_urls = extract_feed_links(&String::from_utf8_lossy(&content), page_url);
+    match feed_urls.is_empty() {
+        true => Err(ErrorMessages::new(ErrorCodes::E0035_FEED_DISCOVERY_FAILURE)),
+        false => Ok(feed_urls),
+    }
+}
+
+/// Extracts the RSS feed URLs from the alternate `<link>` tags of given HTML page
+///
+/// Relative URLs are resolved against `base_url`, and duplicates are dropped.
+pub fn extract_feed_links(html: &str, base_url: &str) -> Vec<String> {
+    let base_url = Url::parse(base_url).ok();
+    // ASCII lowercasing keeps the byte offsets of the page, for slicing it at the tags found
+    let lowercase_html = html.to_ascii_lowercase();
+    let mut feed_urls: Vec<String> = Vec::new();
+
+    for (start, _) in lowercase_html.match_indices("<link") {
+        let tag_end = match html[start..].find('>') {
+            Some(t) => start + t,
+            None => break,
+        };
+        let attributes = parse_attributes(&html[start + "<link".len()..tag_end]);
+        let attribute = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let is_alternate = attribute("rel").is_some_and(|rel| {
+            rel.split_whitespace()
+                .any(|r| r.eq_ignore_ascii_case("alternate"))
+        });
+        let is_feed = attribute("type")
+            .is_some_and(|t| FEED_MIME_TYPES.contains(&t.trim().to_lowercase().as_str()));
+        let href = match attribute("href") {
+            Some(t) if is_alternate && is_feed && !t.trim().is_empty() => clean_html(t),
+            _ => continue,
+        };
+
+        let feed_url = match &base_url {
+            Some(base_url) => base_url.join(&href).map(|url| url.to_string()).ok(),
+            None => Url::parse(&href).map(|url| url.to_string()).ok(),
+        };
+
+        if let Some(feed_url) = feed_url {
+            if !feed_urls.contains(&feed_url) {
+                feed_urls.push(feed_url);
+            }
+        }
+    }
+
+    feed_urls
+}
+
+/// Parses the attributes of an HTML tag into lowercase names and raw values
+fn parse_attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut chars = tag.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == '/').is_some() {}
+
+        let name: String =
+            std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace() && *c != '=' && *c != '/'))
+                .collect();
+        if name.is_empty() {
+            break;
+        }
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let value = match chars.next_if_eq(&'=') {
+            Some(_) => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                match chars.next_if(|c| *c == '"' || *c == '\'') {
+                    Some(quote) => chars.by_ref().take_while(|c| *c != quote).collect(),
+                    None => std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace())).collect(),
+                }
+            }
+            None => String::new(),
+        };
+
+        attributes.push((name.to_lowercase(), value));
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+  <title>Example blog</title>
+  <link rel="stylesheet" href="/style.css">
+  <LINK REL="alternate" TYPE="application/rss+xml" TITLE="Posts" HREF="/feed.xml">
+  <link rel='alternate' type='application/atom+xml' href='https://feeds.example.com/atom?format=full&amp;lang=en'/>
+  <link rel="alternate" type="text/html" hreflang="fr" href="/fr/">
+  <link type="application/rss+xml" href="/not-alternate.xml">
+  <link rel="alternate" type="application/rss+xml" href="feed.xml">
+</head>
+<body></body>
+</html>"#;
+
+    #[test]
+    fn extracts_alternate_feed_links() {
+        assert_eq!(
+            extract_feed_links(SAMPLE_PAGE, "https://example.com/blog/"),
+            vec![
+                "https://example.com/feed.xml",
+                "https://feeds.example.com/atom?format=full&lang=en",
+                "https://example.com/blog/feed.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_nothing_from_pages_without_feeds() {
+        let html = r#"<html><head><link rel="icon" href="/favicon.ico"></head></html>"#;
+        assert!(extract_feed_links(html, "https://example.com/").is_empty());
+    }
+
+    #[test]
+    fn extracts_feed_links_after_non_ascii_text() {
+        // 'İ' lowercases to 3 bytes instead of 2, shifting the offsets of the lowercase page
+        let html = r#"<html><head><title>İstanbul İİİ</title>
+<LINK rel="alternate" type="application/rss+xml" title="Haberler – İ" href="/feed.xml">
+<link rel="alternate" type="application/atom+xml" href="/ẞ/atom.xml"></head></html>"#;
+        assert_eq!(
+            extract_feed_links(html, "https://example.com/"),
+            vec![
+                "https://example.com/feed.xml",
+                "https://example.com/%E1%BA%9E/atom.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_duplicate_feed_links() {
+        let html = r#"<link rel="alternate" type="application/rss+xml" href="/feed.xml">
+<link rel="alternate" type="application/rss+xml" href="https://example.com/feed.xml">"#;
+        assert_eq!(
+            extract_feed_links(html, "https://example.com/"),
+            vec!["https://example.com/feed.xml"]
+        );
+    }
+}
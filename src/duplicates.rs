@@ -0,0 +1,162 @@
+//! Detects RSS feeds serving the same articles under different names or URLs, and merges them
+//!
+//! Two RSS feeds are duplicates when most of the recent articles of one of them are also
+//! articles of the other one (same article link). Merging keeps the RSS feed added first, moves
+//! the articles of the other RSS feed over to it and removes the other RSS feed.
+//!
+
+use crate::config::data_path;
+use crate::db_writer::{lock_db_writes, write_db_file};
+use crate::feed_layers::write_feed_layers;
+use crate::{read_articles_db, read_rss_db, Articles, RSSFeed, ARTICLE_DB_FILE};
+use std::collections::HashSet;
+
+/// Number of most recent articles of each RSS feed compared
+const RECENT_ARTICLES: usize = 50;
+
+/// Minimum number of shared articles for two RSS feeds to be duplicates
+const MIN_SHARED_ARTICLES: usize = 3;
+
+/// Minimum share of the recent articles of the smaller RSS feed also found in the other one
+const MIN_OVERLAP: f64 = 0.8;
+
+#[derive(Clone, Debug)]
+/// Defines a pair of RSS feeds serving the same articles
+pub struct DuplicateFeeds {
+    /// RSS feed kept on merge (the one added first)
+    pub kept: RSSFeed,
+    /// RSS feed removed on merge, its articles being moved to the kept RSS feed
+    pub duplicate: RSSFeed,
+    /// Number of recent articles found in both RSS feeds
+    pub num_shared: usize,
+}
+
+#[derive(Clone, Debug)]
+/// Defines the outcome of merging a duplicate RSS feed into the kept one
+pub struct MergeOutcome {
+    /// Name of the RSS feed kept
+    pub kept_name: String,
+    /// Name of the RSS feed removed
+    pub duplicate_name: String,
+    /// Number of articles moved to the kept RSS feed
+    pub num_moved: usize,
+    /// Number of articles dropped, as the kept RSS feed already had them
+    pub num_dropped: usize,
+}
+
+/// Finds the RSS feeds whose recent articles substantially overlap with those of another RSS feed
+///
+/// Each RSS feed is reported at most once as a duplicate, and never both kept and removed, so
+/// all the candidates returned can be merged one after the other. The welcome feed (first
+/// entry) is left out.
+pub fn find_duplicate_feeds() -> Vec<DuplicateFeeds> {
+    let rss_feed_list: Vec<RSSFeed> = read_rss_db().into_iter().skip(1).collect();
+    let articles_list: Vec<Articles> = read_articles_db();
+
+    let recent_links: Vec<HashSet<&str>> = rss_feed_list
+        .iter()
+        .map(|rss_feed| {
+            let mut articles: Vec<&Articles> = articles_list
+                .iter()
+                .filter(|r| r.rss_id == rss_feed.rss_id && !r.article_link.is_empty())
+                .collect();
+            articles.sort_by_key(|r| std::cmp::Reverse(r.pub_date));
+            articles
+                .into_iter()
+                .take(RECENT_ARTICLES)
+                .map(|r| r.article_link.as_str())
+                .collect()
+        })
+        .collect();
+
+    let mut duplicates: Vec<DuplicateFeeds> = Vec::new();
+    let mut merged: HashSet<usize> = HashSet::new();
+
+    for (i, kept) in rss_feed_list.iter().enumerate() {
+        if merged.contains(&kept.rss_id) {
+            continue;
+        }
+        for (j, duplicate) in rss_feed_list.iter().enumerate().skip(i + 1) {
+            if merged.contains(&duplicate.rss_id) {
+                continue;
+            }
+            let num_shared = recent_links[i].intersection(&recent_links[j]).count();
+            let num_compared = recent_links[i].len().min(recent_links[j].len());
+
+            if num_shared >= MIN_SHARED_ARTICLES
+                && num_shared as f64 >= num_compared as f64 * MIN_OVERLAP
+            {
+                merged.insert(duplicate.rss_id);
+                duplicates.push(DuplicateFeeds {
+                    kept: kept.clone(),
+                    duplicate: duplicate.clone(),
+                    num_shared,
+                });
+            }
+        }
+    }
+    duplicates
+}
+
+/// Merges the duplicate RSS feed into the kept one, returning what was merged
+///
+/// Articles of the duplicate RSS feed are moved to the kept RSS feed, unless it already has
+/// them: these are dropped, their read, bookmarked and tags being carried over to the kept
+/// article. Returns `None` when either RSS feed no longer exists.
+pub fn merge_duplicate_feeds(duplicate_feeds: &DuplicateFeeds) -> Option<MergeOutcome> {
+    let (kept_id, duplicate_id) = (
+        duplicate_feeds.kept.rss_id,
+        duplicate_feeds.duplicate.rss_id,
+    );
+
+    let _db_lock = lock_db_writes();
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+    if !rss_feed_list.iter().any(|r| r.rss_id == kept_id)
+        || !rss_feed_list.iter().any(|r| r.rss_id == duplicate_id)
+    {
+        return None;
+    }
+
+    let (duplicate_articles, mut articles_list): (Vec<Articles>, Vec<Articles>) =
+        read_articles_db()
+            .into_iter()
+            .partition(|r| r.rss_id == duplicate_id);
+
+    let mut num_moved = 0;
+    let mut num_dropped = 0;
+    for mut article in duplicate_articles {
+        let existing = articles_list
+            .iter_mut()
+            .find(|r| r.rss_id == kept_id && r.article_link == article.article_link);
+
+        match existing {
+            Some(existing) => {
+                existing.read |= article.read;
+                existing.bookmarked |= article.bookmarked;
+                existing.viewed_at = existing.viewed_at.or(article.viewed_at);
+                for tag in article.tags {
+                    if !existing.tags.contains(&tag) {
+                        existing.tags.push(tag);
+                    }
+                }
+                num_dropped += 1;
+            }
+            None => {
+                article.rss_id = kept_id;
+                articles_list.push(article);
+                num_moved += 1;
+            }
+        }
+    }
+
+    rss_feed_list.retain(|r| r.rss_id != duplicate_id);
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    write_feed_layers(&rss_feed_list);
+
+    Some(MergeOutcome {
+        kept_name: duplicate_feeds.kept.label().to_string(),
+        duplicate_name: duplicate_feeds.duplicate.label().to_string(),
+        num_moved,
+        num_dropped,
+    })
+}
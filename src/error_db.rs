@@ -2,7 +2,7 @@
 //!
 
 #[allow(non_camel_case_types)]
-#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 /// Defines the list of error codes used in the application
 pub enum ErrorCodes {
     /// Unable to convert terminal to raw mode
@@ -49,9 +49,67 @@ pub enum ErrorCodes {
     E0020_RFC2822_TIMESTAMP_PARSE_FAILURE,
     /// Unable to fetch max timestamp from Articles database
     E0021_ARTICLE_MAX_TIMESTAMP_FETCH_FAILURE,
+    /// Unable to parse config file, default configuration is used
+    E0022_CONFIG_PARSE_FAILURE,
+    /// Invalid date format string in config file, default date format is used
+    E0023_DATE_FORMAT_PARSE_FAILURE,
+    /// Unable to parse command line arguments
+    E0024_CLI_ARGUMENT_PARSE_FAILURE,
+    /// Unable to start HTTP server on the port provided
+    E0025_HTTP_SERVER_FAILURE,
+    /// Application was built without the "serve" feature
+    E0026_SERVE_FEATURE_DISABLED,
+    /// Unable to copy content into the system clipboard
+    E0028_CLIPBOARD_WRITE_FAILURE,
+    /// RSS feed is already being refreshed
+    E0029_FEED_REFRESH_IN_PROGRESS,
+    /// Invalid HTTP request header configured for RSS feed
+    E0030_INVALID_REQUEST_HEADER,
+    /// Missing category/name or invalid URL provided for new RSS feed
+    E0031_INVALID_FEED_INPUT,
+    /// Unable to parse OPML content in file provided
+    E0032_OPML_PARSE_FAILURE,
+    /// Unable to open article link in the web browser
+    E0033_BROWSER_OPEN_FAILURE,
+    /// Unable to write to the refresh history file
+    E0034_REFRESH_HISTORY_WRITE_FAILURE,
+    /// Unable to find RSS feeds on the website page provided
+    E0035_FEED_DISCOVERY_FAILURE,
+    /// Unable to parse the duration provided
+    E0036_DURATION_PARSE_FAILURE,
+    /// Received an empty response body from the RSS feed
+    E0037_EMPTY_RESPONSE_BODY,
+    /// Unable to find the config file provided
+    E0038_CONFIG_FILE_NOT_FOUND,
+    /// Invalid setting in config file, default value is used
+    E0039_INVALID_CONFIG_VALUE,
+    /// HTTP request timed out
+    E0040_HTTP_TIMEOUT,
+    /// Unable to resolve the host name of the HTTP link provided (DNS lookup failed)
+    E0041_DNS_LOOKUP_FAILURE,
+    /// Unable to establish a secure (TLS) connection with the server
+    E0042_TLS_HANDSHAKE_FAILURE,
+    /// Unable to connect to the server of the HTTP link provided
+    E0043_HTTP_CONNECT_FAILURE,
+    /// Server responded with an HTTP error status
+    E0044_HTTP_STATUS_FAILURE,
+    /// Secret referenced in the request headers of the RSS feed was not found
+    E0045_UNRESOLVED_SECRET_REFERENCE,
+    /// Unable to read text from the system clipboard
+    E0046_CLIPBOARD_READ_FAILURE,
+    /// Server answered with a Cloudflare challenge, which can't be passed without a browser
+    E0047_CLOUDFLARE_CHALLENGE,
+    /// Response body is larger than the maximum response size
+    E0048_RESPONSE_TOO_LARGE,
+    /// Failed to back up the JSON files before resetting them
+    E0049_DB_BACKUP_FAILURE,
+    /// An RSS feed with the same URL is already filed under the category
+    E0050_FEED_ALREADY_IN_CATEGORY,
+    /// Failed to read the config file
+    E0051_CONFIG_FILE_READ_FAILURE,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 /// Defines metadata for mapping the error codes to corresponding error descriptions
 pub struct ErrorMessages {
     /// Error codes defined as per "ErrorCodes" enum
@@ -128,6 +186,91 @@ impl ErrorMessages {
             ErrorCodes::E0021_ARTICLE_MAX_TIMESTAMP_FETCH_FAILURE => {
                 String::from("Unable to fetch max timestamp from Articles database.")
             }
+            ErrorCodes::E0022_CONFIG_PARSE_FAILURE => {
+                String::from("Unable to parse config file.")
+            }
+            ErrorCodes::E0023_DATE_FORMAT_PARSE_FAILURE => String::from(
+                "Invalid date format string in config file, default date format is used.",
+            ),
+            ErrorCodes::E0024_CLI_ARGUMENT_PARSE_FAILURE => {
+                String::from("Unable to parse command line arguments.")
+            }
+            ErrorCodes::E0025_HTTP_SERVER_FAILURE => {
+                String::from("Unable to start HTTP server on the port provided.")
+            }
+            ErrorCodes::E0026_SERVE_FEATURE_DISABLED => {
+                String::from("Application was built without the \"serve\" feature.")
+            }
+            ErrorCodes::E0028_CLIPBOARD_WRITE_FAILURE => {
+                String::from("Unable to copy content into the system clipboard.")
+            }
+            ErrorCodes::E0029_FEED_REFRESH_IN_PROGRESS => {
+                String::from("RSS feed is already being refreshed.")
+            }
+            ErrorCodes::E0030_INVALID_REQUEST_HEADER => {
+                String::from("Invalid HTTP request header configured for RSS feed.")
+            }
+            ErrorCodes::E0031_INVALID_FEED_INPUT => String::from(
+                "Category, name and a valid HTTP(S) URL are required to add an RSS feed.",
+            ),
+            ErrorCodes::E0032_OPML_PARSE_FAILURE => {
+                String::from("Unable to parse OPML content in file provided.")
+            }
+            ErrorCodes::E0033_BROWSER_OPEN_FAILURE => {
+                String::from("Unable to open article link in the web browser.")
+            }
+            ErrorCodes::E0034_REFRESH_HISTORY_WRITE_FAILURE => {
+                String::from("Unable to write to the refresh history file.")
+            }
+            ErrorCodes::E0035_FEED_DISCOVERY_FAILURE => {
+                String::from("Unable to find RSS feeds on the website page provided.")
+            }
+            ErrorCodes::E0036_DURATION_PARSE_FAILURE => {
+                String::from("Unable to parse the duration provided (expected e.g. 12h, 7d or 2w).")
+            }
+            ErrorCodes::E0037_EMPTY_RESPONSE_BODY => {
+                String::from("Received an empty response body from the RSS feed.")
+            }
+            ErrorCodes::E0038_CONFIG_FILE_NOT_FOUND => {
+                String::from("Unable to find the config file provided.")
+            }
+            ErrorCodes::E0039_INVALID_CONFIG_VALUE => {
+                String::from("Invalid setting in config file, default value is used.")
+            }
+            ErrorCodes::E0040_HTTP_TIMEOUT => String::from("HTTP request timed out."),
+            ErrorCodes::E0041_DNS_LOOKUP_FAILURE => String::from(
+                "Unable to resolve the host name of the HTTP link provided (check the URL for typos).",
+            ),
+            ErrorCodes::E0042_TLS_HANDSHAKE_FAILURE => String::from(
+                "Unable to establish a secure (TLS) connection with the server (e.g. invalid certificate).",
+            ),
+            ErrorCodes::E0043_HTTP_CONNECT_FAILURE => {
+                String::from("Unable to connect to the server of the HTTP link provided.")
+            }
+            ErrorCodes::E0044_HTTP_STATUS_FAILURE => {
+                String::from("Server responded with an HTTP error status.")
+            }
+            ErrorCodes::E0045_UNRESOLVED_SECRET_REFERENCE => String::from(
+                "Secret referenced in the request headers of the RSS feed was not found in the environment or the secrets file.",
+            ),
+            ErrorCodes::E0046_CLIPBOARD_READ_FAILURE => {
+                String::from("Unable to read text from the system clipboard.")
+            }
+            ErrorCodes::E0047_CLOUDFLARE_CHALLENGE => String::from(
+                "Server answered with a Cloudflare challenge (bot check), which can't be passed without a browser.",
+            ),
+            ErrorCodes::E0048_RESPONSE_TOO_LARGE => String::from(
+                "Feed too large: response is larger than the maximum response size (max_response_size_mb in config).",
+            ),
+            ErrorCodes::E0049_DB_BACKUP_FAILURE => String::from(
+                "Unable to back up the JSON files, nothing was reset.",
+            ),
+            ErrorCodes::E0050_FEED_ALREADY_IN_CATEGORY => String::from(
+                "An RSS feed with the same URL is already filed under this category.",
+            ),
+            ErrorCodes::E0051_CONFIG_FILE_READ_FAILURE => {
+                String::from("Unable to read the config file.")
+            }
         };
         ErrorMessages {
             error_code: err_code,
@@ -0,0 +1,284 @@
+//! Exports RSS articles into files for use outside the application
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::html::clean_html;
+use crate::{format_timestamp, read_articles_db, read_rss_db, Articles};
+use chrono::prelude::{DateTime, Utc};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Maximum number of characters used from the article title in exported file names
+const MAX_SLUG_LENGTH: usize = 60;
+/// Maximum number of characters used from the article body in Markdown snippets
+const MAX_EXCERPT_LENGTH: usize = 280;
+/// Title used for articles published without one
+const UNTITLED_ARTICLE: &str = "Untitled article";
+
+/// Defines the file formats of the exported read history
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReadHistoryFormat {
+    /// Comma separated values, with a header row
+    Csv,
+    /// JSON array of entries
+    Json,
+}
+
+impl ReadHistoryFormat {
+    /// Picks the format from the extension of given file path, CSV unless it ends in ".json"
+    pub fn from_path(file_path: &str) -> ReadHistoryFormat {
+        match Path::new(file_path)
+            .extension()
+            .is_some_and(|t| t.eq_ignore_ascii_case("json"))
+        {
+            true => ReadHistoryFormat::Json,
+            false => ReadHistoryFormat::Csv,
+        }
+    }
+}
+
+/// Defines a single entry of the exported read history
+#[derive(Serialize, Clone, Debug)]
+pub struct ReadHistoryEntry {
+    /// Unique identifier of the article
+    pub article_id: usize,
+    /// Article title
+    pub title: String,
+    /// Name of the RSS feed the article comes from
+    pub source: String,
+    /// Timestamp at which the article was viewed
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Exports the viewed articles, oldest view first, into given file, returning the number of entries
+///
+/// An empty history is written as a header row (CSV) or an empty array (JSON).
+pub fn export_read_history(
+    file_path: &str,
+    format: ReadHistoryFormat,
+) -> Result<usize, ErrorMessages> {
+    let rss_feed_list = read_rss_db();
+    let mut entries: Vec<ReadHistoryEntry> = read_articles_db()
+        .into_iter()
+        .filter_map(|article| {
+            let viewed_at = article.viewed_at?;
+            let source = rss_feed_list
+                .iter()
+                .find(|r| r.rss_id == article.rss_id)
+                .map(|r| r.label().to_string())
+                .unwrap_or_default();
+            Some(ReadHistoryEntry {
+                article_id: article.article_id,
+                title: article.title,
+                source,
+                viewed_at,
+            })
+        })
+        .collect();
+    entries.sort_by_key(|r| r.viewed_at);
+
+    let content = match format {
+        ReadHistoryFormat::Json => serde_json::to_string_pretty(&entries).map_err(|_err| {
+            ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE)
+        })?,
+        ReadHistoryFormat::Csv => {
+            let mut csv = String::from("article_id,title,source,viewed_at\n");
+            for entry in entries.iter() {
+                csv.push_str(&format!(
+                    "{},{},{},{}\n",
+                    entry.article_id,
+                    csv_field(&entry.title),
+                    csv_field(&entry.source),
+                    entry.viewed_at.to_rfc3339()
+                ));
+            }
+            csv
+        }
+    };
+
+    fs::write(file_path, content)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE))?;
+    Ok(entries.len())
+}
+
+/// Quotes given CSV field when it contains a separator, a quote or a line break
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+/// Exports given article into a Markdown file in the directory provided, returning the file path
+///
+/// File name is derived from the article title and publishing date, and a counter is
+/// appended when a file with the same name already exists.
+pub fn export_article(article_id: usize, dir: &str) -> Result<PathBuf, ErrorMessages> {
+    let article = read_articles_db()
+        .into_iter()
+        .find(|r| r.article_id == article_id)
+        .ok_or_else(|| ErrorMessages::new(ErrorCodes::E0013_ARTICLES_LIST_READ_FAILURE))?;
+
+    let feed_name = read_rss_db()
+        .into_iter()
+        .find(|r| r.rss_id == article.rss_id)
+        .map(|r| r.label().to_string())
+        .unwrap_or_default();
+
+    fs::create_dir_all(dir)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE))?;
+
+    let file_stem = format!(
+        "{}-{}",
+        slugify(&article.title),
+        article.pub_date.format("%Y-%m-%d")
+    );
+
+    let mut file_path = Path::new(dir).join(format!("{}.md", file_stem));
+    let mut counter: usize = 1;
+    while file_path.exists() {
+        file_path = Path::new(dir).join(format!("{}-{}.md", file_stem, counter));
+        counter += 1;
+    }
+
+    fs::write(&file_path, article_to_markdown(&article, &feed_name))
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE))?;
+
+    Ok(file_path)
+}
+
+/// Formats given article as a Markdown document
+pub fn article_to_markdown(article: &Articles, feed_name: &str) -> String {
+    let mut markdown = format!("# {}\n\n", article_title(article));
+    markdown.push_str(&format!("- **Source:** {}\n", feed_name));
+    markdown.push_str(&format!(
+        "- **Published On:** {}\n",
+        format_timestamp(&article.pub_date)
+    ));
+    if !article.author.is_empty() {
+        markdown.push_str(&format!("- **Author:** {}\n", article.author));
+    }
+    if !article.article_link.trim().is_empty() {
+        markdown.push_str(&format!("- **Link:** <{}>\n", article.article_link.trim()));
+    }
+    markdown.push('\n');
+    markdown.push_str(&article_body(article));
+    markdown.push('\n');
+    markdown
+}
+
+/// Formats given article as a short Markdown snippet (`[title](link)` and an excerpt)
+pub fn article_to_markdown_snippet(article: &Articles) -> String {
+    let title = article_title(article)
+        .replace('[', "\\[")
+        .replace(']', "\\]");
+    let link = article.article_link.trim();
+
+    let mut markdown = if link.is_empty() {
+        format!("**{}**", title)
+    } else {
+        format!("[{}]({})", title, link)
+    };
+
+    let article_body = article_body(article);
+    let first_paragraph = article_body.split("\n\n").next().unwrap_or_default();
+    if !first_paragraph.is_empty() {
+        markdown.push_str("\n\n> ");
+        markdown.push_str(&excerpt(first_paragraph).replace('\n', "\n> "));
+    }
+
+    markdown
+}
+
+/// Builds a `mailto:` URL sharing given article, with its title as subject and its title, link
+/// and an excerpt as body
+///
+/// No recipient is set, so the mail client asks for one. Empty links and bodies are left out.
+pub fn build_mailto(article: &Articles) -> String {
+    let title = article_title(article);
+    let mut body = title.to_string();
+
+    let link = article.article_link.trim();
+    if !link.is_empty() {
+        body.push('\n');
+        body.push_str(link);
+    }
+
+    let article_body = article_body(article);
+    let first_paragraph = article_body.split("\n\n").next().unwrap_or_default();
+    if !first_paragraph.is_empty() {
+        body.push_str("\n\n");
+        body.push_str(&excerpt(first_paragraph));
+    }
+
+    // Line breaks of mailto bodies are CRLF (RFC 6068)
+    format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(title),
+        percent_encode(&body.replace('\n', "\r\n"))
+    )
+}
+
+/// Percent-encodes all the characters of given text, except the unreserved ones (RFC 3986)
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Returns the trimmed article title, or a placeholder if the article has no title
+fn article_title(article: &Articles) -> &str {
+    match article.title.trim() {
+        "" => UNTITLED_ARTICLE,
+        title => title,
+    }
+}
+
+/// Returns the article body as plain text, preferring the full content when available
+fn article_body(article: &Articles) -> String {
+    match &article.full_content {
+        Some(full_content) => clean_html(full_content),
+        None => clean_html(&article.summary),
+    }
+}
+
+/// Shortens given text to the excerpt length, cutting at a word boundary
+fn excerpt(text: &str) -> String {
+    if text.chars().count() <= MAX_EXCERPT_LENGTH {
+        return text.to_string();
+    }
+
+    let shortened: String = text.chars().take(MAX_EXCERPT_LENGTH).collect();
+    let shortened = match shortened.rfind(char::is_whitespace) {
+        Some(idx) => &shortened[..idx],
+        None => &shortened,
+    };
+    format!("{}…", shortened.trim_end())
+}
+
+/// Converts the given text into a lowercase, dash separated file name
+fn slugify(text: &str) -> String {
+    let slug = text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+
+    let slug: String = slug.chars().take(MAX_SLUG_LENGTH).collect();
+    let slug = slug.trim_end_matches('-');
+
+    if slug.is_empty() {
+        String::from("article")
+    } else {
+        slug.to_string()
+    }
+}
@@ -0,0 +1,115 @@
+//! Layers the RSS feeds database over a read-only list of base RSS feeds, for packaged installs
+//! shipping a default set of RSS feeds
+//!
+//! The base RSS feeds are read from `base_feeds_file` (set in config, same format as
+//! "data/rss_db.json") and are never written. The RSS feeds database in the data folder only
+//! keeps the changes made on top of them:
+//! - RSS feeds added by the user are stored as usual
+//! - Base RSS feeds changed locally (renamed, paused, refreshed, ...) are stored in full, and
+//!   this copy is used instead of the base RSS feed from then on
+//! - Base RSS feeds deleted locally are recorded as tombstones (their RSS ids) in
+//!   "data/feed_tombstones.json", and left out of the RSS feeds list
+//!
+//! Base RSS feeds left untouched follow the base file. The order of the whole RSS feeds list
+//! (e.g. after moving RSS feeds up or down) is kept in "data/feed_order.json" as RSS ids; RSS
+//! feeds missing from it, such as base RSS feeds added to the base file since, are listed last.
+//!
+
+use crate::config::{data_path, get_config};
+use crate::db_writer::write_db_file;
+use crate::{RSSFeed, RSS_DB_FILE};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+
+/// JSON file name for the RSS ids of the base RSS feeds deleted locally, in the data folder
+pub const FEED_TOMBSTONES_FILE: &str = "feed_tombstones.json";
+/// JSON file name for the RSS ids of the whole RSS feeds list in order, in the data folder
+pub const FEED_ORDER_FILE: &str = "feed_order.json";
+
+/// Reads the base RSS feeds from `base_feeds_file`, none when not set or unreadable
+pub fn read_base_feeds() -> Vec<RSSFeed> {
+    get_config()
+        .base_feeds_file
+        .as_ref()
+        .and_then(|base_feeds_file| fs::read_to_string(base_feeds_file).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Reads the RSS ids of the base RSS feeds deleted locally
+pub fn read_feed_tombstones() -> BTreeSet<usize> {
+    fs::read_to_string(data_path(FEED_TOMBSTONES_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Reads the RSS ids of the whole RSS feeds list in order, none until base RSS feeds are used
+pub fn read_feed_order() -> Vec<usize> {
+    fs::read_to_string(data_path(FEED_ORDER_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Merges the base RSS feeds into the RSS feeds of the database, leaving out the ones deleted
+/// locally and the ones the database has its own copy of (same RSS id), in the stored order
+pub fn merge_feed_layers(mut rss_feed_list: Vec<RSSFeed>) -> Vec<RSSFeed> {
+    let base_feeds = read_base_feeds();
+    if base_feeds.is_empty() {
+        return rss_feed_list;
+    }
+
+    let tombstones = read_feed_tombstones();
+    let rss_ids: BTreeSet<usize> = rss_feed_list.iter().map(|r| r.rss_id).collect();
+    rss_feed_list.extend(
+        base_feeds
+            .into_iter()
+            .filter(|r| !rss_ids.contains(&r.rss_id) && !tombstones.contains(&r.rss_id)),
+    );
+
+    let positions: HashMap<usize, usize> = read_feed_order()
+        .into_iter()
+        .enumerate()
+        .map(|(position, rss_id)| (rss_id, position))
+        .collect();
+    rss_feed_list.sort_by_key(|r| positions.get(&r.rss_id).copied().unwrap_or(usize::MAX));
+    rss_feed_list
+}
+
+/// Stores the RSS feeds list into the RSS feeds database, keeping only the changes made on top
+/// of the base RSS feeds, recording the base RSS feeds missing from the list as tombstones and
+/// the order of the list
+///
+/// Callers are expected to hold the database write lock.
+pub fn write_feed_layers(rss_feed_list: &[RSSFeed]) {
+    let base_feeds = read_base_feeds();
+    if base_feeds.is_empty() {
+        write_db_file(&data_path(RSS_DB_FILE), rss_feed_list);
+        return;
+    }
+
+    let user_feeds: Vec<&RSSFeed> = rss_feed_list
+        .iter()
+        .filter(|r| !base_feeds.contains(r))
+        .collect();
+    write_db_file(&data_path(RSS_DB_FILE), &user_feeds);
+
+    let rss_ids: BTreeSet<usize> = rss_feed_list.iter().map(|r| r.rss_id).collect();
+    let mut tombstones = read_feed_tombstones();
+    let num_tombstones = tombstones.len();
+    tombstones.extend(
+        base_feeds
+            .iter()
+            .map(|r| r.rss_id)
+            .filter(|rss_id| !rss_ids.contains(rss_id)),
+    );
+    if tombstones.len() != num_tombstones {
+        write_db_file(&data_path(FEED_TOMBSTONES_FILE), &tombstones);
+    }
+
+    let feed_order: Vec<usize> = rss_feed_list.iter().map(|r| r.rss_id).collect();
+    if feed_order != read_feed_order() {
+        write_db_file(&data_path(FEED_ORDER_FILE), &feed_order);
+    }
+}
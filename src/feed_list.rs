@@ -0,0 +1,203 @@
+//! Adds RSS feeds in bulk from plain text lists, a lighter-weight alternative to OPML imports
+//!
+//! Besides the feed list format of the application, the `urls` file of Newsboat (the terminal
+//! RSS reader) is supported, for migrating from it.
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::opml::DEFAULT_IMPORT_CATEGORY;
+use crate::{add_feed, read_rss_db};
+use std::fs;
+use std::io::Read;
+
+/// Defines a line of a feed list which could not be added, and why
+#[derive(Clone, Debug)]
+pub struct FailedFeedLine {
+    /// Line number (starting at 1) in the feed list
+    pub line_number: usize,
+    /// Content of the line
+    pub line: String,
+    /// Reason the RSS feed was not added
+    pub reason: String,
+}
+
+/// Defines the outcome of adding RSS feeds from a feed list
+#[derive(Clone, Debug, Default)]
+pub struct FeedListReport {
+    /// URLs of the RSS feeds added
+    pub added: Vec<String>,
+    /// Lines which could not be added
+    pub failed: Vec<FailedFeedLine>,
+}
+
+impl FeedListReport {
+    /// Summarizes the outcome in a single line
+    pub fn summary(&self) -> String {
+        format!(
+            "Added {} RSS feeds, {} lines failed",
+            self.added.len(),
+            self.failed.len()
+        )
+    }
+}
+
+/// Adds the RSS feeds listed in given text file
+///
+/// See [`add_feeds_from_list`] for the expected format.
+pub async fn add_feeds_from_file(file_path: &str) -> Result<FeedListReport, ErrorMessages> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE))?;
+    Ok(add_feeds_from_list(&content).await)
+}
+
+/// Adds the RSS feeds listed on the standard input
+///
+/// See [`add_feeds_from_list`] for the expected format.
+pub async fn add_feeds_from_stdin() -> Result<FeedListReport, ErrorMessages> {
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE))?;
+    Ok(add_feeds_from_list(&content).await)
+}
+
+/// Adds the RSS feeds listed in given Newsboat `urls` file
+///
+/// See [`add_feeds_from_newsboat`] for the expected format.
+pub async fn import_newsboat(file_path: &str) -> Result<FeedListReport, ErrorMessages> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE))?;
+    Ok(add_feeds_from_newsboat(&content).await)
+}
+
+/// Adds the RSS feeds of a Newsboat `urls` file, one RSS feed per line, and refreshes them
+///
+/// Each line is a URL followed by optional tags, separated by spaces and quoted when they
+/// contain spaces (e.g. `https://example.com/feed.xml "tech news" rust`). The first tag is
+/// used as the category, or else the "Imported" category. A tag starting with `~` names the
+/// RSS feed; without it, RSS feeds are named after their channel title on their first refresh.
+/// Hidden feed tags (`!`) are ignored. Empty lines and lines starting with `#` are skipped.
+/// Query, exec and filter feeds, RSS feeds already present (same URL) and invalid URLs are
+/// reported as failed.
+pub async fn add_feeds_from_newsboat(content: &str) -> FeedListReport {
+    let mut report = FeedListReport::default();
+
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fail = |reason: &str| FailedFeedLine {
+            line_number: idx + 1,
+            line: line.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let mut tokens = split_newsboat_line(line).into_iter();
+        let url = tokens.next().unwrap_or_default();
+        if ["query:", "exec:", "filter:"]
+            .iter()
+            .any(|prefix| url.starts_with(prefix))
+        {
+            report.failed.push(fail(
+                "Newsboat query, exec and filter feeds are not supported",
+            ));
+            continue;
+        }
+
+        let mut name = url.clone();
+        let mut category = None;
+        for tag in tokens {
+            match tag.strip_prefix('~') {
+                Some(title) if !title.trim().is_empty() => name = title.to_string(),
+                Some(_) => {}
+                None if tag == "!" || category.is_some() => {}
+                None => category = Some(tag),
+            }
+        }
+        let category = category.unwrap_or_else(|| DEFAULT_IMPORT_CATEGORY.to_string());
+
+        if let Err(reason) = add_listed_feed(&category, &name, &url).await {
+            report.failed.push(fail(&reason));
+            continue;
+        }
+        report.added.push(url);
+    }
+
+    report
+}
+
+/// Splits a line of a Newsboat `urls` file on spaces, keeping quoted tags whole
+fn split_newsboat_line(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_quotes = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Adds an RSS feed of a feed list, unless already present, returning why it was not added
+async fn add_listed_feed(category: &str, name: &str, url: &str) -> Result<(), String> {
+    if read_rss_db().iter().any(|r| r.url.trim() == url) {
+        return Err(String::from("RSS feed already present"));
+    }
+    add_feed(category, name, url)
+        .await
+        .map_err(|err_msg| err_msg.error_message)
+}
+
+/// Adds the RSS feeds of a feed list, one RSS feed per line, and refreshes them
+///
+/// Each line is either `category|name|url` or just `url`. Empty lines and lines starting with
+/// `#` are skipped. RSS feeds listed by URL only are filed under the "Imported" category and
+/// named after their channel title on their first refresh. RSS feeds already present (same
+/// URL) are reported as failed.
+pub async fn add_feeds_from_list(content: &str) -> FeedListReport {
+    let mut report = FeedListReport::default();
+
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fail = |reason: &str| FailedFeedLine {
+            line_number: idx + 1,
+            line: line.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let (category, name, url) = match line.split('|').collect::<Vec<&str>>()[..] {
+            [url] => (DEFAULT_IMPORT_CATEGORY, url.trim(), url.trim()),
+            [category, name, url] => (category.trim(), name.trim(), url.trim()),
+            _ => {
+                report
+                    .failed
+                    .push(fail("Expected \"category|name|url\" or \"url\""));
+                continue;
+            }
+        };
+
+        match add_listed_feed(category, name, url).await {
+            Ok(()) => report.added.push(url.to_string()),
+            Err(reason) => report.failed.push(fail(&reason)),
+        }
+    }
+
+    report
+}
@@ -0,0 +1,167 @@
+//! Tracks the refresh errors, in-flight refreshes and fetch statistics of RSS feeds while the
+//! application is running
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::RSSFeed;
+use chrono::prelude::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Number of most recent fetches of each RSS feed kept for its fetch statistics
+pub const FETCH_STATS_WINDOW: usize = 10;
+
+static FEED_ERRORS: Mutex<BTreeMap<usize, FeedError>> = Mutex::new(BTreeMap::new());
+static REFRESHING_FEEDS: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+static FETCH_SAMPLES: Mutex<BTreeMap<usize, VecDeque<(Duration, bool)>>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(Clone, Debug)]
+/// Defines the metadata for storing the last refresh error of an RSS feed
+pub struct FeedError {
+    /// Unique identifier for each RSS feed
+    pub rss_id: usize,
+    /// RSS feed name
+    pub feed_name: String,
+    /// Error code as per "ErrorCodes" enum
+    pub error_code: ErrorCodes,
+    /// Error description for the error code
+    pub error_message: String,
+    /// Timestamp of the failed refresh
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Records the refresh error of given RSS feed, replacing any previous error
+pub fn record_feed_error(rss_feed: &RSSFeed, err_msg: &ErrorMessages) {
+    let feed_error = FeedError {
+        rss_id: rss_feed.rss_id,
+        feed_name: rss_feed.label().to_string(),
+        error_code: err_msg.error_code,
+        error_message: err_msg.error_message.clone(),
+        occurred_at: Utc::now(),
+    };
+
+    lock_feed_errors().insert(rss_feed.rss_id, feed_error);
+}
+
+/// Clears the refresh error of given RSS feed after a successful refresh
+pub fn clear_feed_error(rss_id: usize) {
+    lock_feed_errors().remove(&rss_id);
+}
+
+/// Reads the refresh error of given RSS feed, if it is in an error state
+pub fn read_feed_error(rss_id: usize) -> Option<FeedError> {
+    lock_feed_errors().get(&rss_id).cloned()
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Defines the statistics of the most recent fetches of an RSS feed (see `FETCH_STATS_WINDOW`)
+pub struct FetchStats {
+    /// Number of fetches the statistics are computed from
+    pub num_fetches: usize,
+    /// Average time taken by a fetch, failed fetches included
+    pub avg_response_time: Duration,
+    /// Share of the fetches which failed, from 0 to 1
+    pub failure_rate: f64,
+}
+
+/// Records the time taken by a fetch of given RSS feed and whether it succeeded, dropping the
+/// oldest fetch once `FETCH_STATS_WINDOW` fetches are recorded
+pub fn record_feed_fetch(rss_id: usize, response_time: Duration, succeeded: bool) {
+    let mut fetch_samples = lock_fetch_samples();
+    let samples = fetch_samples.entry(rss_id).or_default();
+    if samples.len() >= FETCH_STATS_WINDOW {
+        samples.pop_front();
+    }
+    samples.push_back((response_time, succeeded));
+}
+
+/// Computes the fetch statistics of given RSS feed, if fetched since startup
+pub fn read_fetch_stats(rss_id: usize) -> Option<FetchStats> {
+    lock_fetch_samples().get(&rss_id).and_then(fetch_stats)
+}
+
+/// Computes the fetch statistics of all the RSS feeds fetched since startup, slowest first
+pub fn read_slowest_feeds() -> Vec<(usize, FetchStats)> {
+    let mut slowest_feeds: Vec<(usize, FetchStats)> = lock_fetch_samples()
+        .iter()
+        .filter_map(|(rss_id, samples)| fetch_stats(samples).map(|stats| (*rss_id, stats)))
+        .collect();
+    slowest_feeds.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.avg_response_time));
+    slowest_feeds
+}
+
+fn fetch_stats(samples: &VecDeque<(Duration, bool)>) -> Option<FetchStats> {
+    if samples.is_empty() {
+        return None;
+    }
+    let num_fetches = samples.len();
+    let total_time: Duration = samples
+        .iter()
+        .map(|(response_time, _)| *response_time)
+        .sum();
+    let num_failures = samples.iter().filter(|(_, succeeded)| !succeeded).count();
+
+    Some(FetchStats {
+        num_fetches,
+        avg_response_time: total_time / num_fetches as u32,
+        failure_rate: num_failures as f64 / num_fetches as f64,
+    })
+}
+
+/// Reads the RSS feeds currently in an error state, ordered by RSS id
+pub fn read_feed_errors() -> Vec<FeedError> {
+    lock_feed_errors().values().cloned().collect()
+}
+
+/// Marks given RSS feed as refreshing until the returned guard is dropped
+///
+/// Returns `None` if a refresh of the RSS feed is already in flight.
+pub fn begin_feed_refresh(rss_id: usize) -> Option<RefreshGuard> {
+    if lock_refreshing_feeds().insert(rss_id) {
+        Some(RefreshGuard { rss_id })
+    } else {
+        None
+    }
+}
+
+/// Checks whether a refresh of given RSS feed is in flight
+pub fn is_feed_refreshing(rss_id: usize) -> bool {
+    lock_refreshing_feeds().contains(&rss_id)
+}
+
+/// Checks whether a refresh of any RSS feed is in flight
+pub fn is_any_feed_refreshing() -> bool {
+    !lock_refreshing_feeds().is_empty()
+}
+
+/// Reads the RSS ids of the RSS feeds whose refresh is in flight
+pub fn read_refreshing_feeds() -> BTreeSet<usize> {
+    lock_refreshing_feeds().clone()
+}
+
+/// Keeps an RSS feed marked as refreshing while in scope
+pub struct RefreshGuard {
+    rss_id: usize,
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        lock_refreshing_feeds().remove(&self.rss_id);
+    }
+}
+
+fn lock_feed_errors() -> MutexGuard<'static, BTreeMap<usize, FeedError>> {
+    FEED_ERRORS.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn lock_refreshing_feeds() -> MutexGuard<'static, BTreeSet<usize>> {
+    REFRESHING_FEEDS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
+
+fn lock_fetch_samples() -> MutexGuard<'static, BTreeMap<usize, VecDeque<(Duration, bool)>>> {
+    FETCH_SAMPLES.lock().unwrap_or_else(|err| err.into_inner())
+}
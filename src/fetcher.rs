@@ -0,0 +1,166 @@
+//! Refreshes many RSS feeds at once with a bounded number of concurrent fetches
+//!
+//! RSS feeds are fetched concurrently, at most `max_concurrency` (see config) at a time, and
+//! the fetched articles of all the feeds are then stored into the JSON files in a single
+//! batched write. Used by refresh all and OPML import.
+//!
+//! Requests to the same host are also spaced by at least `host_request_delay_ms` (see config),
+//! so that refreshing many RSS feeds of a single site (e.g. newsletters of one platform)
+//! doesn't trip its rate limits.
+//!
+//! For troubleshooting, RSS feeds can be fetched one at a time instead (`sequential_fetch` in
+//! config, or `--sequential-fetch`), logging the start and outcome of each fetch.
+//!
+
+use crate::config::get_config;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::feed_status::begin_feed_refresh;
+use crate::http_client::http_client;
+use crate::notifications::notify_new_articles;
+use crate::{
+    fetch_refreshed_feed, read_rss_db, record_refresh_result, store_fetched_feeds, RSSFeed,
+};
+use log::info;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::{sleep_until, Instant};
+
+/// Time of the last request made to each host, locked while waiting for the next request slot
+type HostSlot = Arc<tokio::sync::Mutex<Option<Instant>>>;
+
+static HOST_SLOTS: OnceLock<Mutex<HashMap<String, HostSlot>>> = OnceLock::new();
+static SEQUENTIAL_FETCH: AtomicBool = AtomicBool::new(false);
+
+/// Fetches the RSS feeds one at a time, whatever the config says (`--sequential-fetch`)
+pub fn set_sequential_fetch(sequential_fetch: bool) {
+    SEQUENTIAL_FETCH.store(sequential_fetch, Ordering::Relaxed);
+}
+
+/// Returns whether the RSS feeds are fetched one at a time, with each fetch logged
+pub fn is_sequential_fetch() -> bool {
+    SEQUENTIAL_FETCH.load(Ordering::Relaxed) || get_config().sequential_fetch
+}
+
+/// Returns the request slot of the host of given URL, or `None` for URLs without host
+fn host_slot(url: &str) -> Option<HostSlot> {
+    let host = reqwest::Url::parse(url).ok()?.host_str()?.to_lowercase();
+    let mut host_slots = HOST_SLOTS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    Some(Arc::clone(host_slots.entry(host).or_default()))
+}
+
+/// Refreshes the given RSS feeds (by RSS id), returning the number of new articles of each feed
+///
+/// Paused RSS feeds are skipped. RSS feeds which are already being refreshed are skipped and
+/// reported as `E0029_FEED_REFRESH_IN_PROGRESS`.
+pub async fn refresh_rss_feeds(rss_ids: &[usize]) -> Vec<(usize, Result<usize, ErrorMessages>)> {
+    let rss_feed_list: Vec<RSSFeed> = read_rss_db()
+        .into_iter()
+        .filter(|r| r.enabled && rss_ids.contains(&r.rss_id))
+        .collect();
+
+    let sequential_fetch = is_sequential_fetch();
+    let max_concurrency = match sequential_fetch {
+        true => 1,
+        false => get_config().max_concurrency.max(1),
+    };
+    let semaphore = Arc::new(Semaphore::new(max_concurrency));
+    let host_delay = Duration::from_millis(get_config().host_request_delay_ms);
+    // Built before the first request slot is taken, so that building the client (e.g. loading
+    // the TLS certificates) doesn't delay the first request into the host delay of the next one
+    http_client();
+    let mut results = Vec::with_capacity(rss_feed_list.len());
+    let mut refresh_guards = Vec::with_capacity(rss_feed_list.len());
+    let mut fetch_tasks = Vec::with_capacity(rss_feed_list.len());
+
+    for rss_feed in rss_feed_list.iter() {
+        match begin_feed_refresh(rss_feed.rss_id) {
+            Some(refresh_guard) => refresh_guards.push(refresh_guard),
+            None => {
+                let err_msg = ErrorMessages::new(ErrorCodes::E0029_FEED_REFRESH_IN_PROGRESS);
+                results.push((rss_feed.rss_id, Err(err_msg)));
+                continue;
+            }
+        }
+
+        let semaphore = Arc::clone(&semaphore);
+        let fetched_rss_feed = rss_feed.clone();
+        let fetch_task = tokio::spawn(async move {
+            // Requests to the same host wait for their turn without holding a fetch permit
+            let host_slot = host_slot(&fetched_rss_feed.url);
+            let mut last_request = match &host_slot {
+                Some(host_slot) => Some(host_slot.lock().await),
+                None => None,
+            };
+            if let Some(Some(last_request)) = last_request.as_deref() {
+                sleep_until(*last_request + host_delay).await;
+            }
+
+            let _permit = semaphore.acquire_owned().await;
+            if let Some(last_request) = last_request.as_deref_mut() {
+                *last_request = Some(Instant::now());
+            }
+            drop(last_request);
+
+            if !sequential_fetch {
+                return fetch_refreshed_feed(fetched_rss_feed).await;
+            }
+            let (feed_name, feed_url) =
+                (fetched_rss_feed.name.clone(), fetched_rss_feed.url.clone());
+            info!("Fetching RSS feed \"{}\" ({})", feed_name, feed_url);
+            let started_at = std::time::Instant::now();
+            let result = fetch_refreshed_feed(fetched_rss_feed).await;
+            match &result {
+                Ok(_fetched_feed) => info!(
+                    "Fetched RSS feed \"{}\" in {} ms",
+                    feed_name,
+                    started_at.elapsed().as_millis()
+                ),
+                Err(err_msg) => info!(
+                    "Failed to fetch RSS feed \"{}\" after {} ms: {:?} - {}",
+                    feed_name,
+                    started_at.elapsed().as_millis(),
+                    err_msg.error_code,
+                    err_msg.error_message
+                ),
+            }
+            result
+        });
+        fetch_tasks.push((rss_feed, fetch_task));
+    }
+
+    let mut fetched_feeds = Vec::with_capacity(fetch_tasks.len());
+    let mut refresh_results = Vec::with_capacity(fetch_tasks.len());
+    for (rss_feed, fetch_task) in fetch_tasks.into_iter() {
+        match fetch_task.await {
+            Ok(Ok(fetched_feed)) => fetched_feeds.push(fetched_feed),
+            Ok(Err(err_msg)) => refresh_results.push((rss_feed.rss_id, Err(err_msg))),
+            Err(_err) => refresh_results.push((
+                rss_feed.rss_id,
+                Err(ErrorMessages::new(ErrorCodes::E0010_HTTP_REQUEST_FAILURE)),
+            )),
+        }
+    }
+
+    refresh_results.extend(store_fetched_feeds(fetched_feeds));
+    drop(refresh_guards);
+
+    let mut new_articles = Vec::new();
+    for (rss_id, result) in refresh_results.iter() {
+        if let Some(rss_feed) = rss_feed_list.iter().find(|r| r.rss_id == *rss_id) {
+            record_refresh_result(rss_feed, result);
+            if let Ok(num_articles) = result {
+                new_articles.push((rss_feed.label().to_string(), *num_articles));
+            }
+        }
+    }
+    notify_new_articles(&new_articles);
+
+    results.extend(refresh_results);
+    results
+}
@@ -0,0 +1,191 @@
+//! Converts the HTML content found in RSS feeds into plain text, keeping track of the links
+//! it contains
+//!
+
+#[derive(Clone, Debug, PartialEq)]
+/// Defines a hyperlink found in HTML content
+pub struct HtmlLink {
+    /// Text of the link, as plain text (empty for e.g. image links)
+    pub text: String,
+    /// Target URL of the link
+    pub url: String,
+}
+
+/// Converts HTML content into plain text by dropping tags and decoding common entities
+pub fn clean_html(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                let tag: String = chars.by_ref().take_while(|t| *t != '>').collect();
+                text.push_str(tag_separator(&tag));
+            }
+            '&' => {
+                let entity: String = chars
+                    .clone()
+                    .take_while(|t| *t != ';' && !t.is_whitespace())
+                    .take(10)
+                    .collect();
+
+                match decode_entity(&entity) {
+                    Some(decoded) if chars.clone().nth(entity.chars().count()) == Some(';') => {
+                        text.push(decoded);
+                        chars.nth(entity.chars().count());
+                    }
+                    _ => text.push('&'),
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+
+    collapse_whitespace(&text)
+}
+
+/// Extracts the hyperlinks (`<a href="...">` tags) of HTML content, in order of appearance
+///
+/// Only links to web pages (http and https URLs) are kept, so anchors and e.g. `mailto:` links
+/// are left out.
+pub fn extract_links(raw: &str) -> Vec<HtmlLink> {
+    // ASCII lowercasing keeps the byte offsets of the original content
+    let lower = raw.to_ascii_lowercase();
+    let mut links = Vec::new();
+    let mut pos = 0;
+
+    while let Some(found) = lower[pos..].find("<a") {
+        let start = pos + found;
+        let is_anchor_tag = lower[start + 2..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_whitespace() || c == '>');
+        let tag_end = match lower[start..].find('>') {
+            Some(t) => start + t,
+            None => break,
+        };
+        if !is_anchor_tag {
+            pos = start + 2;
+            continue;
+        }
+
+        let content_end = lower[tag_end..]
+            .find("</a")
+            .map_or(raw.len(), |t| tag_end + t);
+        let url = tag_attribute(&raw[start + 2..tag_end], "href")
+            .map(|href| clean_html(&href))
+            .unwrap_or_default();
+
+        if url.starts_with("http://") || url.starts_with("https://") {
+            links.push(HtmlLink {
+                text: clean_html(&raw[tag_end + 1..content_end]),
+                url,
+            });
+        }
+        pos = content_end.max(tag_end + 1);
+    }
+
+    links
+}
+
+/// Reads the value of given attribute in the content of an HTML tag (without the tag name)
+fn tag_attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut pos = 0;
+
+    while let Some(found) = lower[pos..].find(name) {
+        let start = pos + found;
+        pos = start + name.len();
+
+        let follows_space = lower[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        let value = tag[pos..].trim_start();
+        let value = match value.strip_prefix('=') {
+            Some(value) if follows_space => value.trim_start(),
+            _ => continue,
+        };
+
+        return Some(
+            match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+                _ => value.split(char::is_whitespace).next().unwrap_or_default(),
+            }
+            .to_string(),
+        );
+    }
+    None
+}
+
+/// Returns the text separator to be used in place of given HTML tag
+fn tag_separator(tag: &str) -> &'static str {
+    let tag_name = tag
+        .trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match tag_name.as_str() {
+        "br" | "li" | "tr" => "\n",
+        "p" | "div" | "blockquote" | "ul" | "ol" | "table" | "h1" | "h2" | "h3" | "h4" | "h5"
+        | "h6" => "\n\n",
+        _ => "",
+    }
+}
+
+/// Decodes an HTML entity (without the leading '&' and trailing ';')
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(code) = entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+        return u32::from_str_radix(code, 16).ok().and_then(char::from_u32);
+    }
+
+    if let Some(code) = entity.strip_prefix('#') {
+        return code.parse::<u32>().ok().and_then(char::from_u32);
+    }
+
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        "hellip" => Some('…'),
+        "mdash" => Some('—'),
+        "ndash" => Some('–'),
+        "lsquo" => Some('‘'),
+        "rsquo" => Some('’'),
+        "ldquo" => Some('“'),
+        "rdquo" => Some('”'),
+        _ => None,
+    }
+}
+
+/// Collapses repeated whitespace within lines, and repeated blank lines into one
+fn collapse_whitespace(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut pending_blank_line = false;
+
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+        if line.is_empty() {
+            pending_blank_line = !cleaned.is_empty();
+            continue;
+        }
+
+        if !cleaned.is_empty() {
+            cleaned.push('\n');
+            if pending_blank_line {
+                cleaned.push('\n');
+            }
+        }
+
+        pending_blank_line = false;
+        cleaned.push_str(&line);
+    }
+
+    cleaned
+}
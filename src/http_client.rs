@@ -0,0 +1,170 @@
+//! Provides the HTTP client shared by all the requests made by the application
+//!
+//! A single client keeps a pool of connections, so refreshing many RSS feeds hosted on the
+//! same server reuses them. HTTP/2 is negotiated over TLS (ALPN) when the server supports it.
+//!
+//! The TLS implementation and the minimum TLS version are set in config (`tls_backend`,
+//! `min_tls_version`), for servers which only accept some TLS setups.
+//!
+//! Response bodies are read in chunks and given up once larger than `max_response_size_mb`, so
+//! a misconfigured server can't exhaust the memory.
+//!
+
+use crate::config::{get_config, TlsBackend};
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use log::error;
+use reqwest::header::HeaderMap;
+use reqwest::tls;
+use reqwest::StatusCode;
+use std::error::Error;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Maximum time allowed for connecting to a server
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum time allowed for a whole request, including reading the response body
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Returns the shared HTTP client, building it on first use
+pub fn http_client() -> &'static reqwest::Client {
+    HTTP_CLIENT.get_or_init(|| {
+        let config = get_config();
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT);
+
+        builder = match config.tls_backend {
+            TlsBackend::Native => builder.use_native_tls(),
+            #[cfg(feature = "rustls")]
+            TlsBackend::Rustls => builder.use_rustls_tls(),
+            #[cfg(not(feature = "rustls"))]
+            TlsBackend::Rustls => {
+                log::warn!("Built without the \"rustls\" feature, using the native TLS backend");
+                builder.use_native_tls()
+            }
+        };
+
+        let min_tls_version = match config.min_tls_version.as_deref() {
+            Some("1.0") => Some(tls::Version::TLS_1_0),
+            Some("1.1") => Some(tls::Version::TLS_1_1),
+            Some("1.2") => Some(tls::Version::TLS_1_2),
+            Some("1.3") => Some(tls::Version::TLS_1_3),
+            _ => None,
+        };
+        if let Some(min_tls_version) = min_tls_version {
+            builder = builder.min_tls_version(min_tls_version);
+        }
+
+        builder.build().unwrap_or_else(|err| {
+            error!("Unable to build the HTTP client, using defaults: {}", err);
+            reqwest::Client::new()
+        })
+    })
+}
+
+/// Reads the whole body of given response, however it is transferred (chunked, HTTP/2 frames)
+///
+/// Fails with `E0048_RESPONSE_TOO_LARGE` as soon as the body announced or received is larger
+/// than `max_response_size_mb`, without reading the rest of it.
+pub async fn read_response_body(mut response: reqwest::Response) -> Result<Vec<u8>, ErrorMessages> {
+    let max_bytes = get_config()
+        .max_response_size_mb
+        .saturating_mul(1024 * 1024);
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes)
+    {
+        return Err(ErrorMessages::new(ErrorCodes::E0048_RESPONSE_TOO_LARGE));
+    }
+
+    let mut content: Vec<u8> = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0011_HTTP_RESPONSE_PARSE_FAILURE))?
+    {
+        if (content.len() + chunk.len()) as u64 > max_bytes {
+            return Err(ErrorMessages::new(ErrorCodes::E0048_RESPONSE_TOO_LARGE));
+        }
+        content.extend_from_slice(&chunk);
+    }
+    Ok(content)
+}
+
+/// Checks whether a response is a Cloudflare challenge (bot check) instead of the content
+/// requested
+///
+/// Challenges are flagged by the `cf-mitigated` header, or else recognized from the markers of
+/// the challenge page. They require running JavaScript in a browser, so they can't be passed.
+pub fn is_cloudflare_challenge(headers: &HeaderMap, body: &[u8]) -> bool {
+    let mitigated = headers
+        .get("cf-mitigated")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("challenge"));
+    if mitigated {
+        return true;
+    }
+
+    let is_cloudflare = headers
+        .get(reqwest::header::SERVER)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("cloudflare"));
+    let body = String::from_utf8_lossy(body);
+    is_cloudflare
+        && [
+            "challenge-platform",
+            "cf-chl",
+            "<title>Just a moment...</title>",
+        ]
+        .iter()
+        .any(|marker| body.contains(marker))
+}
+
+/// Maps a failed HTTP request to the error code of its cause (DNS, connection, TLS, timeout,
+/// HTTP status), so that broken RSS feeds can be troubleshot from their error status
+pub fn http_request_error(err: &reqwest::Error) -> ErrorMessages {
+    if let Some(status) = err.status() {
+        return http_status_error(status);
+    }
+    if err.is_timeout() {
+        return ErrorMessages::new(ErrorCodes::E0040_HTTP_TIMEOUT);
+    }
+    if !err.is_connect() {
+        return ErrorMessages::new(ErrorCodes::E0010_HTTP_REQUEST_FAILURE);
+    }
+
+    // Connection errors only tell their cause apart in the messages of the error chain
+    let mut causes = Vec::new();
+    let mut source: Option<&dyn Error> = Some(err);
+    while let Some(t) = source {
+        causes.push(t.to_string().to_lowercase());
+        source = t.source();
+    }
+    let causes = causes.join(": ");
+
+    let err_code = if causes.contains("dns error") || causes.contains("lookup address") {
+        ErrorCodes::E0041_DNS_LOOKUP_FAILURE
+    } else if ["tls", "ssl", "certificate", "handshake"]
+        .iter()
+        .any(|t| causes.contains(t))
+    {
+        ErrorCodes::E0042_TLS_HANDSHAKE_FAILURE
+    } else {
+        ErrorCodes::E0043_HTTP_CONNECT_FAILURE
+    };
+    ErrorMessages::new(err_code)
+}
+
+/// Builds the error of an HTTP response with an error status, including the status received
+pub fn http_status_error(status: StatusCode) -> ErrorMessages {
+    let mut err_msg = ErrorMessages::new(ErrorCodes::E0044_HTTP_STATUS_FAILURE);
+    err_msg.error_message = format!(
+        "{} ({})",
+        err_msg.error_message.trim_end_matches('.'),
+        status
+    );
+    err_msg
+}
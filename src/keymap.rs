@@ -0,0 +1,707 @@
+//! Defines the keybindings of the terminal UI, used both to dispatch key presses and to
+//! render the help popup
+//!
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Defines the actions that can be triggered from the keyboard
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Action {
+    /// Open the add RSS feed wizard
+    AddFeed,
+    /// Open the add RSS feed wizard with the URL held in clipboard
+    AddFeedFromClipboard,
+    /// Import RSS feeds from an OPML file
+    ImportOpml,
+    /// Copy all the RSS feeds to clipboard as OPML
+    CopyOpml,
+    /// Delete the selected RSS feed
+    DeleteFeed,
+    /// Refresh the selected RSS feed
+    RefreshFeed,
+    /// Refresh all the RSS feeds
+    RefreshAllFeeds,
+    /// Refresh the RSS feeds with refresh errors only
+    RefreshErroredFeeds,
+    /// Pause the selected RSS feed, or resume it
+    ToggleFeedEnabled,
+    /// Set the display name of the selected RSS feed
+    RenameFeed,
+    /// Show the details of the selected RSS feed
+    ShowFeedDetails,
+    /// Rename the category of the selected RSS feed, on all the RSS feeds filed under it
+    RenameCategory,
+    /// Copy the selected RSS feed under another category
+    DuplicateFeed,
+    /// Edit the muted keywords of the selected RSS feed
+    MuteKeywords,
+    /// Select the next RSS feed
+    NextFeed,
+    /// Select the previous RSS feed
+    PreviousFeed,
+    /// Go to the first unread article of the next RSS feed with unread articles
+    NextUnreadFeed,
+    /// Go to the first unread article of the previous RSS feed with unread articles
+    PreviousUnreadFeed,
+    /// Move the selected RSS feed up
+    MoveFeedUp,
+    /// Move the selected RSS feed down
+    MoveFeedDown,
+    /// Cycle the order of the RSS feeds list
+    CycleFeedSort,
+    /// Mark the articles older than a duration as read
+    MarkReadBefore,
+    /// Select the next article
+    NextArticle,
+    /// Select the previous article
+    PreviousArticle,
+    /// Go to the first item of the focused list (RSS feeds or articles)
+    GoToTop,
+    /// Go to the last item of the focused list (RSS feeds or articles)
+    GoToBottom,
+    /// Hide the selected article
+    HideArticle,
+    /// Bookmark the selected article, or remove its bookmark
+    ToggleBookmark,
+    /// Mark the selected article as read, or as unread when already read
+    ToggleRead,
+    /// Edit the tags of the selected article
+    EditTags,
+    /// Show the articles with a given tag
+    ShowTaggedArticles,
+    /// Snooze the selected article for a given duration
+    SnoozeArticle,
+    /// Show the snoozed articles
+    ShowSnoozedArticles,
+    /// Move the selected article to the archive
+    ArchiveArticle,
+    /// Move the read articles of the selected RSS feed to the archive
+    ArchiveReadArticles,
+    /// Search the archived articles
+    SearchArchive,
+    /// Open the selected article in the web browser
+    OpenArticle,
+    /// Focus the next link of the summary of the selected article
+    FocusNextLink,
+    /// Open the focused link of the summary in the web browser
+    OpenFocusedLink,
+    /// Open all the unread articles of the selected RSS feed in the web browser
+    OpenUnreadArticles,
+    /// Open the website of the selected RSS feed in the web browser
+    OpenWebsite,
+    /// Export the selected article to Markdown file
+    ExportArticle,
+    /// Copy the selected article to clipboard as Markdown
+    CopyArticle,
+    /// Copy the summary of the selected article to clipboard as plain text
+    CopySummaryText,
+    /// Share the selected article by email
+    ShareArticle,
+    /// Toggle between article summary and full content
+    ToggleFullContent,
+    /// Scroll the summary down
+    ScrollSummaryDown,
+    /// Scroll the summary up
+    ScrollSummaryUp,
+    /// Toggle the summary preview in the articles list
+    ToggleArticlePreview,
+    /// Cycle the summary text style between normal, bold and high contrast
+    CycleSummaryEmphasis,
+    /// Toggle between showing only the unread articles and all the articles
+    ToggleUnreadOnly,
+    /// Collapse or expand the summary pane
+    ToggleSummaryPane,
+    /// Start selecting text in the summary
+    SelectText,
+    /// Show the RSS feeds errors popup
+    ShowErrors,
+    /// Show the log viewer popup
+    ShowLogs,
+    /// Show the statistics popup
+    ShowStats,
+    /// Find the RSS feeds serving the same articles, and offer to merge them
+    FindDuplicateFeeds,
+    /// Reload the RSS feeds and articles from disk
+    ReloadDb,
+    /// Write any pending changes to disk right away
+    FlushToDisk,
+    /// Back up, then clear all the RSS feeds and articles, after confirmation
+    ResetDb,
+    /// Show the help navigation popup
+    Help,
+    /// Leave the current input mode or popup
+    Back,
+    /// Exit the application
+    Quit,
+}
+
+/// Defines the part of the terminal UI a keybinding applies to, used for grouping the help
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum KeyContext {
+    /// Applies everywhere in the terminal UI
+    Global,
+    /// Applies to the RSS feeds list
+    Feeds,
+    /// Applies to the articles list and summary
+    Articles,
+}
+
+impl KeyContext {
+    /// Returns the title of the keybindings group in the help popup
+    pub fn title(&self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::Feeds => "RSS Feeds",
+            KeyContext::Articles => "Articles",
+        }
+    }
+}
+
+/// Defines a single keybinding
+#[derive(Clone, Debug)]
+pub struct KeyBinding {
+    /// Action triggered by the keybinding
+    pub action: Action,
+    /// Key code of the keybinding
+    pub code: KeyCode,
+    /// Modifiers (Alt, Ctrl) to be held along with the key
+    pub modifiers: KeyModifiers,
+    /// Part of the terminal UI the keybinding applies to
+    pub context: KeyContext,
+    /// Description shown in the help popup
+    pub description: &'static str,
+}
+
+/// Defines the active keybindings of the terminal UI
+pub struct Keymap {
+    bindings: Vec<KeyBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Keymap {
+        use Action::*;
+        use KeyContext::*;
+
+        let none = KeyModifiers::NONE;
+        let bindings = vec![
+            (
+                AddFeed,
+                KeyCode::Char('a'),
+                none,
+                Global,
+                "Add new RSS feed (tab / shift+tab to switch fields)",
+            ),
+            (
+                AddFeedFromClipboard,
+                KeyCode::Char('v'),
+                KeyModifiers::CONTROL,
+                Global,
+                "Add new RSS feed from the URL in clipboard",
+            ),
+            (
+                ImportOpml,
+                KeyCode::Char('I'),
+                none,
+                Global,
+                "Import RSS feeds from an OPML file",
+            ),
+            (
+                CopyOpml,
+                KeyCode::Char('C'),
+                none,
+                Global,
+                "Copy all RSS feeds to clipboard as OPML",
+            ),
+            (
+                RefreshAllFeeds,
+                KeyCode::Char('R'),
+                none,
+                Global,
+                "Refresh articles for all RSS feeds",
+            ),
+            (
+                RefreshErroredFeeds,
+                KeyCode::Char('r'),
+                KeyModifiers::CONTROL,
+                Global,
+                "Refresh articles for RSS feeds with refresh errors only",
+            ),
+            (
+                ShowErrors,
+                KeyCode::Char('E'),
+                none,
+                Global,
+                "Show RSS feeds with refresh errors",
+            ),
+            (
+                ShowLogs,
+                KeyCode::Char('L'),
+                none,
+                Global,
+                "Show application logs",
+            ),
+            (
+                ShowStats,
+                KeyCode::Char('S'),
+                none,
+                Global,
+                "Show reading statistics",
+            ),
+            (
+                FindDuplicateFeeds,
+                KeyCode::Char('D'),
+                none,
+                Global,
+                "Find and merge duplicate RSS feeds",
+            ),
+            (
+                ReloadDb,
+                KeyCode::Char('l'),
+                KeyModifiers::CONTROL,
+                Global,
+                "Reload RSS feeds and articles from disk",
+            ),
+            (
+                FlushToDisk,
+                KeyCode::Char('s'),
+                KeyModifiers::CONTROL,
+                Global,
+                "Write pending changes to disk",
+            ),
+            (
+                ResetDb,
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL,
+                Global,
+                "Reset all RSS feeds and articles (backed up first)",
+            ),
+            (Help, KeyCode::Char('h'), none, Global, "Open help menu"),
+            (
+                Back,
+                KeyCode::Esc,
+                none,
+                Global,
+                "Exit RSS add option / Exit popup windows",
+            ),
+            (
+                Quit,
+                KeyCode::Char('q'),
+                none,
+                Global,
+                "Exit the application",
+            ),
+            (
+                DeleteFeed,
+                KeyCode::Char('d'),
+                none,
+                Feeds,
+                "Delete existing RSS feed",
+            ),
+            (
+                RefreshFeed,
+                KeyCode::Char('r'),
+                none,
+                Feeds,
+                "Refresh articles for RSS feed",
+            ),
+            (
+                ToggleFeedEnabled,
+                KeyCode::Char('t'),
+                none,
+                Feeds,
+                "Pause / resume refreshing of RSS feed",
+            ),
+            (
+                ShowFeedDetails,
+                KeyCode::Char('i'),
+                none,
+                Feeds,
+                "Show RSS feed details",
+            ),
+            (
+                RenameFeed,
+                KeyCode::Char('n'),
+                none,
+                Feeds,
+                "Set display name of RSS feed",
+            ),
+            (
+                RenameCategory,
+                KeyCode::Char('N'),
+                none,
+                Feeds,
+                "Rename category of RSS feed (on all RSS feeds in it)",
+            ),
+            (
+                DuplicateFeed,
+                KeyCode::Char('P'),
+                none,
+                Feeds,
+                "Copy RSS feed under another category",
+            ),
+            (
+                MuteKeywords,
+                KeyCode::Char('K'),
+                none,
+                Feeds,
+                "Edit muted keywords of RSS feed (comma-separated)",
+            ),
+            (
+                NextFeed,
+                KeyCode::PageDown,
+                none,
+                Feeds,
+                "Go to next RSS feed",
+            ),
+            (
+                PreviousFeed,
+                KeyCode::PageUp,
+                none,
+                Feeds,
+                "Go to previous RSS feed",
+            ),
+            (
+                NextUnreadFeed,
+                KeyCode::Char(']'),
+                none,
+                Articles,
+                "Go to first unread article of next RSS feed with unread articles",
+            ),
+            (
+                PreviousUnreadFeed,
+                KeyCode::Char('['),
+                none,
+                Articles,
+                "Go to first unread article of previous RSS feed with unread articles",
+            ),
+            (
+                MoveFeedUp,
+                KeyCode::Up,
+                KeyModifiers::ALT,
+                Feeds,
+                "Move selected RSS feed up",
+            ),
+            (
+                MoveFeedDown,
+                KeyCode::Down,
+                KeyModifiers::ALT,
+                Feeds,
+                "Move selected RSS feed down",
+            ),
+            (
+                CycleFeedSort,
+                KeyCode::Char('s'),
+                none,
+                Feeds,
+                "Cycle RSS feeds order (insertion / name / unread count / last refreshed)",
+            ),
+            (
+                MarkReadBefore,
+                KeyCode::Char('M'),
+                none,
+                Feeds,
+                "Mark articles older than a duration as read",
+            ),
+            (
+                NextArticle,
+                KeyCode::Down,
+                none,
+                Articles,
+                "Go to next article",
+            ),
+            (
+                PreviousArticle,
+                KeyCode::Up,
+                none,
+                Articles,
+                "Go to previous article",
+            ),
+            (
+                GoToTop,
+                KeyCode::Char('g'),
+                none,
+                Global,
+                "Go to first RSS feed / article (in the list last navigated)",
+            ),
+            (
+                GoToBottom,
+                KeyCode::Char('G'),
+                none,
+                Global,
+                "Go to last RSS feed / article (in the list last navigated)",
+            ),
+            (
+                HideArticle,
+                KeyCode::Char('x'),
+                none,
+                Articles,
+                "Hide selected article",
+            ),
+            (
+                ToggleBookmark,
+                KeyCode::Char('b'),
+                none,
+                Articles,
+                "Bookmark / remove bookmark of selected article",
+            ),
+            (
+                ToggleRead,
+                KeyCode::Char('U'),
+                none,
+                Articles,
+                "Mark selected article as read / unread",
+            ),
+            (
+                EditTags,
+                KeyCode::Char('T'),
+                none,
+                Articles,
+                "Edit tags of selected article (comma-separated)",
+            ),
+            (
+                ShowTaggedArticles,
+                KeyCode::Char('f'),
+                none,
+                Global,
+                "Show articles with a tag, across all RSS feeds",
+            ),
+            (
+                SnoozeArticle,
+                KeyCode::Char('Z'),
+                none,
+                Articles,
+                "Snooze selected article (e.g. 12h, 1d), it comes back unread",
+            ),
+            (
+                ShowSnoozedArticles,
+                KeyCode::Char('H'),
+                none,
+                Global,
+                "Show snoozed articles",
+            ),
+            (
+                ArchiveArticle,
+                KeyCode::Char('A'),
+                none,
+                Articles,
+                "Archive selected article",
+            ),
+            (
+                ArchiveReadArticles,
+                KeyCode::Char('X'),
+                none,
+                Feeds,
+                "Archive read articles of RSS feed",
+            ),
+            (
+                SearchArchive,
+                KeyCode::Char('/'),
+                none,
+                Global,
+                "Search archived articles",
+            ),
+            (
+                OpenArticle,
+                KeyCode::Char('o'),
+                none,
+                Articles,
+                "Open selected article in the web browser",
+            ),
+            (
+                OpenWebsite,
+                KeyCode::Char('W'),
+                none,
+                Feeds,
+                "Open website of RSS feed in the web browser",
+            ),
+            (
+                OpenUnreadArticles,
+                KeyCode::Char('O'),
+                none,
+                Articles,
+                "Open all unread articles of the RSS feed in the web browser",
+            ),
+            (
+                ExportArticle,
+                KeyCode::Char('e'),
+                none,
+                Articles,
+                "Export selected article to Markdown file",
+            ),
+            (
+                ShareArticle,
+                KeyCode::Char('m'),
+                none,
+                Articles,
+                "Share selected article by email",
+            ),
+            (
+                CopyArticle,
+                KeyCode::Char('c'),
+                none,
+                Articles,
+                "Copy selected article to clipboard as Markdown",
+            ),
+            (
+                CopySummaryText,
+                KeyCode::Char('y'),
+                none,
+                Articles,
+                "Copy summary of selected article to clipboard as plain text",
+            ),
+            (
+                FocusNextLink,
+                KeyCode::Char('F'),
+                none,
+                Articles,
+                "Focus next link in summary",
+            ),
+            (
+                OpenFocusedLink,
+                KeyCode::Enter,
+                none,
+                Articles,
+                "Open focused link in web browser",
+            ),
+            (
+                ToggleFullContent,
+                KeyCode::Tab,
+                none,
+                Articles,
+                "Toggle between article summary and full content",
+            ),
+            (
+                ScrollSummaryDown,
+                KeyCode::Down,
+                KeyModifiers::SHIFT,
+                Articles,
+                "Scroll summary down",
+            ),
+            (
+                ScrollSummaryUp,
+                KeyCode::Up,
+                KeyModifiers::SHIFT,
+                Articles,
+                "Scroll summary up",
+            ),
+            (
+                ToggleArticlePreview,
+                KeyCode::Char('p'),
+                none,
+                Articles,
+                "Toggle two-line preview of summaries",
+            ),
+            (
+                CycleSummaryEmphasis,
+                KeyCode::Char('z'),
+                none,
+                Articles,
+                "Cycle summary text style (normal / bold / high contrast)",
+            ),
+            (
+                ToggleUnreadOnly,
+                KeyCode::Char('u'),
+                none,
+                Articles,
+                "Show unread articles only / all articles",
+            ),
+            (
+                ToggleSummaryPane,
+                KeyCode::Char('w'),
+                none,
+                Global,
+                "Collapse / expand the summary pane",
+            ),
+            (
+                SelectText,
+                KeyCode::Char('v'),
+                none,
+                Articles,
+                "Select text in summary (arrows/hjkl to extend, y to copy)",
+            ),
+        ];
+
+        Keymap {
+            bindings: bindings
+                .into_iter()
+                .map(
+                    |(action, code, modifiers, context, description)| KeyBinding {
+                        action,
+                        code,
+                        modifiers,
+                        context,
+                        description,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Finds the action bound to the given key press, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+
+        self.bindings
+            .iter()
+            .find(|binding| binding.code == key.code && binding.modifiers == modifiers)
+            .map(|binding| binding.action)
+    }
+
+    /// Finds the part of the terminal UI the given key press applies to, if it is bound
+    pub fn context_for(&self, key: &KeyEvent) -> Option<KeyContext> {
+        let action = self.action_for(key)?;
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == action)
+            .map(|binding| binding.context)
+    }
+
+    /// Lists the keybindings which apply to the given part of the terminal UI
+    pub fn bindings(&self, context: KeyContext) -> impl Iterator<Item = &KeyBinding> {
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.context == context)
+    }
+}
+
+/// Formats a key (and its modifiers) as shown in the help popup
+pub fn key_label(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let key = match code {
+        KeyCode::Char(' ') => String::from("space"),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => String::from("arrow-up"),
+        KeyCode::Down => String::from("arrow-down"),
+        KeyCode::Left => String::from("arrow-left"),
+        KeyCode::Right => String::from("arrow-right"),
+        KeyCode::PageUp => String::from("page-up"),
+        KeyCode::PageDown => String::from("page-down"),
+        KeyCode::Home => String::from("home"),
+        KeyCode::End => String::from("end"),
+        KeyCode::Tab => String::from("tab"),
+        KeyCode::BackTab => String::from("shift+tab"),
+        KeyCode::Enter => String::from("enter"),
+        KeyCode::Esc => String::from("esc"),
+        KeyCode::Backspace => String::from("backspace"),
+        KeyCode::Delete => String::from("delete"),
+        KeyCode::F(n) => format!("f{}", n),
+        other => format!("{:?}", other).to_lowercase(),
+    };
+
+    let mut label = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        label.push_str("ctrl+");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("alt+");
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        label.push_str("shift+");
+    }
+    label.push_str(&key);
+    label
+}
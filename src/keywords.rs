@@ -0,0 +1,73 @@
+//! Matches articles against keyword lists, for muting the articles of topics not cared about
+//! and flagging the articles of topics not to be missed
+//!
+//! Matching is case-insensitive, on the title and the summary of articles. Keywords match
+//! anywhere in the text, or only as whole words with `whole_word_keywords` (see config).
+//!
+
+use crate::config::get_config;
+use crate::html::clean_html;
+use crate::{Articles, RSSFeed};
+
+/// Checks whether given text contains the keyword, ignoring case
+///
+/// With `whole_word`, the keyword must not be surrounded by letters or digits (e.g. "rust"
+/// matches "Rust 1.70" but not "trusted").
+pub fn matches_keyword(text: &str, keyword: &str, whole_word: bool) -> bool {
+    let keyword = keyword.trim().to_lowercase();
+    if keyword.is_empty() {
+        return false;
+    }
+    let text = text.to_lowercase();
+
+    if !whole_word {
+        return text.contains(&keyword);
+    }
+
+    text.match_indices(&keyword).any(|(idx, _)| {
+        let before = text[..idx].chars().next_back();
+        let after = text[idx + keyword.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Returns the muted keywords applying to given RSS feed: its own and the global ones
+pub fn muted_keywords(rss_feed: &RSSFeed) -> Vec<String> {
+    let mut keywords = rss_feed.muted_keywords.clone();
+    keywords.extend(get_config().muted_keywords.iter().cloned());
+    keywords
+}
+
+/// Checks whether the title or the summary of given article matches any of the muted keywords
+pub fn is_article_muted(article: &Articles, keywords: &[String]) -> bool {
+    matches_any_keyword(article, keywords)
+}
+
+/// Checks whether the title or the summary of given article matches any of the alert keywords
+/// (see config)
+pub fn is_article_alerted(article: &Articles) -> bool {
+    matches_any_keyword(article, &get_config().alert_keywords)
+}
+
+/// Checks whether the title or the summary of given article matches any of the keywords
+fn matches_any_keyword(article: &Articles, keywords: &[String]) -> bool {
+    if keywords.is_empty() {
+        return false;
+    }
+    let whole_word = get_config().whole_word_keywords;
+    let summary = clean_html(&article.summary);
+
+    keywords.iter().any(|keyword| {
+        matches_keyword(&article.title, keyword, whole_word)
+            || matches_keyword(&summary, keyword, whole_word)
+    })
+}
+
+/// Parses a comma-separated list of keywords, dropping the empty ones
+pub fn parse_keywords(keywords: &str) -> Vec<String> {
+    keywords
+        .split(',')
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty())
+        .collect()
+}
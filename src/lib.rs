@@ -5,33 +5,112 @@
 //! - Enables users to add/remove RSS feeds
 //! - Incremental refresh for RSS articles
 //! - Help menu provided to help users with keyboard navigation
+//! - Optional read-only HTTP/JSON endpoints for feeds and articles (`serve` feature)
 //!
 //! ## Getting Started:
 //! Visit the [Byte-Bite official repository](https://github.com/oss-rust-github-io/byte_bite) to download and install the application on the host machine.
 //!
 
 extern crate chrono;
+pub mod archive;
+pub mod browser;
+pub mod clipboard;
+pub mod config;
+pub mod consistency;
+pub mod dates;
+pub mod db_writer;
+pub mod discovery;
+pub mod duplicates;
 pub mod error_db;
+pub mod export;
+pub mod feed_layers;
+pub mod feed_list;
+pub mod feed_status;
+pub mod fetcher;
+pub mod html;
+pub mod http_client;
+pub mod keywords;
+pub mod links;
+pub mod logging;
+pub mod notifications;
+pub mod opml;
+pub mod refresh_history;
+pub mod secrets;
+#[cfg(feature = "serve")]
+pub mod server;
+pub mod stats;
+pub mod suppressed_links;
+pub mod theme;
+pub mod transforms;
+pub mod ui_state;
+pub mod update_hints;
 
-use chrono::prelude::{DateTime, Utc};
+use archive::{read_archive_db, ARCHIVE_DB_FILE};
+use chrono::prelude::{DateTime, Local, Utc};
+use chrono::Duration;
+use config::{data_path, get_config, ArticleColumn, FeedCountFormat};
+use dates::parse_pub_date;
+use db_writer::{lock_db_writes, write_db_file};
 use error_db::{ErrorCodes, ErrorMessages};
-use reqwest;
+use feed_layers::{merge_feed_layers, write_feed_layers, FEED_ORDER_FILE, FEED_TOMBSTONES_FILE};
+use feed_status::{
+    begin_feed_refresh, clear_feed_error, is_feed_refreshing, read_feed_error, record_feed_error,
+    record_feed_fetch,
+};
+use html::{clean_html, extract_links, HtmlLink};
+use http_client::{
+    http_client, http_request_error, http_status_error, is_cloudflare_challenge, read_response_body,
+};
+use keywords::{is_article_alerted, is_article_muted, muted_keywords, parse_keywords};
+use links::{clean_url, elide_url};
+use log::{debug, error, info};
+use notifications::{notify_alert_articles, notify_new_articles};
+use opml::category_path;
+use refresh_history::{log_refresh_outcome, RefreshOutcome};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT, IF_MODIFIED_SINCE, LAST_MODIFIED,
+};
+use reqwest::StatusCode;
 use rss::Channel;
-use serde::{Deserialize, Serialize};
+use secrets::resolve_secret_references;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use suppressed_links::{read_suppressed_links, suppress_links, SUPPRESSED_LINKS_FILE};
+use transforms::apply_article_transforms;
 use tui::{
+    layout::Constraint,
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Block, BorderType, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, Wrap,
+    },
 };
+use ui_state::{FeedSort, SummaryEmphasis};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use update_hints::UpdateHints;
+
+/// JSON file name for RSS feed data, in the data folder (`data_dir` in config)
+pub const RSS_DB_FILE: &str = "rss_db.json";
 
-/// JSON file path for RSS feed data
-pub const RSS_DB_PATH: &str = "C:\\byte_bite\\data\\rss_db.json";
+/// JSON file name for RSS articles data, in the data folder
+pub const ARTICLE_DB_FILE: &str = "article_db.json";
 
-/// JSON file path for RSS articles data
-pub const ARTICLE_DB_PATH: &str = "C:\\byte_bite\\data\\article_db.json";
+/// JSON file name for the last RSS id assigned, so that the ids of deleted RSS feeds are never reused
+pub const RSS_ID_COUNTER_FILE: &str = "rss_id_counter.json";
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Folder of the data folder where the JSON files are backed up before a reset, one subfolder
+/// per reset
+pub const DB_BACKUP_FOLDER: &str = "backups";
+
+/// Default `Accept` header sent when refreshing RSS feeds, unless overridden per feed
+pub const DEFAULT_ACCEPT_HEADER: &str =
+    "application/rss+xml, application/xml;q=0.9, text/xml;q=0.8, */*;q=0.5";
+
+#[derive(PartialEq, Serialize, Deserialize, Clone, Debug)]
 /// Defines the metadata for storing RSS feed information
 pub struct RSSFeed {
     /// Unique identifier for each RSS feed
@@ -40,9 +119,102 @@ pub struct RSSFeed {
     pub category: String,
     /// RSS feed name
     pub name: String,
+    /// Nickname shown in the terminal UI instead of the RSS feed name, when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
     /// RSS feed URL
     pub url: String,
     created_at: DateTime<Utc>,
+    /// Extra HTTP request headers sent when refreshing the RSS feed
+    ///
+    /// Headers are stored in plain text in the RSS database, so reference secrets (e.g. API
+    /// tokens) as `${NAME}` instead of storing them here, see [`secrets`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// `Last-Modified` header returned by the server on the last successful refresh
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Channel title returned by the server on the last successful refresh
+    #[serde(default)]
+    pub channel_title: Option<String>,
+    /// URL of the channel image (logo) returned by the server on the last successful refresh
+    #[serde(default)]
+    pub logo_url: Option<String>,
+    /// Website of the RSS feed (channel link) returned by the server on the last successful refresh
+    #[serde(default)]
+    pub site_url: Option<String>,
+    /// Channel description returned by the server on the last successful refresh
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Flag for RSS feeds refreshed along with the others (paused RSS feeds are skipped)
+    #[serde(default = "default_feed_enabled")]
+    pub enabled: bool,
+    /// Keywords muting the articles of the RSS feed which mention them (see [`keywords`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub muted_keywords: Vec<String>,
+    /// Maximum number of items (the first ones, as ordered in the RSS feed) stored per refresh,
+    /// for RSS feeds returning many items at once; unlimited when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items_per_refresh: Option<usize>,
+    /// Update frequency hints returned by the server on the last successful refresh
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_hints: Option<UpdateHints>,
+    /// Time of the last successful refresh, none until the RSS feed is first refreshed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+}
+
+fn default_feed_enabled() -> bool {
+    true
+}
+
+impl RSSFeed {
+    /// Creates a new RSS feed entry, trimming the category, name and URL provided
+    pub fn new(rss_id: usize, category: &str, name: &str, url: &str) -> RSSFeed {
+        RSSFeed {
+            rss_id,
+            category: category.trim().to_string(),
+            name: name.trim().to_string(),
+            display_name: None,
+            url: url.trim().to_string(),
+            created_at: Utc::now(),
+            headers: BTreeMap::new(),
+            last_modified: None,
+            channel_title: None,
+            logo_url: None,
+            site_url: None,
+            description: None,
+            enabled: true,
+            muted_keywords: Vec::new(),
+            max_items_per_refresh: None,
+            update_hints: None,
+            last_refreshed_at: None,
+        }
+    }
+
+    /// Returns the time at which the RSS feed was added
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the name shown in the terminal UI, the display name when set or else the name
+    pub fn label(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Checks whether the RSS feed is due for a refresh at given time, as per its update hints
+    ///
+    /// RSS feeds without update hints are always due.
+    pub fn is_refresh_due(&self, now: DateTime<Utc>) -> bool {
+        self.update_hints
+            .as_ref()
+            .is_none_or(|update_hints| update_hints.is_refresh_due(self.last_refreshed_at, now))
+    }
+
+    /// Returns the website of the RSS feed, or else the RSS feed URL when no channel link is known
+    pub fn website_url(&self) -> &str {
+        self.site_url.as_deref().unwrap_or(&self.url)
+    }
 }
 
 #[derive(PartialEq, Serialize, Deserialize, Clone, Debug)]
@@ -63,11 +235,85 @@ pub struct Articles {
     /// Article publishing date
     pub pub_date: DateTime<Utc>,
     created_at: DateTime<Utc>,
+    /// Flag for hiding the article from the articles list
+    #[serde(default)]
+    pub hidden: bool,
+    /// Full content of the article, when provided in addition to the summary
+    #[serde(default)]
+    pub full_content: Option<String>,
+    /// Timestamp at which the article was first viewed
+    #[serde(default)]
+    pub viewed_at: Option<DateTime<Utc>>,
+    /// Flag for articles already read (e.g. opened in the browser)
+    #[serde(default)]
+    pub read: bool,
+    /// Flag for bookmarked articles, which are left out of bulk maintenance actions
+    #[serde(default)]
+    pub bookmarked: bool,
+    /// Labels set on the article (e.g. "to-blog", "reference"), kept across refreshes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Time until which the article is left out of the articles list (see [`snooze_article`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<DateTime<Utc>>,
+}
+
+impl Articles {
+    /// Checks whether the article is still snoozed at given time, articles coming back at the
+    /// time they were snoozed until
+    pub fn is_snoozed(&self, now: DateTime<Utc>) -> bool {
+        self.snoozed_until
+            .is_some_and(|snoozed_until| snoozed_until > now)
+    }
 }
 
+#[derive(Default, Clone, Copy, Debug)]
+/// Defines the display options for rendering RSS feeds and articles in TUI
+pub struct RenderOptions {
+    /// Show the full content of the selected article instead of its summary, when available
+    pub show_full_content: bool,
+    /// Show a two-line preview of the summary beneath each title in the articles list
+    pub show_article_preview: bool,
+    /// Width of the RSS feeds list pane (including borders), used to truncate RSS feed names
+    pub feeds_pane_width: u16,
+    /// Width of the articles list pane (including borders), used to truncate previews
+    pub articles_pane_width: u16,
+    /// Width of the summary pane (including borders)
+    pub summary_pane_width: u16,
+    /// Height of the summary pane (including borders)
+    pub summary_pane_height: u16,
+    /// Number of lines scrolled in the summary pane, reset when another article is selected
+    pub summary_scroll: u16,
+    /// Article shown in the summary pane on the last render, used to reset the scroll
+    pub summary_article_id: Option<usize>,
+    /// Selected range (anchor and cursor character offsets) of the article body, while selecting text
+    pub text_selection: Option<(usize, usize)>,
+    /// Link of the article body focused for opening (see [`article_links`]), reset when another article is selected
+    pub focused_link: Option<usize>,
+    /// Text style of the article summary
+    pub summary_emphasis: SummaryEmphasis,
+    /// Flag for the RSS feeds list being focused (last key press applied to the RSS feeds)
+    pub feeds_focused: bool,
+}
+
+/// Maximum number of bytes of an RSS feed body kept by [`fetch_raw_feed`]
+pub const MAX_RAW_FEED_BYTES: usize = 512 * 1024;
+
+/// Number of characters of the summary used as title for articles without title
+const DERIVED_TITLE_LENGTH: usize = 80;
+
+/// Number of lines used by the article preview in the articles list
+const ARTICLE_PREVIEW_LINES: usize = 2;
+
+/// Flag for showing only the unread articles in the articles list
+static UNREAD_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Order of the RSS feeds list
+static FEED_SORT: RwLock<FeedSort> = RwLock::new(FeedSort::Insertion);
+
 /// Reads the RSS feed information from JSON files
 pub fn read_rss_db() -> Vec<RSSFeed> {
-    let db_content = fs::read_to_string(RSS_DB_PATH).unwrap_or_else(|_err| {
+    let db_content = fs::read_to_string(data_path(RSS_DB_FILE)).unwrap_or_else(|_err| {
         let err_msg = ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE);
         panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
     });
@@ -75,60 +321,226 @@ pub fn read_rss_db() -> Vec<RSSFeed> {
         let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
         panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
     });
-    parsed
+    merge_feed_layers(parsed)
+}
+
+/// Reads the RSS feeds in the order of the RSS feeds list (see [`set_feed_sort`])
+///
+/// Positions in the RSS feeds list are positions in this list. The welcome feed (first entry)
+/// always stays on top, and RSS feeds which compare equal keep their insertion order.
+pub fn read_rss_feed_list() -> Vec<RSSFeed> {
+    let mut rss_feed_list = read_rss_db();
+    if rss_feed_list.len() < 2 {
+        return rss_feed_list;
+    }
+
+    let rss_feeds = &mut rss_feed_list[1..];
+    match feed_sort() {
+        FeedSort::Insertion => {}
+        FeedSort::Name => rss_feeds.sort_by_cached_key(|r| r.label().to_lowercase()),
+        FeedSort::UnreadCount => {
+            let mut unread_counts: HashMap<usize, usize> = HashMap::new();
+            let now = Utc::now();
+            for article in read_articles_db()
+                .iter()
+                .filter(|r| !r.read && !r.hidden && !r.is_snoozed(now))
+            {
+                *unread_counts.entry(article.rss_id).or_default() += 1;
+            }
+            rss_feeds.sort_by_key(|r| {
+                std::cmp::Reverse(unread_counts.get(&r.rss_id).copied().unwrap_or_default())
+            });
+        }
+        FeedSort::LastRefreshed => {
+            rss_feeds.sort_by_key(|r| std::cmp::Reverse(r.last_refreshed_at))
+        }
+        FeedSort::Category => rss_feeds.sort_by_cached_key(|r| {
+            let category_levels: Vec<String> = category_path(&r.category)
+                .iter()
+                .map(|level| level.to_lowercase())
+                .collect();
+            (category_levels, r.label().to_lowercase())
+        }),
+    }
+    rss_feed_list
+}
+
+/// Sets the order of the RSS feeds list
+pub fn set_feed_sort(sort: FeedSort) {
+    if let Ok(mut feed_sort) = FEED_SORT.write() {
+        *feed_sort = sort;
+    }
+}
+
+/// Returns the order of the RSS feeds list
+pub fn feed_sort() -> FeedSort {
+    FEED_SORT
+        .read()
+        .map(|feed_sort| *feed_sort)
+        .unwrap_or_default()
+}
+
+/// Reads the RSS feed at given position of the RSS feeds list, if any
+fn read_listed_rss_feed(rss_list_state: &ListState) -> Option<RSSFeed> {
+    let selected = rss_list_state.selected()?;
+    read_rss_feed_list().into_iter().nth(selected)
+}
+
+/// Re-reads both JSON databases from disk, returning the number of RSS feeds and articles
+///
+/// The databases are read from disk on every render, so edits made outside of the application
+/// show up on their own; reloading checks that the files can still be parsed, without
+/// panicking when they can't.
+pub fn reload_db() -> Result<(usize, usize), ErrorMessages> {
+    let rss_feed_list: Vec<RSSFeed> = merge_feed_layers(read_db_file(&data_path(RSS_DB_FILE))?);
+    let articles_list: Vec<Articles> = read_db_file(&data_path(ARTICLE_DB_FILE))?;
+    Ok((rss_feed_list.len(), articles_list.len()))
+}
+
+/// Clears all the RSS feeds, articles, archived articles and suppressed links, after backing up
+/// the JSON files, returning the backup folder
+///
+/// The welcome feed (first entry) and its articles are kept, as the RSS feeds list expects it
+/// on top, and the base RSS feeds (see [`feed_layers`]) are restored. RSS ids are not reused
+/// after a reset. Nothing is reset when the backup fails.
+pub fn reset_db() -> Result<PathBuf, ErrorMessages> {
+    let _db_lock = lock_db_writes();
+    let backup_dir = Path::new(&data_path(DB_BACKUP_FOLDER))
+        .join(Utc::now().format("%Y%m%d_%H%M%S").to_string());
+    fs::create_dir_all(&backup_dir)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0049_DB_BACKUP_FAILURE))?;
+
+    for file_name in [
+        RSS_DB_FILE,
+        ARTICLE_DB_FILE,
+        ARCHIVE_DB_FILE,
+        FEED_TOMBSTONES_FILE,
+        FEED_ORDER_FILE,
+        SUPPRESSED_LINKS_FILE,
+    ] {
+        let file_path = data_path(file_name);
+        if Path::new(&file_path).exists() {
+            fs::copy(&file_path, backup_dir.join(file_name))
+                .map_err(|_err| ErrorMessages::new(ErrorCodes::E0049_DB_BACKUP_FAILURE))?;
+        }
+    }
+
+    let rss_feed_list: Vec<RSSFeed> = read_rss_db().into_iter().take(1).collect();
+    let articles_list: Vec<Articles> = read_articles_db()
+        .into_iter()
+        .filter(|r| {
+            rss_feed_list
+                .iter()
+                .any(|rss_feed| rss_feed.rss_id == r.rss_id)
+        })
+        .collect();
+
+    // Without tombstones, the base RSS feeds deleted locally are listed again, in the base order
+    if Path::new(&data_path(FEED_TOMBSTONES_FILE)).exists() {
+        write_db_file(&data_path(FEED_TOMBSTONES_FILE), &BTreeSet::<usize>::new());
+    }
+    if Path::new(&data_path(FEED_ORDER_FILE)).exists() {
+        write_db_file(&data_path(FEED_ORDER_FILE), &Vec::<usize>::new());
+    }
+    write_feed_layers(&merge_feed_layers(rss_feed_list));
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    if Path::new(&data_path(ARCHIVE_DB_FILE)).exists() {
+        write_db_file(&data_path(ARCHIVE_DB_FILE), &Vec::<Articles>::new());
+    }
+    if Path::new(&data_path(SUPPRESSED_LINKS_FILE)).exists() {
+        write_db_file(
+            &data_path(SUPPRESSED_LINKS_FILE),
+            &BTreeSet::<String>::new(),
+        );
+    }
+    info!(
+        "Reset the JSON files, backed up to {}",
+        backup_dir.display()
+    );
+    Ok(backup_dir)
+}
+
+fn read_db_file<T: DeserializeOwned>(file_path: &str) -> Result<T, ErrorMessages> {
+    let db_content = fs::read_to_string(file_path)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE))?;
+    serde_json::from_str(&db_content)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE))
+}
+
+/// Stores the RSS feed information, given as "<RSS category> | <RSS Name> | <RSS Url>", into JSON files
+pub async fn write_rss_db(input_text: String) -> Result<(), ErrorMessages> {
+    match input_text.split('|').collect::<Vec<&str>>()[..] {
+        [category, name, url] => add_feed(category, name, url).await,
+        _ => Err(ErrorMessages::new(ErrorCodes::E0031_INVALID_FEED_INPUT)),
+    }
 }
 
-/// Stores the RSS feed information into JSON files
-pub async fn write_rss_db(input_text: String) {
-    let split_parts = input_text.split("|").collect::<Vec<&str>>();
+/// Checks whether the given text is a valid HTTP(S) URL for an RSS feed
+pub fn is_valid_feed_url(url: &str) -> bool {
+    reqwest::Url::parse(url.trim())
+        .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+}
+
+/// Adds a new RSS feed into JSON files and refreshes its articles
+///
+/// Category and name are required, and the URL must be a valid HTTP(S) URL.
+pub async fn add_feed(category: &str, name: &str, url: &str) -> Result<(), ErrorMessages> {
+    if category.trim().is_empty() || name.trim().is_empty() || !is_valid_feed_url(url) {
+        return Err(ErrorMessages::new(ErrorCodes::E0031_INVALID_FEED_INPUT));
+    }
+
+    let db_lock = lock_db_writes();
     let mut parsed: Vec<RSSFeed> = read_rss_db();
-    let max_id = parsed
-        .iter()
-        .max_by_key(|p| p.rss_id)
-        .map(|p| p.rss_id)
-        .unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0017_RSS_MAX_ID_FETCH_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        });
+    let rss_id = next_rss_id(&parsed);
 
-    let new_entry = RSSFeed {
-        rss_id: max_id + 1,
-        category: split_parts[0].trim().to_string(),
-        name: split_parts[1].trim().to_string(),
-        url: split_parts[2].trim().to_string(),
-        created_at: Utc::now(),
-    };
+    parsed.push(RSSFeed::new(rss_id, category, name, url));
+    write_feed_layers(&parsed);
+    drop(db_lock);
 
-    parsed.push(new_entry);
+    let _ = refresh_rss_feed(rss_id).await;
+    Ok(())
+}
 
-    let parsed_serde: &Vec<u8> = &serde_json::to_vec(&parsed).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+/// Assigns the RSS id of the next RSS feed added, and records it as the last RSS id assigned
+///
+/// RSS ids only ever increase, even after the RSS feed with the highest id is deleted, so the
+/// articles left by a deleted RSS feed are never attributed to a new one. Without a recorded
+/// counter (e.g. first run), counting starts after the highest RSS id of both databases, or
+/// at 1 when both are empty. Callers are expected to hold the database write lock.
+fn next_rss_id(rss_feed_list: &[RSSFeed]) -> usize {
+    let max_id = rss_feed_list
+        .iter()
+        .map(|p| p.rss_id)
+        .max()
+        .unwrap_or_default();
 
-    fs::write(RSS_DB_PATH, parsed_serde).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+    let last_id = fs::read_to_string(data_path(RSS_ID_COUNTER_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str::<usize>(&content).ok())
+        .unwrap_or_else(|| {
+            read_articles_db()
+                .iter()
+                .map(|r| r.rss_id)
+                .max()
+                .unwrap_or_default()
+        });
 
-    let _ = write_articles_db(parsed.len() - 1).await;
+    let rss_id = max_id.max(last_id) + 1;
+    write_db_file(&data_path(RSS_ID_COUNTER_FILE), &rss_id);
+    rss_id
 }
 
 /// Delete given RSS feed data from JSON files
 pub fn update_rss_db(rss_list_state: &mut ListState) {
     if let Some(selected) = rss_list_state.selected() {
+        let _db_lock = lock_db_writes();
+        let rss_id = match read_listed_rss_feed(rss_list_state) {
+            Some(rss_feed) => rss_feed.rss_id,
+            None => return,
+        };
         let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
-        rss_feed_list.remove(selected);
-
-        let parsed_serde: &Vec<u8> = &serde_json::to_vec(&rss_feed_list).unwrap_or_else(|_err| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        });
-
-        fs::write(RSS_DB_PATH, parsed_serde).unwrap_or_else(|_err| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        });
+        rss_feed_list.retain(|r| r.rss_id != rss_id);
+        write_feed_layers(&rss_feed_list);
 
         if selected > 0 {
             rss_list_state.select(Some(selected - 1));
@@ -138,9 +550,182 @@ pub fn update_rss_db(rss_list_state: &mut ListState) {
     }
 }
 
+/// Moves the selected RSS feed one position up or down in the RSS feeds list
+///
+/// The feeds order is the order of the JSON file, so RSS feeds are only moved while the RSS
+/// feeds list is in insertion order. The welcome feed (first entry) always stays on top, and
+/// the selection follows the moved feed.
+pub fn move_rss_feed(rss_list_state: &mut ListState, move_up: bool) {
+    if feed_sort() != FeedSort::Insertion {
+        return;
+    }
+    if let Some(selected) = rss_list_state.selected() {
+        let _db_lock = lock_db_writes();
+        let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+
+        let target = match move_up {
+            true => selected.checked_sub(1),
+            false => selected.checked_add(1),
+        }
+        .filter(|target| *target > 0 && *target < rss_feed_list.len());
+
+        if let (true, Some(target)) = (selected > 0, target) {
+            rss_feed_list.swap(selected, target);
+            write_feed_layers(&rss_feed_list);
+            rss_list_state.select(Some(target));
+        }
+    }
+}
+
+/// Sets the display name of the selected RSS feed, clearing it when given an empty name
+///
+/// Returns whether the RSS feed was updated. The welcome feed (first entry) can't be renamed.
+pub fn set_rss_feed_display_name(rss_list_state: &ListState, display_name: &str) -> bool {
+    if rss_list_state
+        .selected()
+        .filter(|selected| *selected > 0)
+        .is_none()
+    {
+        return false;
+    }
+    let _db_lock = lock_db_writes();
+    let rss_id = match read_listed_rss_feed(rss_list_state) {
+        Some(rss_feed) => rss_feed.rss_id,
+        None => return false,
+    };
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+
+    match rss_feed_list.iter_mut().find(|r| r.rss_id == rss_id) {
+        Some(rss_feed) => {
+            let display_name = display_name.trim();
+            rss_feed.display_name = match display_name.is_empty() {
+                true => None,
+                false => Some(display_name.to_string()),
+            };
+            write_feed_layers(&rss_feed_list);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Renames a category on all the RSS feeds filed under it, returning the number of RSS feeds changed
+///
+/// The welcome feed (first entry) is left as is. Nothing is written when no RSS feed matches.
+pub fn rename_category(old: &str, new: &str) -> usize {
+    let (old, new) = (old.trim(), new.trim());
+    if new.is_empty() || old == new {
+        return 0;
+    }
+
+    let _db_lock = lock_db_writes();
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+    let mut num_renamed = 0;
+
+    for rss_feed in rss_feed_list.iter_mut().skip(1) {
+        if rss_feed.category == old {
+            rss_feed.category = new.to_string();
+            num_renamed += 1;
+        }
+    }
+
+    if num_renamed > 0 {
+        write_feed_layers(&rss_feed_list);
+    }
+    num_renamed
+}
+
+/// Copies given RSS feed (by RSS id) under another category, returning the RSS id of the copy
+///
+/// The copy shares the URL and settings of the RSS feed, but gets a new RSS id: its articles
+/// are fetched on their own on its next refresh, and read, bookmarked or hidden independently.
+/// Fails with `E0050_FEED_ALREADY_IN_CATEGORY` when an RSS feed with the same URL is already
+/// filed under the category.
+pub fn duplicate_rss_feed(rss_id: usize, category: &str) -> Result<usize, ErrorMessages> {
+    let category = category.trim();
+    if category.is_empty() {
+        return Err(ErrorMessages::new(ErrorCodes::E0031_INVALID_FEED_INPUT));
+    }
+
+    let _db_lock = lock_db_writes();
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+    let rss_feed = rss_feed_list
+        .iter()
+        .skip(1)
+        .find(|r| r.rss_id == rss_id)
+        .ok_or_else(|| ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE))?;
+    if rss_feed_list
+        .iter()
+        .any(|r| r.url == rss_feed.url && r.category == category)
+    {
+        return Err(ErrorMessages::new(
+            ErrorCodes::E0050_FEED_ALREADY_IN_CATEGORY,
+        ));
+    }
+
+    let duplicate_feed = RSSFeed {
+        rss_id: next_rss_id(&rss_feed_list),
+        category: category.to_string(),
+        created_at: Utc::now(),
+        last_modified: None,
+        last_refreshed_at: None,
+        ..rss_feed.clone()
+    };
+    let duplicate_rss_id = duplicate_feed.rss_id;
+    rss_feed_list.push(duplicate_feed);
+    write_feed_layers(&rss_feed_list);
+    Ok(duplicate_rss_id)
+}
+
+/// Sets the muted keywords of the selected RSS feed, given as a comma-separated list
+///
+/// Returns whether the RSS feed was updated. The welcome feed (first entry) can't be muted.
+pub fn set_rss_feed_muted_keywords(rss_list_state: &ListState, muted_keywords: &str) -> bool {
+    if rss_list_state
+        .selected()
+        .filter(|selected| *selected > 0)
+        .is_none()
+    {
+        return false;
+    }
+    let _db_lock = lock_db_writes();
+    let rss_id = match read_listed_rss_feed(rss_list_state) {
+        Some(rss_feed) => rss_feed.rss_id,
+        None => return false,
+    };
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+
+    match rss_feed_list.iter_mut().find(|r| r.rss_id == rss_id) {
+        Some(rss_feed) => {
+            rss_feed.muted_keywords = parse_keywords(muted_keywords);
+            write_feed_layers(&rss_feed_list);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Pauses the selected RSS feed, or resumes it, returning whether it is now enabled
+///
+/// Paused RSS feeds and their articles are kept, but they are skipped by refresh all. The
+/// welcome feed (first entry) can't be paused.
+pub fn toggle_rss_feed_enabled(rss_list_state: &ListState) -> Option<bool> {
+    rss_list_state.selected().filter(|selected| *selected > 0)?;
+    let _db_lock = lock_db_writes();
+    let rss_id = read_listed_rss_feed(rss_list_state)?.rss_id;
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+
+    let rss_feed = rss_feed_list.iter_mut().find(|r| r.rss_id == rss_id)?;
+    rss_feed.enabled = !rss_feed.enabled;
+    let enabled = rss_feed.enabled;
+
+    write_feed_layers(&rss_feed_list);
+    Some(enabled)
+}
+
 /// Reads the RSS articles information from JSON files
 pub fn read_articles_db() -> Vec<Articles> {
-    let db_content = fs::read_to_string(ARTICLE_DB_PATH).unwrap_or_else(|_err| {
+    let db_content = fs::read_to_string(data_path(ARTICLE_DB_FILE)).unwrap_or_else(|_err| {
         let err_msg = ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE);
         panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
     });
@@ -152,235 +737,2058 @@ pub fn read_articles_db() -> Vec<Articles> {
     parsed
 }
 
-/// Stores the RSS articles information into JSON files
-pub async fn write_articles_db(rss_selected: usize) {
-    let mut articles_list: Vec<Articles> = read_articles_db();
-    let rss_feed_list: Vec<RSSFeed> = read_rss_db();
+/// Defines the content fetched from an RSS feed, to be stored into JSON files
+pub struct FetchedFeed {
+    /// RSS feed the content was fetched from
+    pub rss_feed: RSSFeed,
+    /// Parsed RSS channel, or `None` if the feed was not modified since the last refresh
+    pub channel: Option<Channel>,
+    /// `Last-Modified` header returned by the server
+    pub last_modified: Option<String>,
+}
 
-    let selected_rss_feed = rss_feed_list
-        .get(rss_selected)
-        .unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        })
+/// Stores the RSS articles information of given RSS feed (by RSS id) into JSON files, returning
+/// the number of new articles
+///
+/// The RSS feed is fetched before taking the database write lock, so that other writers
+/// are not blocked on the network.
+pub async fn write_articles_db(rss_id: usize) -> Result<usize, ErrorMessages> {
+    let selected_rss_feed = read_rss_db()
+        .into_iter()
+        .find(|r| r.rss_id == rss_id)
+        .ok_or_else(|| ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE))?
         .clone();
 
-    let response = reqwest::get(selected_rss_feed.url)
+    let fetched_feed = fetch_refreshed_feed(selected_rss_feed).await?;
+
+    store_fetched_feeds(vec![fetched_feed])
+        .pop()
+        .map(|(_rss_id, result)| result)
+        .unwrap_or(Ok(0))
+}
+
+/// Fetches and parses given RSS feed over HTTP, without touching the JSON files
+pub async fn fetch_rss_feed(rss_feed: RSSFeed) -> Result<FetchedFeed, ErrorMessages> {
+    let response = http_client()
+        .get(&rss_feed.url)
+        .headers(build_request_headers(&rss_feed)?)
+        .send()
         .await
-        .unwrap_or_else(|_err| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0010_HTTP_REQUEST_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+        .map_err(|err| http_request_error(&err))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED {
+        let last_modified = rss_feed.last_modified.clone();
+        return Ok(FetchedFeed {
+            rss_feed,
+            channel: None,
+            last_modified,
         });
+    }
 
-    let content = response.bytes().await.unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0011_HTTP_RESPONSE_PARSE_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+    let status = response.status();
+    let headers = response.headers().clone();
 
-    let rss = Channel::read_from(&content[..]).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0012_RSS_CHANNEL_PARSE_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+    if !status.is_success() {
+        let content = read_response_body(response).await.unwrap_or_default();
+        if is_cloudflare_challenge(&headers, &content) {
+            return Err(ErrorMessages::new(ErrorCodes::E0047_CLOUDFLARE_CHALLENGE));
+        }
+        return Err(http_status_error(status));
+    }
 
-    let article_id = articles_list
-        .iter()
-        .max_by_key(|p| p.article_id)
-        .map(|p| p.article_id);
+    let last_modified = headers
+        .get(LAST_MODIFIED)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    let content = read_response_body(response).await?;
+
+    debug!(
+        "Received {} bytes from RSS feed \"{}\"",
+        content.len(),
+        rss_feed.name
+    );
+
+    if content.iter().all(|byte| byte.is_ascii_whitespace()) {
+        return Err(ErrorMessages::new(ErrorCodes::E0037_EMPTY_RESPONSE_BODY));
+    }
+
+    let channel =
+        Channel::read_from(&content[..]).map_err(|_err| {
+            match is_cloudflare_challenge(&headers, &content) {
+                true => ErrorMessages::new(ErrorCodes::E0047_CLOUDFLARE_CHALLENGE),
+                false => ErrorMessages::new(ErrorCodes::E0012_RSS_CHANNEL_PARSE_FAILURE),
+            }
+        })?;
+
+    Ok(FetchedFeed {
+        rss_feed,
+        channel: Some(channel),
+        last_modified,
+    })
+}
+
+/// Fetches given RSS feed for a refresh, recording the time taken and whether it succeeded in
+/// its fetch statistics
+async fn fetch_refreshed_feed(rss_feed: RSSFeed) -> Result<FetchedFeed, ErrorMessages> {
+    let rss_id = rss_feed.rss_id;
+    let started_at = std::time::Instant::now();
+    let result = fetch_rss_feed(rss_feed).await;
+    record_feed_fetch(rss_id, started_at.elapsed(), result.is_ok());
+    result
+}
+
+/// Fetches the latest articles of given RSS feed URL without subscribing to it
+///
+/// The articles returned are transient: nothing is written to the JSON files.
+pub async fn preview_feed(url: &str) -> Result<Vec<Articles>, ErrorMessages> {
+    if !is_valid_feed_url(url) {
+        return Err(ErrorMessages::new(ErrorCodes::E0031_INVALID_FEED_INPUT));
+    }
 
-    let mut article_id = match article_id {
-        Some(t) => t,
-        None => 0,
+    let fetched_feed = fetch_rss_feed(RSSFeed::new(0, "", "", url)).await?;
+    build_new_articles(&fetched_feed, &|_link| false, 0)
+}
+
+/// Fetches the body of given RSS feed as returned by the server, for troubleshooting
+///
+/// The request is sent with the same headers as a refresh (including the feed headers and
+/// their secrets), except `If-Modified-Since` so that the full body is always returned.
+/// The body is read in chunks and cut after [`MAX_RAW_FEED_BYTES`], with a note of the cut
+/// appended. Nothing is parsed or written to the JSON files.
+pub async fn fetch_raw_feed(rss_feed: &RSSFeed) -> Result<String, ErrorMessages> {
+    let unconditional_feed = RSSFeed {
+        last_modified: None,
+        ..rss_feed.clone()
     };
+    let mut response = http_client()
+        .get(rss_feed.url.trim())
+        .headers(build_request_headers(&unconditional_feed)?)
+        .send()
+        .await
+        .map_err(|err| http_request_error(&err))?;
 
-    for item in rss.items().iter() {
-        article_id += 1;
+    if !response.status().is_success() {
+        return Err(http_status_error(response.status()));
+    }
 
-        let title = match item.title() {
-            Some(t) => t,
-            None => "",
-        };
+    let mut content: Vec<u8> = Vec::new();
+    let mut truncated = false;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0011_HTTP_RESPONSE_PARSE_FAILURE))?
+    {
+        let remaining = MAX_RAW_FEED_BYTES - content.len();
+        if chunk.len() > remaining {
+            content.extend_from_slice(&chunk[..remaining]);
+            truncated = true;
+            break;
+        }
+        content.extend_from_slice(&chunk);
+    }
 
-        let summary = match item.description() {
-            Some(t) => t,
-            None => "",
-        };
+    let mut raw_feed = String::from_utf8_lossy(&content).into_owned();
+    if truncated {
+        raw_feed.push_str(&format!(
+            "\n\n[... truncated after {} KB]",
+            MAX_RAW_FEED_BYTES / 1024
+        ));
+    }
+    Ok(raw_feed)
+}
 
-        let article_link = match item.link() {
-            Some(t) => t,
-            None => "",
-        };
+/// Stores the articles of the fetched RSS feeds into JSON files in a single batch
+///
+/// The articles database is read and written once for the whole batch, and the links already
+/// stored (or archived) are indexed once, so that checking for duplicates doesn't scan the
+/// database for each article. Articles found in several RSS feeds of the batch are stored once,
+/// except in RSS feeds sharing the same URL (see [`duplicate_rss_feed`]), which each keep
+/// their own copy.
+///
+/// Returns the number of new articles for each RSS feed (by RSS id). A feed whose articles
+/// can't be parsed is reported as failed, and none of its articles are stored.
+pub fn store_fetched_feeds(
+    fetched_feeds: Vec<FetchedFeed>,
+) -> Vec<(usize, Result<usize, ErrorMessages>)> {
+    let db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+    let archive_list: Vec<Articles> = read_archive_db();
+    let mut alerted_titles: Vec<String> = Vec::new();
+    let mut article_id = articles_list
+        .iter()
+        .chain(archive_list.iter())
+        .map(|p| p.article_id)
+        .max()
+        .unwrap_or_default();
+    let mut link_owners: HashMap<String, BTreeSet<usize>> = HashMap::new();
+    for article in articles_list.iter().chain(archive_list.iter()) {
+        link_owners
+            .entry(article.article_link.clone())
+            .or_default()
+            .insert(article.rss_id);
+    }
+    let feed_urls: HashMap<usize, String> = rss_feed_list
+        .iter()
+        .map(|r| (r.rss_id, r.url.clone()))
+        .collect();
+    let suppressed_links = read_suppressed_links();
+    let mut rss_db_changed = false;
+    let mut results = Vec::with_capacity(fetched_feeds.len());
 
-        let pub_date = match item.pub_date() {
-            Some(t) => t,
-            None => "",
+    for fetched_feed in fetched_feeds.iter() {
+        let rss_id = fetched_feed.rss_feed.rss_id;
+        // Links stored by another RSS feed with the same URL (a copy) don't count as known
+        let is_known_link = |link: &str| {
+            suppressed_links.contains(link)
+                || link_owners.get(link).is_some_and(|owners| {
+                    owners.iter().any(|owner| {
+                        *owner == rss_id || feed_urls.get(owner) != Some(&fetched_feed.rss_feed.url)
+                    })
+                })
         };
+        let result = build_new_articles(fetched_feed, &is_known_link, article_id);
 
-        let author = match item.author() {
-            Some(t) => t,
-            None => "",
-        };
+        if let Ok(new_articles) = &result {
+            article_id += new_articles.len();
+            for article in new_articles.iter() {
+                link_owners
+                    .entry(article.article_link.clone())
+                    .or_default()
+                    .insert(rss_id);
+            }
+            articles_list.extend(new_articles.iter().cloned());
+            alerted_titles.extend(
+                new_articles
+                    .iter()
+                    .filter(|r| is_article_alerted(r))
+                    .map(|r| r.title.clone()),
+            );
 
-        if check_if_article_exists(&article_link, &articles_list) {
-            continue;
-        } else {
-            let new_article = Articles {
-                article_id,
-                rss_id: selected_rss_feed.rss_id,
-                title: title.to_string(),
-                summary: summary.to_string(),
-                article_link: article_link.to_string(),
-                author: author.to_string(),
-                pub_date: DateTime::from(DateTime::parse_from_rfc2822(pub_date).unwrap_or_else(
-                    |_err| {
-                        let err_msg =
-                            ErrorMessages::new(ErrorCodes::E0020_RFC2822_TIMESTAMP_PARSE_FAILURE);
-                        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                    },
-                )),
-                created_at: Utc::now(),
-            };
+            if let Some(rss_feed) = rss_feed_list.iter_mut().find(|r| r.rss_id == rss_id) {
+                rss_feed.last_refreshed_at = Some(Utc::now());
+                rss_db_changed = true;
+                if rss_feed.last_modified != fetched_feed.last_modified {
+                    rss_feed.last_modified = fetched_feed.last_modified.clone();
+                    rss_db_changed = true;
+                }
+                if let Some(channel) = &fetched_feed.channel {
+                    rss_db_changed |= update_channel_title(rss_feed, channel.title());
+
+                    let logo_url = channel
+                        .image()
+                        .map(|image| image.url().trim().to_string())
+                        .filter(|url| !url.is_empty());
+                    if rss_feed.logo_url != logo_url {
+                        rss_feed.logo_url = logo_url;
+                        rss_db_changed = true;
+                    }
+
+                    let site_url = Some(channel.link().trim().to_string())
+                        .filter(|url| is_valid_feed_url(url));
+                    if rss_feed.site_url != site_url {
+                        rss_feed.site_url = site_url;
+                        rss_db_changed = true;
+                    }
 
-            articles_list.push(new_article);
+                    let description = Some(channel.description().trim().to_string())
+                        .filter(|description| !description.is_empty());
+                    if rss_feed.description != description {
+                        rss_feed.description = description;
+                        rss_db_changed = true;
+                    }
+
+                    let update_hints = UpdateHints::from_channel(channel);
+                    if rss_feed.update_hints != update_hints {
+                        rss_feed.update_hints = update_hints;
+                        rss_db_changed = true;
+                    }
+                }
+            }
         }
+
+        results.push((rss_id, result.map(|new_articles| new_articles.len())));
     }
 
-    let parsed_serde: &Vec<u8> = &serde_json::to_vec(&articles_list).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+    let evicted_links = evict_articles(&mut articles_list, get_config().max_articles);
+    if !evicted_links.is_empty() {
+        info!(
+            "Evicted {} articles to keep at most {} articles",
+            evicted_links.len(),
+            get_config().max_articles
+        );
+        suppress_links(evicted_links);
+    }
 
-    fs::write(ARTICLE_DB_PATH, parsed_serde).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    if rss_db_changed {
+        write_feed_layers(&rss_feed_list);
+    }
+    drop(db_lock);
+
+    notify_alert_articles(&alerted_titles);
+    results
 }
 
-/// Renders the list of RSS feeds and articles, and articles summary in TUI
-pub fn render_rss_feed_list<'a>(
-    rss_list_state: &ListState,
-    article_list_state: &ListState,
-) -> (List<'a>, List<'a>, Paragraph<'a>) {
-    let rss_feed_list = read_rss_db();
+/// Removes articles until at most `max_articles` are left, returning the links of the articles
+/// removed
+///
+/// Eviction order:
+/// 1. Bookmarked articles are never evicted (so more than `max_articles` may be left)
+/// 2. Read or hidden articles are evicted before unread ones
+/// 3. Within each group, the oldest articles (by publishing date) are evicted first
+///
+/// A `max_articles` of 0 keeps all articles. The links of evicted articles are added to the
+/// suppression set by the caller (see [`suppressed_links`]), so that articles still listed in
+/// their RSS feed are not added back on the next refresh.
+fn evict_articles(articles_list: &mut Vec<Articles>, max_articles: usize) -> Vec<String> {
+    if max_articles == 0 || articles_list.len() <= max_articles {
+        return Vec::new();
+    }
 
-    let rss_feeds = Block::default()
-        .borders(Borders::ALL)
-        .style(Style::default().fg(Color::White))
-        .title("RSS Feeds")
-        .border_type(BorderType::Plain);
+    let mut candidates: Vec<&Articles> = articles_list.iter().filter(|r| !r.bookmarked).collect();
+    candidates.sort_by_key(|r| (!(r.read || r.hidden), r.pub_date));
 
-    let items: Vec<_> = rss_feed_list
+    let num_evicted = (articles_list.len() - max_articles).min(candidates.len());
+    let evicted_ids: BTreeSet<usize> = candidates
         .iter()
-        .map(|feed| {
-            ListItem::new(Spans::from(vec![Span::styled(
-                feed.name.clone(),
-                Style::default(),
-            )]))
-        })
+        .take(num_evicted)
+        .map(|r| r.article_id)
         .collect();
 
-    let rss_list = List::new(items).block(rss_feeds).highlight_style(
-        Style::default()
-            .bg(Color::Yellow)
-            .fg(Color::Black)
-            .add_modifier(Modifier::BOLD),
-    );
+    let mut evicted_links = Vec::with_capacity(num_evicted);
+    articles_list.retain(|r| match evicted_ids.contains(&r.article_id) {
+        true => {
+            evicted_links.push(r.article_link.clone());
+            false
+        }
+        false => true,
+    });
+    evicted_links
+}
 
-    let selected_rss_feed = rss_feed_list
-        .get(rss_list_state.selected().unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        }))
-        .unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0014_RSS_LIST_READ_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        })
-        .clone();
+/// Stores the channel title of an RSS feed, returning whether the RSS feed was changed
+///
+/// When `auto_rename_feeds` is set in config, an RSS feed whose channel title changed since
+/// the last refresh (e.g. site rebrand) is renamed after the new title. RSS feeds are never
+/// renamed on their first refresh, so names chosen when adding the RSS feed are kept, except
+/// for RSS feeds named after their URL (added from a feed list by URL only).
+fn update_channel_title(rss_feed: &mut RSSFeed, channel_title: &str) -> bool {
+    let channel_title = channel_title.trim();
+    if channel_title.is_empty() || rss_feed.channel_title.as_deref() == Some(channel_title) {
+        return false;
+    }
 
-    let mut rss_articles_list: Vec<Articles> = read_articles_db()
-        .into_iter()
-        .filter(|r| r.rss_id == selected_rss_feed.rss_id)
-        .collect();
+    match (&rss_feed.channel_title, get_config().auto_rename_feeds) {
+        (None, _) if rss_feed.name == rss_feed.url => {
+            info!(
+                "Named RSS feed \"{}\" after its channel title \"{}\"",
+                rss_feed.url, channel_title
+            );
+            rss_feed.name = channel_title.to_string();
+        }
+        (Some(_), true) => {
+            info!(
+                "Renamed RSS feed \"{}\" to \"{}\" after its channel title changed",
+                rss_feed.name, channel_title
+            );
+            rss_feed.name = channel_title.to_string();
+        }
+        (Some(_), false) if rss_feed.name != channel_title => info!(
+            "Channel title of RSS feed \"{}\" changed to \"{}\" (set auto_rename_feeds to rename it)",
+            rss_feed.name, channel_title
+        ),
+        _ => {}
+    }
 
-    rss_articles_list.sort_by_key(|r| std::cmp::Reverse(r.pub_date));
+    rss_feed.channel_title = Some(channel_title.to_string());
+    true
+}
 
-    let articles = Block::default()
-        .borders(Borders::ALL)
-        .style(Style::default().fg(Color::White))
-        .title("Articles")
-        .border_type(BorderType::Plain);
+/// Builds the articles of a fetched RSS feed whose links are not known (e.g. links of the
+/// stored and archived articles)
+fn build_new_articles(
+    fetched_feed: &FetchedFeed,
+    is_known_link: &dyn Fn(&str) -> bool,
+    max_article_id: usize,
+) -> Result<Vec<Articles>, ErrorMessages> {
+    let mut new_articles: Vec<Articles> = Vec::new();
+    let mut new_links: HashSet<String> = HashSet::new();
 
-    let items: Vec<_> = rss_articles_list
-        .iter()
-        .map(|feed| {
-            ListItem::new(Spans::from(vec![Span::styled(
-                feed.title.clone(),
-                Style::default(),
-            )]))
-        })
-        .collect();
+    let channel = match &fetched_feed.channel {
+        Some(channel) => channel,
+        None => return Ok(new_articles),
+    };
 
-    let article_list = List::new(items).block(articles).highlight_style(
-        Style::default()
-            .bg(Color::Yellow)
-            .fg(Color::Black)
-            .add_modifier(Modifier::BOLD),
-    );
+    // Items already stored among the first ones are skipped below, so they are not added again
+    let max_items = fetched_feed
+        .rss_feed
+        .max_items_per_refresh
+        .unwrap_or(usize::MAX);
 
-    let selected_article = rss_articles_list
-        .get(article_list_state.selected().unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    for item in channel.items().iter().take(max_items) {
+        let raw_article_link = item.link().unwrap_or_default();
+        let pub_date = parse_pub_date(item.pub_date().unwrap_or_default());
+
+        // Full article provided by the `<content:encoded>` element (e.g. WordPress feeds)
+        let full_content = item
+            .content()
+            .filter(|content| !content.trim().is_empty())
+            .map(|content| content.to_string());
+
+        let summary = item
+            .description()
+            .filter(|description| !description.trim().is_empty())
+            .or(full_content.as_deref())
+            .unwrap_or_default()
+            .to_string();
+
+        let title = match item
+            .title()
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+        {
+            Some(title) => title.to_string(),
+            None => derive_article_title(
+                &summary,
+                item.pub_date()
+                    .filter(|date| !date.trim().is_empty())
+                    .map(|_| &pub_date),
+            ),
+        };
+
+        let mut new_article = Articles {
+            article_id: max_article_id + new_articles.len() + 1,
+            rss_id: fetched_feed.rss_feed.rss_id,
+            title,
+            summary,
+            article_link: raw_article_link.to_string(),
+            author: item.author().unwrap_or_default().to_string(),
+            pub_date,
+            created_at: Utc::now(),
+            hidden: false,
+            full_content,
+            viewed_at: None,
+            read: false,
+            bookmarked: false,
+            tags: Vec::new(),
+            snoozed_until: None,
+        };
+
+        // Duplicates are checked on both the link as fetched and the transformed link
+        apply_article_transforms(&mut new_article);
+        let article_link = &new_article.article_link;
+        if is_known_link(raw_article_link)
+            || is_known_link(article_link)
+            || new_links.contains(article_link)
+        {
+            continue;
+        }
+
+        new_links.insert(article_link.clone());
+        new_articles.push(new_article);
+    }
+
+    Ok(new_articles)
+}
+
+/// Derives a title for articles without one (common in microblog feeds), so that they don't
+/// show as blank rows in the articles list
+///
+/// Uses the beginning of the summary, else the publishing date, else "(untitled)".
+fn derive_article_title(summary: &str, pub_date: Option<&DateTime<Utc>>) -> String {
+    let summary = clean_html(summary);
+    let summary = summary.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    if summary.chars().count() > DERIVED_TITLE_LENGTH {
+        let excerpt: String = summary.chars().take(DERIVED_TITLE_LENGTH).collect();
+        return format!("{}…", excerpt.trim_end());
+    }
+    if !summary.is_empty() {
+        return summary;
+    }
+    match pub_date {
+        Some(pub_date) => format_timestamp(pub_date),
+        None => String::from("(untitled)"),
+    }
+}
+
+/// Builds the HTTP request headers for refreshing given RSS feed
+///
+/// Starts from the default `Accept` header, and the `If-Modified-Since` header with the
+/// stored `Last-Modified` value of the feed, which can be overridden by the feed headers.
+/// Secret references in the feed headers (e.g. `${NEWS_TOKEN}`) are resolved, see [`secrets`].
+fn build_request_headers(rss_feed: &RSSFeed) -> Result<HeaderMap, ErrorMessages> {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static(DEFAULT_ACCEPT_HEADER));
+
+    if let Some(last_modified) = &rss_feed.last_modified {
+        if let Ok(header_value) = HeaderValue::from_str(last_modified) {
+            headers.insert(IF_MODIFIED_SINCE, header_value);
+        }
+    }
+
+    for (name, value) in rss_feed.headers.iter() {
+        let header_name = HeaderName::from_bytes(name.trim().as_bytes())
+            .map_err(|_err| ErrorMessages::new(ErrorCodes::E0030_INVALID_REQUEST_HEADER))?;
+        let header_value = HeaderValue::from_str(resolve_secret_references(value)?.trim())
+            .map_err(|_err| ErrorMessages::new(ErrorCodes::E0030_INVALID_REQUEST_HEADER))?;
+        headers.insert(header_name, header_value);
+    }
+
+    Ok(headers)
+}
+
+/// Refreshes the articles of given RSS feed (by RSS id) and records the outcome in the feed status
+///
+/// Refreshes of an RSS feed which is already being refreshed are ignored, and return
+/// `E0029_FEED_REFRESH_IN_PROGRESS` without touching the articles database.
+pub async fn refresh_rss_feed(rss_id: usize) -> Result<usize, ErrorMessages> {
+    let rss_feed = read_rss_db()
+        .into_iter()
+        .find(|r| r.rss_id == rss_id)
+        .ok_or_else(|| ErrorMessages::new(ErrorCodes::E0014_RSS_LIST_READ_FAILURE))?;
+
+    let _refresh_guard = begin_feed_refresh(rss_feed.rss_id)
+        .ok_or_else(|| ErrorMessages::new(ErrorCodes::E0029_FEED_REFRESH_IN_PROGRESS))?;
+
+    let result = write_articles_db(rss_id).await;
+    record_refresh_result(&rss_feed, &result);
+    if let Ok(new_articles) = result {
+        notify_new_articles(&[(rss_feed.label().to_string(), new_articles)]);
+    }
+    result
+}
+
+/// Logs the outcome of an RSS feed refresh, records it in the feed status and in the refresh history
+fn record_refresh_result(rss_feed: &RSSFeed, result: &Result<usize, ErrorMessages>) {
+    match result {
+        Ok(new_articles) => {
+            info!(
+                "Refreshed RSS feed \"{}\" ({} new articles)",
+                rss_feed.name, new_articles
+            );
+            clear_feed_error(rss_feed.rss_id);
+        }
+        Err(err_msg) => {
+            error!(
+                "Unable to refresh RSS feed \"{}\": {:?} - {}",
+                rss_feed.name, err_msg.error_code, err_msg.error_message
+            );
+            record_feed_error(rss_feed, err_msg);
+        }
+    }
+
+    log_refresh_outcome(&RefreshOutcome::new(rss_feed, result));
+}
+
+/// Reads the RSS feed currently selected in the RSS feeds list
+pub fn read_selected_rss_feed(rss_list_state: &ListState) -> RSSFeed {
+    read_rss_feed_list()
+        .get(rss_list_state.selected().unwrap_or_else(|| {
+            let err_msg = ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
+            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
         }))
         .unwrap_or_else(|| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0013_ARTICLES_LIST_READ_FAILURE);
+            let err_msg = ErrorMessages::new(ErrorCodes::E0014_RSS_LIST_READ_FAILURE);
             panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
         })
-        .clone();
+        .clone()
+}
+
+/// Shows only the unread articles in the articles list, or all of them again
+pub fn set_unread_only(unread_only: bool) {
+    UNREAD_ONLY.store(unread_only, Ordering::Relaxed);
+}
+
+/// Returns whether only the unread articles are shown in the articles list
+pub fn is_unread_only() -> bool {
+    UNREAD_ONLY.load(Ordering::Relaxed)
+}
+
+/// Reads the articles of given RSS feed as shown in the articles list (latest first)
+///
+/// Read articles are left out while only the unread articles are shown (see [`set_unread_only`]),
+/// and so are the articles matching the muted keywords of the RSS feed (see [`keywords`]).
+pub fn read_rss_articles(rss_id: usize) -> Vec<Articles> {
+    read_listed_articles(rss_id).0
+}
+
+/// Reads the articles of given RSS feed as shown in the articles list, along with the number
+/// of articles left out as muted
+fn read_listed_articles(rss_id: usize) -> (Vec<Articles>, usize) {
+    match read_rss_db().into_iter().find(|r| r.rss_id == rss_id) {
+        Some(rss_feed) => list_feed_articles(&rss_feed, &read_articles_db()),
+        None => (Vec::new(), 0),
+    }
+}
+
+/// Lists the articles of given RSS feed among given articles as shown in the articles list,
+/// along with the number of articles left out as muted
+fn list_feed_articles(rss_feed: &RSSFeed, articles_list: &[Articles]) -> (Vec<Articles>, usize) {
+    let show_hidden = get_config().show_hidden_articles;
+    let unread_only = is_unread_only();
+    let now = Utc::now();
+    let muted_keywords = match show_hidden {
+        true => Vec::new(),
+        false => muted_keywords(rss_feed),
+    };
+
+    let (mut rss_articles_list, muted_articles): (Vec<Articles>, Vec<Articles>) = articles_list
+        .iter()
+        .filter(|r| {
+            r.rss_id == rss_feed.rss_id
+                && (show_hidden || !r.hidden)
+                && !(unread_only && r.read)
+                && !r.is_snoozed(now)
+        })
+        .cloned()
+        .partition(|r| !is_article_muted(r, &muted_keywords));
+
+    rss_articles_list.sort_by_key(|r| std::cmp::Reverse(r.pub_date));
+    (rss_articles_list, muted_articles.len())
+}
+
+/// Reads the unread articles of given RSS feed, in the order of the articles list
+pub fn read_unread_articles(rss_id: usize) -> Vec<Articles> {
+    read_rss_articles(rss_id)
+        .into_iter()
+        .filter(|r| !r.read)
+        .collect()
+}
+
+/// Finds the next (or previous) RSS feed with unread articles after the selected RSS feed, in the
+/// order of the RSS feeds list
+///
+/// Returns the position of the RSS feed in the RSS feeds list along with the position of its first
+/// unread article in the articles list. Past the last (or first) RSS feed, the search wraps around
+/// only when `wrap` is set. The welcome feed (first entry) is skipped.
+pub fn find_unread_feed(
+    rss_list_state: &ListState,
+    forward: bool,
+    wrap: bool,
+) -> Option<(usize, usize)> {
+    let rss_feed_list = read_rss_feed_list();
+    let num_rss_feeds = rss_feed_list.len();
+    let selected = rss_list_state.selected().unwrap_or(0);
+
+    let positions: Vec<usize> = match (forward, wrap) {
+        (true, true) => (selected + 1..num_rss_feeds).chain(1..selected).collect(),
+        (true, false) => (selected + 1..num_rss_feeds).collect(),
+        (false, true) => (1..selected)
+            .rev()
+            .chain((selected + 1..num_rss_feeds).rev())
+            .collect(),
+        (false, false) => (1..selected).rev().collect(),
+    };
+
+    positions.into_iter().find_map(|position| {
+        read_rss_articles(rss_feed_list[position].rss_id)
+            .iter()
+            .position(|r| !r.read)
+            .map(|article_selected| (position, article_selected))
+    })
+}
+
+/// Marks given articles as read
+pub fn mark_articles_read(article_ids: &[usize]) {
+    set_articles_read(article_ids, true);
+}
+
+/// Marks given articles as unread, e.g. for revisiting them later
+///
+/// Articles stay unread until marked as read again, be it manually or by opening them.
+pub fn mark_articles_unread(article_ids: &[usize]) {
+    set_articles_read(article_ids, false);
+}
+
+/// Marks given article as read when unread and the other way round, returning whether it is
+/// now read
+pub fn toggle_article_read(article_id: usize) -> bool {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    let read = match articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        Some(article) => {
+            article.read = !article.read;
+            article.read
+        }
+        None => return false,
+    };
+
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    read
+}
+
+fn set_articles_read(article_ids: &[usize], read: bool) {
+    if article_ids.is_empty() {
+        return;
+    }
+
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    for article in articles_list
+        .iter_mut()
+        .filter(|r| article_ids.contains(&r.article_id))
+    {
+        article.read = read;
+    }
+
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+}
+
+/// Marks the articles published before given cutoff as read, returning the number of articles marked
+///
+/// Applies to the articles of given RSS feed, or to all RSS feeds when `rss_id` is `None`.
+/// Bookmarked articles are left untouched, and so are articles published at the cutoff itself.
+pub fn mark_read_before(rss_id: Option<usize>, cutoff: DateTime<Utc>) -> usize {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+    let mut num_marked: usize = 0;
+
+    for article in articles_list.iter_mut().filter(|r| {
+        rss_id.is_none_or(|rss_id| r.rss_id == rss_id)
+            && r.pub_date < cutoff
+            && !r.read
+            && !r.bookmarked
+    }) {
+        article.read = true;
+        num_marked += 1;
+    }
+
+    if num_marked > 0 {
+        write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    }
+    num_marked
+}
+
+/// Parses a duration made of a number and a unit (e.g. "12h", "7d" or "2w")
+///
+/// Supported units are hours (h), days (d) and weeks (w).
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim();
+    let unit = input.chars().last()?;
+    let amount: i64 = input[..input.len() - unit.len_utf8()].trim().parse().ok()?;
+
+    if amount <= 0 {
+        return None;
+    }
+
+    match unit.to_ascii_lowercase() {
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Bookmarks given article, or removes its bookmark, returning whether it is now bookmarked
+pub fn toggle_article_bookmark(article_id: usize) -> bool {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    let bookmarked = match articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        Some(article) => {
+            article.bookmarked = !article.bookmarked;
+            article.bookmarked
+        }
+        None => return false,
+    };
+
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    bookmarked
+}
+
+/// Snoozes given article until given time, or wakes it up right away with `None`, returning
+/// whether the article was found
+///
+/// Snoozed articles are left out of the articles list until then, and come back unread.
+pub fn snooze_article(article_id: usize, snoozed_until: Option<DateTime<Utc>>) -> bool {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    match articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        Some(article) => {
+            article.snoozed_until = snoozed_until;
+            article.read = false;
+            write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Reads the articles still snoozed, the ones coming back first on top
+pub fn read_snoozed_articles() -> Vec<Articles> {
+    let now = Utc::now();
+    let mut snoozed_articles: Vec<Articles> = read_articles_db()
+        .into_iter()
+        .filter(|r| r.is_snoozed(now))
+        .collect();
+
+    snoozed_articles.sort_by_key(|r| r.snoozed_until);
+    snoozed_articles
+}
+
+/// Counts the articles added since given time, and the RSS feeds they belong to
+pub fn count_new_articles_since(since: DateTime<Utc>) -> (usize, usize) {
+    let new_articles: Vec<Articles> = read_articles_db()
+        .into_iter()
+        .filter(|r| r.created_at > since && !r.hidden)
+        .collect();
+    let num_feeds = new_articles
+        .iter()
+        .map(|r| r.rss_id)
+        .collect::<BTreeSet<usize>>()
+        .len();
+
+    (new_articles.len(), num_feeds)
+}
+
+/// Reads the article currently selected in the articles list, if any
+pub fn read_selected_article(
+    rss_list_state: &ListState,
+    article_list_state: &ListState,
+) -> Option<Articles> {
+    let selected_rss_feed = read_selected_rss_feed(rss_list_state);
+    let selected = article_list_state.selected()?;
+    read_rss_articles(selected_rss_feed.rss_id)
+        .into_iter()
+        .nth(selected)
+}
+
+/// Adds a tag to given article, returning whether the tag was added
+///
+/// Tags are trimmed, and a tag already set on the article is not added twice.
+pub fn tag_article(article_id: usize, tag: &str) -> bool {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return false;
+    }
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    match articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        Some(article) if !article.tags.iter().any(|t| t == tag) => {
+            article.tags.push(tag.to_string());
+            write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Removes a tag from given article, returning whether the tag was removed
+pub fn untag_article(article_id: usize, tag: &str) -> bool {
+    let tag = tag.trim();
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    match articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        Some(article) if article.tags.iter().any(|t| t == tag) => {
+            article.tags.retain(|t| t != tag);
+            write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Reads the articles with given tag across all the RSS feeds (latest first)
+pub fn read_tagged_articles(tag: &str) -> Vec<Articles> {
+    let tag = tag.trim();
+    let mut tagged_articles: Vec<Articles> = read_articles_db()
+        .into_iter()
+        .filter(|r| r.tags.iter().any(|t| t == tag))
+        .collect();
+
+    tagged_articles.sort_by_key(|r| std::cmp::Reverse(r.pub_date));
+    tagged_articles
+}
+
+/// Lists the tags set on articles, along with their number of articles, ordered by tag
+pub fn read_article_tags() -> Vec<(String, usize)> {
+    let mut tags: BTreeMap<String, usize> = BTreeMap::new();
+    for article in read_articles_db().iter() {
+        for tag in article.tags.iter() {
+            *tags.entry(tag.clone()).or_default() += 1;
+        }
+    }
+    tags.into_iter().collect()
+}
+
+/// Hides given article from the articles list
+///
+/// The article link is added to the suppression set (see [`suppressed_links`]), so that the
+/// article is not added back on refresh, even once removed from the JSON files.
+pub fn hide_article(article_id: usize) {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    if let Some(article) = articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id)
+    {
+        article.hidden = true;
+        suppress_links([article.article_link.clone()]);
+    }
+
+    write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+}
+
+/// Records the first time given article is viewed
+///
+/// Articles which were already viewed keep their original `viewed_at` timestamp.
+pub fn mark_article_viewed(article_id: usize) {
+    let _db_lock = lock_db_writes();
+    let mut articles_list: Vec<Articles> = read_articles_db();
+
+    if let Some(article) = articles_list
+        .iter_mut()
+        .find(|r| r.article_id == article_id && r.viewed_at.is_none())
+    {
+        article.viewed_at = Some(Utc::now());
+        write_db_file(&data_path(ARTICLE_DB_FILE), &articles_list);
+    }
+}
+
+/// Renders the list of RSS feeds, the articles table and articles summary in TUI
+///
+/// The RSS feeds are given in the order of the RSS feeds list (see [`read_rss_feed_list`]),
+/// along with all the stored articles (see [`read_articles_db`]). The articles table is laid
+/// out with `article_column_widths`, as returned by [`article_column_widths`] for the columns
+/// of [`article_columns`].
+pub fn render_rss_feed_list<'a>(
+    rss_feed_list: &[RSSFeed],
+    articles_list: &[Articles],
+    rss_list_state: &ListState,
+    article_list_state: &ListState,
+    render_options: &mut RenderOptions,
+    article_column_widths: &'a [Constraint],
+) -> (List<'a>, Table<'a>, Paragraph<'a>) {
+    let rss_feeds_title = match feed_sort() {
+        FeedSort::Insertion => String::from("RSS Feeds"),
+        sort => format!("RSS Feeds (by {})", sort.label()),
+    };
+
+    let rss_feeds = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White))
+        .title(rss_feeds_title)
+        .border_type(BorderType::Plain);
+
+    // Sorted by category, the RSS feeds list shows the tree of the categories: each RSS feed
+    // starting a category carries the headers of the category levels it opens
+    let category_tree = feed_sort() == FeedSort::Category;
+    let mut previous_path: Vec<&str> = Vec::new();
+    let article_counts = count_listed_articles(articles_list);
+    let feeds_width = (render_options.feeds_pane_width as usize).saturating_sub(2);
+
+    let items: Vec<_> = rss_feed_list
+        .iter()
+        .enumerate()
+        .map(|(idx, feed)| {
+            let mut lines: Vec<Spans> = Vec::new();
+            let mut indent = String::new();
+
+            if category_tree && idx > 0 {
+                let path = category_path(&feed.category);
+                let num_shared = path
+                    .iter()
+                    .zip(previous_path.iter())
+                    .take_while(|(level, previous_level)| level == previous_level)
+                    .count();
+                for (depth, level) in path.iter().enumerate().skip(num_shared) {
+                    lines.push(Spans::from(vec![Span::styled(
+                        format!("{}▾ {}", "  ".repeat(depth), level),
+                        Style::default()
+                            .fg(Color::LightCyan)
+                            .add_modifier(Modifier::BOLD),
+                    )]));
+                }
+                indent = "  ".repeat(path.len());
+                previous_path = path;
+            }
+
+            let (label_style, paused_note) = match feed.enabled {
+                true => (Style::default(), ""),
+                false => (get_config().theme.paused_style(), " (paused)"),
+            };
+            let (num_unread, num_articles) = article_counts
+                .get(&feed.rss_id)
+                .copied()
+                .unwrap_or_default();
+            let count_badge = match get_config().feed_count_format {
+                FeedCountFormat::Unread if num_unread == 0 => String::new(),
+                FeedCountFormat::Unread => format!(" {}", num_unread),
+                FeedCountFormat::Total => format!(" {}", num_articles),
+                FeedCountFormat::UnreadTotal => format!(" {}/{}", num_unread, num_articles),
+            };
+
+            // The RSS feed name gives way to the count first, the count is only dropped on
+            // panes too narrow for both
+            let label = format!("{}{}", indent, feed.label());
+            let suffix_width = paused_note.width() + count_badge.width();
+            let (label, count_badge) = match feeds_width {
+                0 => (label, count_badge),
+                width if label.width() + suffix_width <= width => (label, count_badge),
+                width if width >= suffix_width + indent.width() + 2 => {
+                    (truncate_to_width(&label, width - suffix_width), count_badge)
+                }
+                width => (truncate_to_width(&label, width), String::new()),
+            };
+
+            let mut spans = vec![Span::styled(label, label_style)];
+            if !paused_note.is_empty() {
+                spans.push(Span::styled(
+                    paused_note,
+                    Style::default().add_modifier(Modifier::DIM),
+                ));
+            }
+            if !count_badge.is_empty() {
+                spans.push(Span::styled(count_badge, Style::default().fg(Color::Gray)));
+            }
+            lines.push(Spans::from(spans));
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let rss_list = List::new(items)
+        .block(rss_feeds)
+        .highlight_style(get_config().theme.highlight_style());
+
+    // The selection may be stale (e.g. RSS feeds removed outside of the application), so
+    // missing RSS feeds and articles render as empty panes instead of failing
+    let selected_rss_feed: Option<RSSFeed> = rss_list_state
+        .selected()
+        .and_then(|selected| rss_feed_list.get(selected))
+        .cloned();
+    let (rss_articles_list, num_muted) = match &selected_rss_feed {
+        Some(rss_feed) => list_feed_articles(rss_feed, articles_list),
+        None => (Vec::new(), 0),
+    };
+
+    let mut title_notes: Vec<String> = Vec::new();
+    if is_unread_only() {
+        title_notes.push(String::from("unread only"));
+    }
+    if num_muted > 0 {
+        title_notes.push(format!("{} muted", num_muted));
+    }
+    let articles = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White))
+        .title(match title_notes.is_empty() {
+            true => String::from("Articles"),
+            false => format!("Articles ({})", title_notes.join(", ")),
+        })
+        .border_type(BorderType::Plain);
+
+    let columns = article_columns();
+    let preview_width = (render_options.articles_pane_width as usize).saturating_sub(4);
+
+    let rows: Vec<_> = rss_articles_list
+        .iter()
+        .map(|feed| {
+            let theme = &get_config().theme;
+            let mut title_style = match feed.read {
+                true => theme.read_style(),
+                false => theme.unread_style(),
+            };
+            let mut title = match feed.bookmarked {
+                true => format!("{} {}", theme.bookmark_marker, feed.title),
+                false => feed.title.clone(),
+            };
+            if is_article_alerted(feed) {
+                title = format!("{} {}", theme.alert_marker, title);
+                title_style = title_style.fg(theme.alert_color());
+            }
+            let mut lines = vec![Spans::from(vec![Span::styled(title, title_style)])];
+
+            if render_options.show_article_preview {
+                lines.extend(
+                    article_preview(&feed.summary, preview_width)
+                        .into_iter()
+                        .map(|line| {
+                            Spans::from(vec![Span::styled(
+                                format!("  {}", line),
+                                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                            )])
+                        }),
+                );
+            }
+
+            let row_height = lines.len() as u16;
+            let mut title_lines = Some(lines);
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|column| match column {
+                    ArticleColumn::Date => {
+                        Cell::from(article_date_label(feed)).style(Style::default().fg(Color::Gray))
+                    }
+                    // Left out of the articles pane, see `article_columns`
+                    ArticleColumn::Source => Cell::default(),
+                    ArticleColumn::Title => Cell::from(title_lines.take().unwrap_or_default()),
+                })
+                .collect();
+
+            Row::new(cells).height(row_height)
+        })
+        .collect();
+
+    let article_list = match rows.is_empty() {
+        true => Table::new(vec![Row::new(vec![Cell::from(Span::styled(
+            empty_articles_message(selected_rss_feed.as_ref(), articles_list),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        ))])])
+        .block(articles)
+        .widths(&[Constraint::Percentage(100)]),
+        false => {
+            let mut article_table = Table::new(rows)
+                .block(articles)
+                .widths(article_column_widths)
+                .column_spacing(1)
+                .highlight_style(get_config().theme.highlight_style());
+
+            if columns != [ArticleColumn::Title] {
+                article_table = article_table.header(
+                    Row::new(columns.iter().map(|column| column.label()))
+                        .style(Style::default().add_modifier(Modifier::UNDERLINED)),
+                );
+            }
+
+            article_table
+        }
+    };
+
+    let selected_article = article_list_state
+        .selected()
+        .and_then(|selected| rss_articles_list.get(selected));
+
+    let show_full_content = render_options.show_full_content
+        && selected_article.is_some_and(|r| r.full_content.is_some());
+
+    let summary_title = match (render_options.show_full_content, show_full_content) {
+        _ if render_options.text_selection.is_some() => {
+            "Select Text (arrows/hjkl: extend, y: copy, Esc: cancel)"
+        }
+        _ if render_options.focused_link.is_some() => {
+            "Links (F: next link, Enter: open, Esc: done)"
+        }
+        (false, _) => "Summary (Tab: full content)",
+        (true, true) => "Full Content (Tab: summary)",
+        (true, false) => "Summary (full content unavailable)",
+    };
+
+    let selected_article_id = selected_article.map(|r| r.article_id);
+    if render_options.summary_article_id != selected_article_id {
+        render_options.summary_article_id = selected_article_id;
+        render_options.summary_scroll = 0;
+        render_options.focused_link = None;
+    }
+
+    let (article_summary, summary_progress) = match selected_article {
+        Some(selected_article) => {
+            let summary_lines = render_article_summary(
+                selected_article.clone(),
+                show_full_content,
+                render_options.text_selection,
+                render_options.focused_link,
+                render_options.summary_emphasis,
+            );
+            let total_lines = summary_lines
+                .iter()
+                .map(|line| {
+                    let text: String = line.0.iter().map(|span| span.content.as_ref()).collect();
+                    wrapped_line_count(
+                        &text,
+                        render_options.summary_pane_width.saturating_sub(2) as usize,
+                    )
+                })
+                .sum::<usize>();
+            let visible_lines = render_options.summary_pane_height.saturating_sub(2) as usize;
+            let max_scroll = total_lines.saturating_sub(visible_lines);
+
+            render_options.summary_scroll = render_options.summary_scroll.min(max_scroll as u16);
+            let summary_progress = match max_scroll {
+                0 => None,
+                _ => Some(render_options.summary_scroll as usize * 100 / max_scroll),
+            };
+            (Paragraph::new(summary_lines), summary_progress)
+        }
+        None => match &selected_rss_feed {
+            Some(rss_feed) if rss_articles_list.is_empty() => {
+                match articles_list.iter().any(|r| r.rss_id == rss_feed.rss_id) {
+                    true => (
+                        Paragraph::new(Span::styled(
+                            empty_articles_message(Some(rss_feed), articles_list),
+                            Style::default().fg(Color::LightCyan),
+                        )),
+                        None,
+                    ),
+                    false => (render_empty_feed_summary(rss_feed.rss_id), None),
+                }
+            }
+            Some(_) => (Paragraph::new(""), None),
+            None => (
+                Paragraph::new(Span::styled(
+                    "No RSS feeds yet, press a to add one.",
+                    Style::default().fg(Color::LightCyan),
+                )),
+                None,
+            ),
+        },
+    };
+
+    let summary_title = match summary_progress {
+        Some(progress) => format!("{} - {}%", summary_title, progress),
+        None => summary_title.to_string(),
+    };
+
+    let summary_block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White))
+        .title(summary_title)
+        .border_type(BorderType::Plain);
+
+    let feed_details = selected_rss_feed
+        .as_ref()
+        .filter(|_| get_config().show_feed_details_on_focus && render_options.feeds_focused);
+    let article_summary = match feed_details {
+        Some(rss_feed) => render_feed_details(rss_feed, articles_list).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("RSS Feed Details (arrow-up / arrow-down: articles)")
+                .border_type(BorderType::Plain),
+        ),
+        None => article_summary
+            .block(summary_block)
+            .scroll((render_options.summary_scroll, 0)),
+    }
+    .wrap(Wrap { trim: true });
+
+    (rss_list, article_list, article_summary)
+}
+
+/// Returns the columns shown in the articles pane, as set in config
+///
+/// The articles pane lists the articles of a single RSS feed, so the source column, which
+/// would repeat the RSS feed name on every row, is left out. Falls back to the title column
+/// when no other column is left.
+pub fn article_columns() -> Vec<ArticleColumn> {
+    let columns: Vec<ArticleColumn> = get_config()
+        .article_columns
+        .iter()
+        .filter(|column| **column != ArticleColumn::Source)
+        .copied()
+        .collect();
+
+    match columns.is_empty() {
+        true => vec![ArticleColumn::Title],
+        false => columns,
+    }
+}
+
+/// Returns the widths of given columns of the articles pane, adapted to the pane width
+///
+/// The source column takes a quarter of the pane (at most 30 characters), the date column a
+/// fixed width, and the title column the rest.
+pub fn article_column_widths(columns: &[ArticleColumn], pane_width: u16) -> Vec<Constraint> {
+    columns
+        .iter()
+        .map(|column| match column {
+            ArticleColumn::Date => Constraint::Length(10),
+            ArticleColumn::Source => Constraint::Length((pane_width / 4).min(30)),
+            ArticleColumn::Title => Constraint::Min(10),
+        })
+        .collect()
+}
 
-    let article_summary = Paragraph::new(vec![
+/// Formats the publication date of given article for the date column of the articles pane
+fn article_date_label(article: &Articles) -> String {
+    match get_config().use_local_timezone {
+        true => article
+            .pub_date
+            .with_timezone(&Local)
+            .format("%Y-%m-%d")
+            .to_string(),
+        false => article.pub_date.format("%Y-%m-%d").to_string(),
+    }
+}
+
+/// Renders the metadata of given RSS feed (description, article counts, last refresh, health)
+/// in the summary pane, shown while the RSS feeds list is focused
+fn render_feed_details<'a>(rss_feed: &RSSFeed, articles_list: &[Articles]) -> Paragraph<'a> {
+    let articles_list: Vec<&Articles> = articles_list
+        .iter()
+        .filter(|r| r.rss_id == rss_feed.rss_id && !r.hidden)
+        .collect();
+
+    let (health, health_color) = match read_feed_error(rss_feed.rss_id) {
+        _ if is_feed_refreshing(rss_feed.rss_id) => (String::from("refreshing"), Color::Yellow),
+        Some(feed_error) => (
+            format!("{:?} - {}", feed_error.error_code, feed_error.error_message),
+            Color::LightRed,
+        ),
+        None if !rss_feed.enabled => (String::from("paused"), Color::Gray),
+        None => (String::from("OK"), Color::LightGreen),
+    };
+
+    let detail_line = |label: &str, value: String, color: Color| {
+        Spans::from(vec![
+            Span::styled(
+                format!("{}: ", label),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+
+    Paragraph::new(vec![
         Spans::from(vec![Span::styled(
-            selected_article.title,
+            rss_feed.label().to_string(),
             Style::default()
                 .fg(Color::White)
                 .add_modifier(Modifier::BOLD),
         )]),
         Spans::from(vec![Span::raw("")]),
         Spans::from(vec![Span::styled(
-            selected_article.summary,
+            rss_feed
+                .description
+                .as_deref()
+                .map(clean_html)
+                .unwrap_or_else(|| String::from("No description provided by the feed.")),
             Style::default().fg(Color::LightBlue),
         )]),
         Spans::from(vec![Span::raw("")]),
+        detail_line("Category", rss_feed.category.clone(), Color::LightCyan),
+        detail_line("URL", rss_feed.url.clone(), Color::LightGreen),
+        detail_line(
+            "Articles",
+            format!(
+                "{} ({} unread, {} bookmarked)",
+                articles_list.len(),
+                articles_list.iter().filter(|r| !r.read).count(),
+                articles_list.iter().filter(|r| r.bookmarked).count()
+            ),
+            Color::LightCyan,
+        ),
+        detail_line(
+            "Last refresh",
+            match &rss_feed.last_refreshed_at {
+                Some(refreshed_at) => format_timestamp(refreshed_at),
+                None => String::from("never refreshed"),
+            },
+            Color::LightCyan,
+        ),
+        detail_line("Health", health, health_color),
+    ])
+}
+
+/// Explains why the articles list shows no articles: no RSS feed is selected, the RSS feed
+/// has no articles stored yet, or all its articles are left out of the list
+fn empty_articles_message(rss_feed: Option<&RSSFeed>, articles_list: &[Articles]) -> &'static str {
+    match rss_feed {
+        None => "No RSS feed selected",
+        Some(rss_feed) if !articles_list.iter().any(|r| r.rss_id == rss_feed.rss_id) => {
+            "This feed has no articles yet"
+        }
+        Some(_) if is_unread_only() => "No unread articles (press u to show all)",
+        Some(_) => "All articles muted, snoozed or hidden",
+    }
+}
+
+/// Renders the summary pane of an RSS feed without any articles
+///
+/// Feeds which failed to refresh are told apart from empty but healthy feeds.
+fn render_empty_feed_summary<'a>(rss_id: usize) -> Paragraph<'a> {
+    match read_feed_error(rss_id) {
+        Some(feed_error) => Paragraph::new(vec![
+            Spans::from(vec![Span::styled(
+                "The last refresh of this feed failed.",
+                Style::default().fg(Color::LightRed),
+            )]),
+            Spans::from(vec![Span::raw("")]),
+            Spans::from(vec![Span::styled(
+                format!("{:?} - {}", feed_error.error_code, feed_error.error_message),
+                Style::default().fg(Color::LightRed),
+            )]),
+        ]),
+        None => Paragraph::new(vec![
+            Spans::from(vec![Span::styled(
+                "This feed has no articles yet.",
+                Style::default().fg(Color::LightCyan),
+            )]),
+            Spans::from(vec![Span::raw("")]),
+            Spans::from(vec![Span::styled(
+                "New articles will show up here once the feed publishes them. Press r to refresh.",
+                Style::default().fg(Color::White),
+            )]),
+        ]),
+    }
+}
+
+/// Returns the summary (or full content, when available) of given article as plain text
+pub fn article_body_text(article: &Articles, show_full_content: bool) -> String {
+    match (show_full_content, &article.full_content) {
+        (true, Some(full_content)) => clean_html(full_content),
+        _ => clean_html(&article.summary),
+    }
+}
+
+/// Returns the links of the article body shown in the summary pane, in order of appearance
+pub fn article_links(article: &Articles, show_full_content: bool) -> Vec<HtmlLink> {
+    match (show_full_content, &article.full_content) {
+        (true, Some(full_content)) => extract_links(full_content),
+        _ => extract_links(&article.summary),
+    }
+}
+
+/// Finds the character range (first and last character offsets) of the text of given link in
+/// the article body, looking for the texts of the links in order of appearance
+fn link_text_range(article_body: &str, links: &[HtmlLink], link: usize) -> Option<(usize, usize)> {
+    let mut search_from = 0;
+    let mut range = None;
+
+    for html_link in links.iter().take(link + 1) {
+        range = match html_link.text.is_empty() {
+            true => None,
+            false => article_body[search_from..]
+                .find(&html_link.text)
+                .map(|found| search_from + found),
+        }
+        .map(|start| {
+            search_from = start + html_link.text.len();
+            let first_char = article_body[..start].chars().count();
+            (first_char, first_char + html_link.text.chars().count() - 1)
+        });
+    }
+    range
+}
+
+/// Renders the lines of the summary (or full content) of given article in TUI
+///
+/// The text selection, or else the focused link, if any, is highlighted in the article body,
+/// which is styled as per the summary emphasis.
+fn render_article_summary<'a>(
+    selected_article: Articles,
+    show_full_content: bool,
+    text_selection: Option<(usize, usize)>,
+    focused_link: Option<usize>,
+    summary_emphasis: SummaryEmphasis,
+) -> Vec<Spans<'a>> {
+    let article_body = article_body_text(&selected_article, show_full_content);
+    let body_style = match summary_emphasis {
+        SummaryEmphasis::Normal => Style::default().fg(Color::LightBlue),
+        SummaryEmphasis::Bold => Style::default()
+            .fg(Color::LightBlue)
+            .add_modifier(Modifier::BOLD),
+        SummaryEmphasis::HighContrast => Style::default()
+            .fg(Color::White)
+            .bg(Color::Black)
+            .add_modifier(Modifier::BOLD),
+    };
+
+    let focused_link_range = focused_link.and_then(|link| {
+        let links = article_links(&selected_article, show_full_content);
+        link_text_range(&article_body, &links, link)
+    });
+    let highlight = match (text_selection, focused_link_range) {
+        (Some(selection), _) => Some((selection, Modifier::REVERSED)),
+        (None, Some(link_range)) => Some((link_range, Modifier::REVERSED | Modifier::UNDERLINED)),
+        (None, None) => None,
+    };
+
+    let article_body_spans = match highlight {
+        Some(((anchor, cursor), highlight_modifier)) => {
+            let (start, end) = (anchor.min(cursor), anchor.max(cursor) + 1);
+            let chars: Vec<char> = article_body.chars().collect();
+            let end = end.min(chars.len());
+            let start = start.min(end);
+
+            vec![
+                Span::styled(chars[..start].iter().collect::<String>(), body_style),
+                Span::styled(
+                    chars[start..end].iter().collect::<String>(),
+                    body_style.add_modifier(highlight_modifier),
+                ),
+                Span::styled(chars[end..].iter().collect::<String>(), body_style),
+            ]
+        }
+        None => vec![Span::styled(article_body, body_style)],
+    };
+
+    let mut summary_lines = vec![
         Spans::from(vec![Span::styled(
-            format!("Published On: {}", selected_article.pub_date),
-            Style::default().fg(Color::White),
-        )]),
-        Spans::from(vec![Span::raw("")]),
-        Spans::from(vec![Span::styled(
-            format!("Author: {}", selected_article.author),
-            Style::default().fg(Color::White),
+            selected_article.title.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
         )]),
         Spans::from(vec![Span::raw("")]),
-        Spans::from(vec![Span::styled(
-            format!("Link to the article: {}", selected_article.article_link),
+        Spans::from(article_body_spans),
+    ];
+
+    if get_config().show_summary_date {
+        summary_lines.push(Spans::from(vec![Span::raw("")]));
+        summary_lines.push(Spans::from(vec![
+            Span::styled(
+                format!(
+                    "Published On: {}",
+                    format_timestamp(&selected_article.pub_date)
+                ),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                match &selected_article.viewed_at {
+                    Some(viewed_at) => format!(" | Viewed On: {}", format_timestamp(viewed_at)),
+                    None => String::new(),
+                },
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+    }
+
+    summary_lines.push(Spans::from(vec![Span::raw("")]));
+    summary_lines.push(Spans::from(vec![Span::styled(
+        format!("Author: {}", selected_article.author),
+        Style::default().fg(Color::White),
+    )]));
+
+    if get_config().show_summary_link {
+        summary_lines.push(Spans::from(vec![Span::raw("")]));
+        summary_lines.push(Spans::from(vec![Span::styled(
+            format!(
+                "Link to the article: {}",
+                display_link(&selected_article.article_link)
+            ),
             Style::default().fg(Color::LightGreen),
-        )]),
-    ])
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::White))
-            .border_type(BorderType::Plain),
-    )
-    .wrap(Wrap { trim: true });
+        )]));
+    }
 
-    (rss_list, article_list, article_summary)
+    if !selected_article.tags.is_empty() {
+        summary_lines.push(Spans::from(vec![Span::raw("")]));
+        summary_lines.push(Spans::from(vec![Span::styled(
+            format!("Tags: {}", selected_article.tags.join(", ")),
+            Style::default().fg(Color::LightMagenta),
+        )]));
+    }
+    summary_lines
+}
+
+/// Counts the lines taken by given text once word wrapped to the given width
+///
+/// Mirrors the word wrapping of the summary pane closely enough to compute the read progress.
+fn wrapped_line_count(text: &str, width: usize) -> usize {
+    if width == 0 {
+        return 1;
+    }
+
+    text.split('\n')
+        .map(|line| {
+            let mut line_count = 1;
+            let mut line_width = 0;
+            for word in line.split_whitespace() {
+                let word_width: usize = word.chars().filter_map(|c| c.width()).sum();
+                let needed_width = match line_width {
+                    0 => word_width,
+                    _ => line_width + 1 + word_width,
+                };
+
+                if needed_width <= width {
+                    line_width = needed_width;
+                } else {
+                    line_count +=
+                        (line_width > 0) as usize + (word_width.saturating_sub(1)) / width;
+                    line_width = match word_width % width {
+                        0 => width,
+                        t => t,
+                    };
+                }
+            }
+            line_count
+        })
+        .sum()
 }
 
-fn check_if_article_exists(article_url: &str, article_db: &Vec<Articles>) -> bool {
-    for item in article_db {
-        if item.article_link == article_url.to_string() {
-            return true;
+/// Formats an article link for display, as set in config
+///
+/// Only the displayed link is changed, the full link is kept for opening and copying.
+fn display_link(article_link: &str) -> String {
+    let config = get_config();
+    let article_link = match config.strip_tracking_params {
+        true => clean_url(article_link),
+        false => article_link.to_string(),
+    };
+    elide_url(&article_link, config.max_link_display_length)
+}
+
+/// Counts the unread articles and the articles of each RSS feed (by RSS id) among given
+/// articles, leaving out snoozed articles and, unless shown, hidden articles
+fn count_listed_articles(articles_list: &[Articles]) -> HashMap<usize, (usize, usize)> {
+    let show_hidden = get_config().show_hidden_articles;
+    let now = Utc::now();
+    let mut article_counts: HashMap<usize, (usize, usize)> = HashMap::new();
+
+    for article in articles_list
+        .iter()
+        .filter(|r| (show_hidden || !r.hidden) && !r.is_snoozed(now))
+    {
+        let (num_unread, num_articles) = article_counts.entry(article.rss_id).or_default();
+        *num_unread += !article.read as usize;
+        *num_articles += 1;
+    }
+    article_counts
+}
+
+/// Truncates given text to the given width, ending it with an ellipsis when cut
+fn truncate_to_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let mut truncated = String::new();
+    let mut truncated_width = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if truncated_width + char_width + 1 > width {
+            break;
+        }
+        truncated.push(c);
+        truncated_width += char_width;
+    }
+    format!("{}…", truncated)
+}
+
+/// Builds the preview lines of an article summary, word wrapped to the given width
+///
+/// The last line is truncated with an ellipsis when the summary does not fit.
+fn article_preview(summary: &str, width: usize) -> Vec<String> {
+    let text = clean_html(summary)
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+    let mut lines: Vec<String> = Vec::new();
+    let mut remaining = text.as_str();
+
+    if width == 0 {
+        return lines;
+    }
+
+    while !remaining.is_empty() && lines.len() < ARTICLE_PREVIEW_LINES {
+        let mut line_width = 0;
+        let mut end = remaining.len();
+        let mut last_space = None;
+
+        for (idx, c) in remaining.char_indices() {
+            let char_width = c.width().unwrap_or(0);
+            if line_width + char_width > width {
+                end = idx;
+                break;
+            }
+            if c == ' ' {
+                last_space = Some(idx);
+            }
+            line_width += char_width;
+        }
+
+        if end == remaining.len() {
+            lines.push(remaining.to_string());
+            break;
+        }
+
+        if lines.len() + 1 == ARTICLE_PREVIEW_LINES {
+            let mut line: String = remaining[..end].to_string();
+            line.pop();
+            lines.push(format!("{}…", line.trim_end()));
+            break;
+        }
+
+        let split_at = last_space.filter(|idx| *idx > 0).unwrap_or(end);
+        lines.push(remaining[..split_at].trim_end().to_string());
+        remaining = remaining[split_at..].trim_start();
+    }
+
+    lines
+}
+
+/// Formats the given timestamp as per the date format and timezone set in config
+pub fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    let config = get_config();
+    match (&config.date_format, config.use_local_timezone) {
+        (Some(date_format), true) => timestamp
+            .with_timezone(&Local)
+            .format(date_format)
+            .to_string(),
+        (Some(date_format), false) => timestamp.format(date_format).to_string(),
+        (None, true) => timestamp.with_timezone(&Local).to_string(),
+        (None, false) => timestamp.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tui::backend::TestBackend;
+    use tui::layout::{Direction, Layout};
+    use tui::widgets::TableState;
+    use tui::Terminal;
+
+    /// Builds an unread article published on given day of January 2024
+    fn test_article(article_id: usize, day: u32) -> Articles {
+        let pub_date = Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+        Articles {
+            article_id,
+            rss_id: 1,
+            title: format!("Article {}", article_id),
+            summary: String::new(),
+            article_link: format!("https://example.com/{}", article_id),
+            author: String::new(),
+            pub_date,
+            created_at: pub_date,
+            hidden: false,
+            full_content: None,
+            viewed_at: None,
+            read: false,
+            bookmarked: false,
+            tags: Vec::new(),
+            snoozed_until: None,
+        }
+    }
+
+    /// Builds an RSS feed fetched with given items (XML of the `<item>` elements)
+    fn fetched_feed(items_xml: &str) -> FetchedFeed {
+        let rss_xml = format!(
+            r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+<channel>
+<title>Test feed</title>
+<link>https://example.com</link>
+<description>Test feed</description>
+{}
+</channel>
+</rss>"#,
+            items_xml
+        );
+        FetchedFeed {
+            rss_feed: RSSFeed::new(1, "Test", "Test feed", "https://example.com/feed.xml"),
+            channel: Some(Channel::read_from(rss_xml.as_bytes()).unwrap()),
+            last_modified: None,
         }
     }
-    false
+
+    fn article_ids(articles_list: &[Articles]) -> Vec<usize> {
+        articles_list.iter().map(|r| r.article_id).collect()
+    }
+
+    /// Renders the RSS feeds, articles and summary panes side by side, with the RSS feed at
+    /// given position selected, returning the lines shown
+    fn render_feeds_pane(
+        rss_feed_list: &[RSSFeed],
+        articles_list: &[Articles],
+        selected: usize,
+    ) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(120, 16)).unwrap();
+        let mut rss_list_state = ListState::default();
+        rss_list_state.select(Some(selected));
+        let mut articles_list_state = ListState::default();
+        articles_list_state.select(Some(0));
+        let mut articles_table_state = TableState::default();
+        articles_table_state.select(Some(0));
+        let mut render_options = RenderOptions {
+            feeds_pane_width: 30,
+            articles_pane_width: 50,
+            summary_pane_width: 40,
+            summary_pane_height: 16,
+            ..RenderOptions::default()
+        };
+        let column_widths = article_column_widths(&article_columns(), 50);
+
+        terminal
+            .draw(|frame| {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Length(30),
+                        Constraint::Length(50),
+                        Constraint::Length(40),
+                    ])
+                    .split(frame.size());
+                let (feeds, articles, summary) = render_rss_feed_list(
+                    rss_feed_list,
+                    articles_list,
+                    &rss_list_state,
+                    &articles_list_state,
+                    &mut render_options,
+                    &column_widths,
+                );
+                frame.render_stateful_widget(feeds, chunks[0], &mut rss_list_state);
+                frame.render_stateful_widget(articles, chunks[1], &mut articles_table_state);
+                frame.render_widget(summary, chunks[2]);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .map(|cells| cells.iter().map(|cell| cell.symbol.as_str()).collect())
+            .collect()
+    }
+
+    fn shows(lines: &[String], text: &str) -> bool {
+        lines.iter().any(|line| line.contains(text))
+    }
+
+    #[test]
+    fn renders_the_articles_of_the_selected_feed() {
+        let rss_feed_list = vec![
+            RSSFeed::new(0, "Welcome", "Welcome", "https://example.com/welcome"),
+            RSSFeed::new(1, "News", "Daily news", "https://example.com/news.xml"),
+        ];
+        let mut articles_list: Vec<Articles> = (1..=3).map(|n| test_article(n, n as u32)).collect();
+        articles_list[0].read = true;
+        articles_list[2].summary = String::from("Latest headlines");
+
+        let lines = render_feeds_pane(&rss_feed_list, &articles_list, 1);
+        // Unread count badge of the RSS feed, column headers, and articles latest first
+        assert!(shows(&lines, "Daily news 2"), "{:#?}", lines);
+        let header = lines.iter().find(|line| line.contains("Date")).unwrap();
+        assert!(header.contains("Title") && !header.contains("Source"));
+        let article_rows: Vec<usize> = ["Article 3", "Article 2", "Article 1"]
+            .iter()
+            .map(|title| lines.iter().position(|line| line.contains(title)).unwrap())
+            .collect();
+        assert!(article_rows.windows(2).all(|rows| rows[0] < rows[1]));
+        assert!(shows(&lines, "Latest headlines"), "{:#?}", lines);
+    }
+
+    #[test]
+    fn renders_a_feed_without_articles() {
+        let rss_feed_list = vec![
+            RSSFeed::new(0, "Welcome", "Welcome", "https://example.com/welcome"),
+            RSSFeed::new(2, "News", "Quiet feed", "https://example.com/quiet.xml"),
+        ];
+        let articles_list: Vec<Articles> = (1..=3).map(|n| test_article(n, n as u32)).collect();
+
+        let lines = render_feeds_pane(&rss_feed_list, &articles_list, 1);
+        assert!(shows(&lines, "Quiet feed"), "{:#?}", lines);
+        assert!(!shows(&lines, "Article 1"), "{:#?}", lines);
+        assert!(
+            shows(&lines, "This feed has no articles yet."),
+            "{:#?}",
+            lines
+        );
+    }
+
+    #[test]
+    fn renders_a_feed_whose_articles_are_all_left_out() {
+        let rss_feed_list = vec![
+            RSSFeed::new(0, "Welcome", "Welcome", "https://example.com/welcome"),
+            RSSFeed::new(1, "News", "Daily news", "https://example.com/news.xml"),
+        ];
+        let mut articles_list: Vec<Articles> = (1..=2).map(|n| test_article(n, n as u32)).collect();
+        articles_list[0].snoozed_until = Some(Utc::now() + chrono::Duration::hours(1));
+        articles_list[1].hidden = true;
+
+        let lines = render_feeds_pane(&rss_feed_list, &articles_list, 1);
+        assert!(!shows(&lines, "Article 1"), "{:#?}", lines);
+        assert!(!shows(&lines, "no articles yet"), "{:#?}", lines);
+        assert!(
+            shows(&lines, "All articles muted, snoozed or hidden"),
+            "{:#?}",
+            lines
+        );
+    }
+
+    #[test]
+    fn renders_without_any_feed() {
+        let lines = render_feeds_pane(&[], &[], 0);
+        assert!(shows(&lines, "RSS Feeds"), "{:#?}", lines);
+        assert!(shows(&lines, "No RSS feed selected"), "{:#?}", lines);
+        assert!(
+            shows(&lines, "No RSS feeds yet, press a to add one."),
+            "{:#?}",
+            lines
+        );
+    }
+
+    #[test]
+    fn keeps_content_encoded_as_full_content_next_to_the_description() {
+        let new_articles = build_new_articles(
+            &fetched_feed(
+                r#"<item><title>Both</title><link>https://example.com/both</link>
+<description>Short summary</description>
+<content:encoded><![CDATA[<p>Full article</p>]]></content:encoded></item>
+<item><title>Content only</title><link>https://example.com/content</link>
+<content:encoded><![CDATA[<p>Only the full article</p>]]></content:encoded></item>"#,
+            ),
+            &|_link| false,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(new_articles[0].summary, "Short summary");
+        assert_eq!(
+            new_articles[0].full_content.as_deref(),
+            Some("<p>Full article</p>")
+        );
+        // Without description, the full article stands in for the summary
+        assert_eq!(new_articles[1].summary, "<p>Only the full article</p>");
+        assert_eq!(
+            new_articles[1].full_content.as_deref(),
+            Some("<p>Only the full article</p>")
+        );
+    }
+
+    #[test]
+    fn derives_titles_of_items_without_title() {
+        let long_summary = "word ".repeat(30);
+        let new_articles = build_new_articles(
+            &fetched_feed(&format!(
+                r#"<item><link>https://example.com/1</link>
+<description><![CDATA[<p>Short   <b>note</b></p>]]></description></item>
+<item><title>  </title><link>https://example.com/2</link>
+<description>{}</description></item>
+<item><link>https://example.com/3</link><pubDate>Wed, 03 Jan 2024 12:00:00 GMT</pubDate></item>
+<item><link>https://example.com/4</link></item>"#,
+                long_summary
+            )),
+            &|_link| false,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(new_articles[0].title, "Short note");
+        assert_eq!(
+            new_articles[1].title,
+            format!("{}…", long_summary[..DERIVED_TITLE_LENGTH].trim_end())
+        );
+        let pub_date = Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap();
+        assert_eq!(new_articles[2].title, format_timestamp(&pub_date));
+        assert_eq!(new_articles[3].title, "(untitled)");
+    }
+
+    #[test]
+    fn stores_at_most_max_items_per_refresh_first_items() {
+        let items_xml: String = (0..500)
+            .map(|n| {
+                format!(
+                    "<item><title>Item {}</title><link>https://example.com/{}</link></item>",
+                    n, n
+                )
+            })
+            .collect();
+        let mut capped_feed = fetched_feed(&items_xml);
+        capped_feed.rss_feed.max_items_per_refresh = Some(20);
+
+        let new_articles = build_new_articles(&capped_feed, &|_link| false, 0).unwrap();
+        assert_eq!(new_articles.len(), 20);
+        assert_eq!(new_articles[0].title, "Item 0");
+        assert_eq!(new_articles[19].title, "Item 19");
+
+        // Items already stored count towards the cap, so later items don't fill in for them
+        let is_known_link = |link: &str| link == "https://example.com/0";
+        let new_articles = build_new_articles(&capped_feed, &is_known_link, 0).unwrap();
+        assert_eq!(new_articles.len(), 19);
+        assert_eq!(new_articles[18].title, "Item 19");
+
+        capped_feed.rss_feed.max_items_per_refresh = None;
+        let new_articles = build_new_articles(&capped_feed, &|_link| false, 0).unwrap();
+        assert_eq!(new_articles.len(), 500);
+    }
+
+    #[test]
+    fn evicts_nothing_under_the_limit_or_without_limit() {
+        let mut articles_list: Vec<Articles> = (1..=3).map(|n| test_article(n, n as u32)).collect();
+
+        assert!(evict_articles(&mut articles_list, 3).is_empty());
+        assert!(evict_articles(&mut articles_list, 0).is_empty());
+        assert_eq!(articles_list.len(), 3);
+    }
+
+    #[test]
+    fn evicts_oldest_articles_first() {
+        let mut articles_list: Vec<Articles> = [3, 1, 4, 2]
+            .iter()
+            .map(|n| test_article(*n, *n as u32))
+            .collect();
+
+        let evicted_links = evict_articles(&mut articles_list, 2);
+        assert_eq!(
+            evicted_links,
+            vec!["https://example.com/1", "https://example.com/2"]
+        );
+        assert_eq!(article_ids(&articles_list), vec![3, 4]);
+    }
+
+    #[test]
+    fn evicts_read_and_hidden_articles_before_unread_ones() {
+        let mut articles_list: Vec<Articles> = (1..=4).map(|n| test_article(n, n as u32)).collect();
+        articles_list[2].read = true;
+        articles_list[3].hidden = true;
+
+        evict_articles(&mut articles_list, 2);
+        assert_eq!(article_ids(&articles_list), vec![1, 2]);
+    }
+
+    #[test]
+    fn never_evicts_bookmarked_articles() {
+        let mut articles_list: Vec<Articles> = (1..=4).map(|n| test_article(n, n as u32)).collect();
+        for article in articles_list.iter_mut() {
+            article.bookmarked = article.article_id != 4;
+            article.read = true;
+        }
+
+        let evicted_links = evict_articles(&mut articles_list, 1);
+        assert_eq!(evicted_links, vec!["https://example.com/4"]);
+        assert_eq!(article_ids(&articles_list), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn snoozed_articles_come_back_at_the_time_they_were_snoozed_until() {
+        let snoozed_until = Utc.with_ymd_and_hms(2024, 1, 10, 8, 0, 0).unwrap();
+        let mut article = test_article(1, 1);
+        assert!(!article.is_snoozed(snoozed_until));
+
+        article.snoozed_until = Some(snoozed_until);
+        assert!(article.is_snoozed(snoozed_until - chrono::Duration::seconds(1)));
+        assert!(!article.is_snoozed(snoozed_until));
+        assert!(!article.is_snoozed(snoozed_until + chrono::Duration::seconds(1)));
+    }
+
+    #[test]
+    fn lists_snoozed_articles_once_their_time_has_passed() {
+        let rss_feed = RSSFeed::new(1, "News", "Daily news", "https://example.com/news.xml");
+        let now = Utc::now();
+        let mut articles_list: Vec<Articles> = (1..=3).map(|n| test_article(n, n as u32)).collect();
+        articles_list[0].snoozed_until = Some(now + chrono::Duration::hours(1));
+        articles_list[1].snoozed_until = Some(now - chrono::Duration::seconds(1));
+
+        let (listed_articles, _) = list_feed_articles(&rss_feed, &articles_list);
+        assert_eq!(article_ids(&listed_articles), vec![3, 2]);
+    }
 }
@@ -0,0 +1,58 @@
+//! Cleans up and shortens the article links shown in the terminal UI
+//!
+
+use reqwest::Url;
+
+/// Query parameters used for tracking, removed by [`clean_url`] (`utm_*` are matched by prefix)
+const TRACKING_PARAMS: [&str; 12] = [
+    "fbclid", "gclid", "dclid", "msclkid", "yclid", "igshid", "mc_cid", "mc_eid", "_hsenc",
+    "_hsmi", "ref_src", "mkt_tok",
+];
+
+/// Removes the tracking query parameters (e.g. `utm_source`, `fbclid`) from given URL
+///
+/// URLs which can't be parsed are returned as is, trimmed.
+pub fn clean_url(raw: &str) -> String {
+    let mut url = match Url::parse(raw.trim()) {
+        Ok(t) => t,
+        Err(_) => return raw.trim().to_string(),
+    };
+
+    let query_pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| !is_tracking_param(key))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    match query_pairs.is_empty() {
+        true => url.set_query(None),
+        false => {
+            url.query_pairs_mut().clear().extend_pairs(query_pairs);
+        }
+    }
+
+    url.to_string()
+}
+
+/// Shortens given URL to at most `max_length` characters by eliding its middle part
+///
+/// URLs which already fit, and a `max_length` of 0, leave the URL unchanged.
+pub fn elide_url(url: &str, max_length: usize) -> String {
+    let num_chars = url.chars().count();
+    if max_length == 0 || num_chars <= max_length {
+        return url.to_string();
+    }
+
+    let kept_chars = max_length.saturating_sub(1);
+    let head_chars = kept_chars - kept_chars / 2;
+    let tail_chars = kept_chars / 2;
+
+    let head: String = url.chars().take(head_chars).collect();
+    let tail: String = url.chars().skip(num_chars - tail_chars).collect();
+    format!("{}…{}", head, tail)
+}
+
+fn is_tracking_param(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.starts_with("utm_") || TRACKING_PARAMS.contains(&key.as_str())
+}
@@ -0,0 +1,80 @@
+//! Sets up application logging and reads back the log file for the in-app log viewer
+//!
+
+use crate::config::get_config;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::fetcher::is_sequential_fetch;
+use log::{Level, LevelFilter};
+use log4rs::append::file::FileAppender;
+use log4rs::config::{Appender, Config, Root};
+use log4rs::encode::pattern::PatternEncoder;
+use std::fs;
+use std::path::Path;
+
+/// YAML file path for log4rs logging configuration
+pub const LOGGING_CONFIG_PATH: &str = "config/logging_config.yaml";
+
+/// Pattern used for log lines when no logging configuration file is provided
+const LOG_PATTERN: &str = "{d(%Y-%m-%d %H:%M:%S)} {l} {t} - {m}{n}";
+
+/// Initializes logging from the logging configuration file, if present
+///
+/// Without a logging configuration file, INFO level logs (DEBUG level while fetching RSS feeds
+/// sequentially) are written to the log file set in config. The log viewer reads the log file set in config, so a custom logging configuration
+/// should write to the same file.
+pub fn init_logging() {
+    let result = if Path::new(LOGGING_CONFIG_PATH).exists() {
+        log4rs::init_file(LOGGING_CONFIG_PATH, Default::default()).map_err(|_err| ())
+    } else {
+        default_logging_config().and_then(|config| {
+            log4rs::init_config(config)
+                .map(|_handle| ())
+                .map_err(|_err| ())
+        })
+    };
+
+    if result.is_err() {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0019_LOGGING_CONFIG_FILE_READ_FAILURE);
+        eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    }
+}
+
+/// Builds the logging configuration used when no logging configuration file is provided
+fn default_logging_config() -> Result<Config, ()> {
+    let log_file = FileAppender::builder()
+        .encoder(Box::new(PatternEncoder::new(LOG_PATTERN)))
+        .build(&get_config().log_file)
+        .map_err(|_err| ())?;
+
+    Config::builder()
+        .appender(Appender::builder().build("log_file", Box::new(log_file)))
+        .build(
+            Root::builder()
+                .appender("log_file")
+                .build(match is_sequential_fetch() {
+                    true => LevelFilter::Debug,
+                    false => LevelFilter::Info,
+                }),
+        )
+        .map_err(|_err| ())
+}
+
+/// Reads the last lines of the log file, oldest first
+///
+/// Returns an empty list if the log file is missing or can't be read.
+pub fn read_log_tail(max_lines: usize) -> Vec<String> {
+    let log_content = fs::read_to_string(&get_config().log_file).unwrap_or_default();
+    let lines: Vec<&str> = log_content.lines().filter(|l| !l.is_empty()).collect();
+
+    lines[lines.len().saturating_sub(max_lines)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Finds the log level of given log line, if any
+pub fn log_line_level(line: &str) -> Option<Level> {
+    line.split_whitespace()
+        .take(4)
+        .find_map(|word| word.parse::<Level>().ok())
+}
@@ -1,31 +1,102 @@
 extern crate chrono;
 extern crate unicode_width;
-pub mod error_db;
+mod cli;
+mod keymap;
 
+#[cfg(feature = "serve")]
+use byte_bite::server::serve;
 use byte_bite::{
-    read_articles_db, read_rss_db, render_rss_feed_list, update_rss_db, write_articles_db,
-    write_rss_db, Articles,
+    add_feed,
+    archive::{archive_article, archive_read_articles, search_archive},
+    article_body_text, article_column_widths, article_columns, article_links,
+    browser::{open_articles_in_browser, open_in_browser, open_in_mail_client},
+    clipboard::{copy_to_clipboard, read_from_clipboard},
+    config::{get_config, load_config},
+    consistency::check_db_consistency,
+    count_new_articles_since,
+    db_writer::lock_db_writes,
+    discovery::discover_feeds,
+    duplicate_rss_feed,
+    duplicates::{find_duplicate_feeds, merge_duplicate_feeds, DuplicateFeeds},
+    error_db::{ErrorCodes, ErrorMessages},
+    export::{
+        article_to_markdown_snippet, build_mailto, export_article, export_read_history,
+        ReadHistoryFormat,
+    },
+    feed_list::{add_feeds_from_file, add_feeds_from_stdin, import_newsboat},
+    feed_sort,
+    feed_status::{
+        is_any_feed_refreshing, is_feed_refreshing, read_feed_errors, read_fetch_stats,
+        read_refreshing_feeds,
+    },
+    fetch_raw_feed,
+    fetcher::{refresh_rss_feeds, set_sequential_fetch},
+    find_unread_feed, format_timestamp, hide_article,
+    html::clean_html,
+    is_unread_only, is_valid_feed_url,
+    keywords::parse_keywords,
+    logging::{init_logging, log_line_level, read_log_tail},
+    mark_article_viewed, mark_read_before, move_rss_feed,
+    opml::{export_opml, export_opml_file, import_opml, store_opml_feeds, ImportReport},
+    parse_duration, preview_feed, read_article_tags, read_articles_db, read_rss_articles,
+    read_rss_db, read_rss_feed_list, read_selected_article, read_selected_rss_feed,
+    read_snoozed_articles, read_tagged_articles, read_unread_articles, refresh_rss_feed, reload_db,
+    rename_category, render_rss_feed_list, reset_db, set_feed_sort, set_rss_feed_display_name,
+    set_rss_feed_muted_keywords, set_unread_only, snooze_article,
+    stats::{compute_feed_stats, STATS_DAYS},
+    tag_article, toggle_article_bookmark, toggle_article_read, toggle_rss_feed_enabled,
+    ui_state::{flush_ui_state, read_ui_state, write_ui_state, FeedSort},
+    untag_article, update_rss_db, Articles, RenderOptions,
 };
+use chrono::Utc;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event as CEvent, KeyCode, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use error_db::{ErrorCodes, ErrorMessages};
-use std::io;
+use keymap::{key_label, Action, KeyContext, Keymap};
+use log::Level;
+use std::future::Future;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
 use std::thread;
+use std::time::{Duration, Instant};
 use tui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Clear, ListState, Paragraph, Tabs},
+    widgets::{
+        BarChart, Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph,
+        TableState, Tabs, Wrap,
+    },
     Terminal,
 };
 use unicode_width::UnicodeWidthStr;
 
 const APP_HEADING: &str = "BYTE-BITE: Take a bite out of the news and updates with ByteBite";
-const MENU_TITLES: [&'static str; 5] = ["Add", "Delete", "Refresh", "Help", "Quit"];
+const MENU_TITLES: [&str; 5] = ["Add", "Delete", "Refresh", "Help", "Quit"];
+const TICK_RATE: Duration = Duration::from_millis(250);
+/// Word to type for confirming a reset of the RSS feeds and articles
+const RESET_CONFIRMATION: &str = "reset";
+
+/// Interval at which the screen is redrawn without any input, for timed elements (e.g. status expiry)
+const IDLE_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(5);
+const LOG_VIEWER_LINES: usize = 200;
+const MIN_TERMINAL_WIDTH: u16 = 60;
+const MIN_TERMINAL_HEIGHT: u16 = 20;
+/// Explanation printed when the terminal UI can't be started
+const UNSUPPORTED_TERMINAL_HELP: &str =
+    "byte_bite needs an interactive terminal supporting raw mode and the alternate screen.
+Run it from a terminal emulator (not with its output redirected), or use the command line
+options which don't need one, e.g. --import-opml, --add-feeds or --serve <port> --headless.";
+/// Whether bracketed paste was enabled at startup, and so has to be disabled on exit
+static BRACKETED_PASTE_ENABLED: AtomicBool = AtomicBool::new(false);
 
 /// Defines the different TUI modes for user interaction
 pub enum InputMode {
@@ -35,12 +106,22 @@ pub enum InputMode {
     Editing,
     /// Popup mode to display information in TUI Popups
     Popup,
+    /// Selection mode to select and copy text from the article summary
+    Selecting,
+    /// Prompt mode to enter a single value (e.g. duration, file path) in a popup
+    Prompting,
 }
 
-/// Defines the metadata for text input box in TUI
+/// Titles of the add RSS feed wizard fields, in focus order
+const ADD_FEED_FIELDS: [&str; 3] = ["Category", "Name", "URL"];
+const URL_FIELD: usize = 2;
+
+/// Defines the metadata for the add RSS feed wizard in TUI
 struct InputBoxApp {
-    /// Stores text input from users
-    pub text_input: String,
+    /// Text inputs for the category, name and URL of the new RSS feed
+    pub fields: [TextInput; 3],
+    /// Index of the field currently receiving the text input
+    pub focused_field: usize,
     /// Different input modes as per "InputMode" enum
     pub input_mode: InputMode,
 }
@@ -48,10 +129,190 @@ struct InputBoxApp {
 impl InputBoxApp {
     fn new() -> InputBoxApp {
         InputBoxApp {
-            text_input: String::new(),
+            fields: Default::default(),
+            focused_field: 0,
             input_mode: InputMode::Normal,
         }
     }
+
+    /// Returns the field currently receiving the text input
+    fn focused_input(&mut self) -> &mut TextInput {
+        &mut self.fields[self.focused_field]
+    }
+
+    fn focus_next_field(&mut self) {
+        self.focused_field = (self.focused_field + 1) % self.fields.len();
+    }
+
+    fn focus_previous_field(&mut self) {
+        self.focused_field = (self.focused_field + self.fields.len() - 1) % self.fields.len();
+    }
+
+    /// Checks whether the given field holds a valid value
+    fn is_field_valid(&self, field: usize) -> bool {
+        let text_input = self.fields[field].text_input.trim();
+        match field {
+            URL_FIELD => is_valid_feed_url(text_input),
+            _ => !text_input.is_empty(),
+        }
+    }
+
+    /// Checks whether all the fields hold valid values
+    fn is_valid(&self) -> bool {
+        (0..self.fields.len()).all(|field| self.is_field_valid(field))
+    }
+
+    /// Clears all the fields and focuses on the first one
+    fn reset(&mut self) {
+        for field in self.fields.iter_mut() {
+            field.take_text();
+        }
+        self.focused_field = 0;
+    }
+
+    /// Adds the RSS feed filled in the wizard, clearing the wizard on success
+    async fn submit(&mut self, status_bar_app: &mut StatusBarApp) {
+        let [category, name, url] = &self.fields;
+        match add_feed(&category.text_input, &name.text_input, &url.text_input).await {
+            Ok(()) => {
+                self.reset();
+                self.input_mode = InputMode::Normal;
+            }
+            Err(err_msg) => {
+                status_bar_app.set_error(&err_msg);
+                self.input_mode = InputMode::Editing;
+            }
+        }
+    }
+}
+
+/// Defines the metadata for a single line text input with cursor
+#[derive(Default)]
+struct TextInput {
+    /// Stores text input from users
+    pub text_input: String,
+    /// Cursor position (in characters) within the text input
+    pub cursor_position: usize,
+}
+
+impl TextInput {
+    /// Converts the character based cursor position into a byte index of the text input
+    fn byte_index(&self) -> usize {
+        self.text_input
+            .char_indices()
+            .nth(self.cursor_position)
+            .map(|(idx, _)| idx)
+            .unwrap_or(self.text_input.len())
+    }
+
+    /// Returns the text input placed before the cursor
+    fn text_before_cursor(&self) -> &str {
+        &self.text_input[..self.byte_index()]
+    }
+
+    fn move_cursor_left(&mut self) {
+        self.cursor_position = self.cursor_position.saturating_sub(1);
+    }
+
+    fn move_cursor_right(&mut self) {
+        if self.cursor_position < self.text_input.chars().count() {
+            self.cursor_position += 1;
+        }
+    }
+
+    fn move_cursor_home(&mut self) {
+        self.cursor_position = 0;
+    }
+
+    fn move_cursor_end(&mut self) {
+        self.cursor_position = self.text_input.chars().count();
+    }
+
+    /// Inserts a character at the cursor position
+    fn insert_char(&mut self, c: char) {
+        let idx = self.byte_index();
+        self.text_input.insert(idx, c);
+        self.cursor_position += 1;
+    }
+
+    /// Inserts pasted text at the cursor position, dropping any line breaks
+    fn insert_str(&mut self, text: &str) {
+        let cleaned: String = text.chars().filter(|c| *c != '\n' && *c != '\r').collect();
+        let idx = self.byte_index();
+        self.text_input.insert_str(idx, &cleaned);
+        self.cursor_position += cleaned.chars().count();
+    }
+
+    /// Deletes the character placed before the cursor (Backspace)
+    fn delete_char_before_cursor(&mut self) {
+        if self.cursor_position > 0 {
+            self.cursor_position -= 1;
+            let idx = self.byte_index();
+            self.text_input.remove(idx);
+        }
+    }
+
+    /// Deletes the character placed under the cursor (Delete)
+    fn delete_char_at_cursor(&mut self) {
+        if self.cursor_position < self.text_input.chars().count() {
+            let idx = self.byte_index();
+            self.text_input.remove(idx);
+        }
+    }
+
+    /// Clears the text input and returns its content
+    fn take_text(&mut self) -> String {
+        self.cursor_position = 0;
+        self.text_input.drain(..).collect::<String>()
+    }
+}
+
+/// Defines the actions which ask for a value in a prompt popup
+#[derive(Default, Clone, Copy, PartialEq)]
+enum PromptKind {
+    /// Mark the articles older than the duration entered as read
+    #[default]
+    MarkReadBefore,
+    /// Import the RSS feeds of the OPML file entered
+    ImportOpml,
+    /// Set the display name of the selected RSS feed to the name entered
+    RenameFeed,
+    /// Rename the category of the selected RSS feed, on all the RSS feeds filed under it
+    RenameCategory,
+    /// Copy the selected RSS feed under the category entered
+    DuplicateFeed,
+    /// Set the muted keywords of the selected RSS feed to the comma-separated keywords entered
+    MuteKeywords,
+    /// Set the tags of the selected article to the comma-separated tags entered
+    EditTags,
+    /// Show the articles with the tag entered
+    ShowTaggedArticles,
+    /// Show the archived articles containing the text entered
+    SearchArchive,
+    /// Reset the RSS feeds and articles, once the confirmation word is entered
+    ResetDb,
+    /// Snooze the selected article for the duration entered
+    SnoozeArticle,
+}
+
+/// Defines the metadata for the prompt popup
+#[derive(Default)]
+struct PromptApp {
+    /// Action the value is asked for
+    pub kind: PromptKind,
+    /// Value entered (e.g. "7d" duration, OPML file path, display name)
+    pub input: TextInput,
+    /// Apply to all RSS feeds instead of the selected one (mark older articles as read)
+    pub all_feeds: bool,
+}
+
+impl PromptApp {
+    fn new(kind: PromptKind) -> PromptApp {
+        PromptApp {
+            kind,
+            ..PromptApp::default()
+        }
+    }
 }
 
 /// Defines the flags for displaying popups
@@ -60,6 +321,46 @@ pub struct PopupApp {
     pub show_refresh_popup: bool,
     /// Flag for showing/hiding help navigation popup
     pub show_help_popup: bool,
+    /// Flag for showing/hiding RSS feed errors popup
+    pub show_errors_popup: bool,
+    /// Flag for showing/hiding log viewer popup
+    pub show_log_popup: bool,
+    /// Flag for showing/hiding statistics popup
+    pub show_stats_popup: bool,
+    /// Flag for showing/hiding RSS feed details popup
+    pub show_feed_details_popup: bool,
+    /// Number of lines scrolled in the help navigation popup
+    pub help_scroll: u16,
+    /// Outcome of the last OPML import, shown in the import report popup while set
+    pub import_report: Option<ImportReport>,
+    /// Number of lines scrolled in the import report popup
+    pub import_scroll: u16,
+    /// Flag for showing/hiding the quit confirmation popup, shown while RSS feeds are refreshing
+    pub show_quit_popup: bool,
+    /// Flag for showing/hiding the confirmation popup for opening many articles in the browser
+    pub show_open_unread_popup: bool,
+    /// Flag for showing/hiding the popup for choosing among the RSS feeds discovered on a website
+    pub show_discovered_feeds_popup: bool,
+    /// RSS feed URLs discovered on the website entered in the add RSS feed wizard
+    pub discovered_feeds: Vec<String>,
+    /// Latest articles of the RSS feed entered in the add RSS feed wizard, shown in the preview popup while set
+    pub preview_articles: Option<Vec<Articles>>,
+    /// Number of lines scrolled in the preview popup
+    pub preview_scroll: u16,
+    /// Body of the selected RSS feed as returned by the server, shown in the raw feed popup while set
+    pub raw_feed: Option<String>,
+    /// Number of lines scrolled in the raw feed popup
+    pub raw_feed_scroll: u16,
+    /// Tag of the articles listed in the tagged articles popup, shown while set
+    pub tag_view: Option<String>,
+    /// Duplicate RSS feeds found, shown in the merge confirmation popup while set
+    pub duplicate_feeds: Option<Vec<DuplicateFeeds>>,
+    /// Text searched in the archived articles listed in the archive search popup, shown while set
+    pub archive_query: Option<String>,
+    /// Flag for showing/hiding the snoozed articles popup
+    pub show_snoozed_popup: bool,
+    /// Summary of the articles added since the last session, shown in the what's new popup while set
+    pub whats_new: Option<String>,
 }
 
 impl PopupApp {
@@ -67,10 +368,172 @@ impl PopupApp {
         PopupApp {
             show_refresh_popup: false,
             show_help_popup: false,
+            show_errors_popup: false,
+            show_log_popup: false,
+            show_stats_popup: false,
+            show_feed_details_popup: false,
+            help_scroll: 0,
+            import_report: None,
+            import_scroll: 0,
+            show_quit_popup: false,
+            show_open_unread_popup: false,
+            show_discovered_feeds_popup: false,
+            discovered_feeds: Vec::new(),
+            preview_articles: None,
+            preview_scroll: 0,
+            raw_feed: None,
+            raw_feed_scroll: 0,
+            tag_view: None,
+            duplicate_feeds: None,
+            archive_query: None,
+            show_snoozed_popup: false,
+            whats_new: None,
+        }
+    }
+}
+
+/// Defines the metadata for status messages shown in the footer
+pub struct StatusBarApp {
+    /// Latest status message, if any
+    pub message: Option<String>,
+    /// Time at which the latest status message was set
+    pub shown_at: Instant,
+}
+
+impl StatusBarApp {
+    fn new() -> StatusBarApp {
+        StatusBarApp {
+            message: None,
+            shown_at: Instant::now(),
+        }
+    }
+
+    /// Shows the given status message in the footer
+    fn set(&mut self, message: String) {
+        self.message = Some(message);
+        self.shown_at = Instant::now();
+    }
+
+    /// Shows the given error in the footer
+    fn set_error(&mut self, err_msg: &ErrorMessages) {
+        self.set(format!(
+            "{:?} - {}",
+            err_msg.error_code, err_msg.error_message
+        ));
+    }
+
+    /// Shows the outcome of opening articles in the browser
+    fn set_open_articles_result(&mut self, result: (usize, Option<ErrorMessages>)) {
+        match result {
+            (opened, Some(err_msg)) => self.set(format!(
+                "Opened {} articles in browser, some failed: {:?} - {}",
+                opened, err_msg.error_code, err_msg.error_message
+            )),
+            (0, None) => self.set(String::from("No unread articles to open")),
+            (opened, None) => self.set(format!("Opened {} articles in browser", opened)),
+        }
+    }
+
+    /// Returns the status message, unless it has expired
+    fn current(&self) -> Option<&str> {
+        match &self.message {
+            Some(message) if self.shown_at.elapsed() < STATUS_MESSAGE_DURATION => Some(message),
+            _ => None,
         }
     }
 }
 
+/// Switches the terminal to raw mode and the alternate screen, for drawing the terminal UI
+///
+/// Restricted terminals (e.g. CI shells, output redirected to a file) fail part way through,
+/// so any change already made is undone before returning the error.
+fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, ErrorMessages> {
+    if !io::stdout().is_terminal() {
+        return Err(ErrorMessages::new(
+            ErrorCodes::E0002_NEW_CROSSTERM_TERMINAL_FAILURE,
+        ));
+    }
+    enable_raw_mode()
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0001_ENABLE_RAW_MODE_FAILURE))?;
+
+    let mut stdout = io::stdout();
+    let terminal = execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
+        .map(|_| enable_bracketed_paste(&mut stdout))
+        .and_then(|_| Terminal::new(CrosstermBackend::new(stdout)))
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0002_NEW_CROSSTERM_TERMINAL_FAILURE))
+        .and_then(|mut terminal| match terminal.clear() {
+            Ok(()) => Ok(terminal),
+            Err(_err) => Err(ErrorMessages::new(ErrorCodes::E0003_TERMINAL_CLEAR_FAILURE)),
+        });
+
+    if terminal.is_err() {
+        disable_bracketed_paste(&mut io::stdout());
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        let _ = disable_raw_mode();
+    }
+    terminal
+}
+
+/// Enables bracketed paste where the terminal supports it, pasting falls back to key events otherwise
+fn enable_bracketed_paste(stdout: &mut io::Stdout) {
+    match execute!(stdout, EnableBracketedPaste) {
+        Ok(()) => BRACKETED_PASTE_ENABLED.store(true, Ordering::Relaxed),
+        Err(err) => log::warn!("Bracketed paste not enabled: {}", err),
+    }
+}
+
+/// Disables bracketed paste, if enabled at startup
+fn disable_bracketed_paste<W: io::Write>(writer: &mut W) {
+    if BRACKETED_PASTE_ENABLED.swap(false, Ordering::Relaxed) {
+        let _ = execute!(writer, DisableBracketedPaste);
+    }
+}
+
+/// Restores the terminal to its original state before exiting the application
+fn restore_terminal(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) -> Result<(), io::Error> {
+    disable_raw_mode().unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0015_DISABLE_RAW_MODE_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+
+    disable_bracketed_paste(terminal.backend_mut());
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+
+    terminal.clear().unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0015_TERMINAL_CLEAR_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+
+    terminal.show_cursor().unwrap_or_else(|_err| {
+        let err_msg = ErrorMessages::new(ErrorCodes::E0016_TERMINAL_SHOW_CURSOR_FAILURE);
+        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    });
+    Ok(())
+}
+
+/// Runs given future to completion on a background thread, with its own Tokio runtime
+fn run_in_background<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap_or_else(|_err| {
+                let err_msg = ErrorMessages::new(ErrorCodes::E0018_TOKIO_RUNTIME_BUILDER_FAILURE);
+                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+            });
+        rt.block_on(future);
+    });
+}
+
 fn show_popup(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -97,49 +560,256 @@ fn show_popup(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Stand-in for the HTTP server in builds without the "serve" feature (rejected by `cli::parse_args`)
+#[cfg(not(feature = "serve"))]
+async fn serve(_port: u16) -> Result<(), ErrorMessages> {
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    enable_raw_mode().unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0001_ENABLE_RAW_MODE_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+    let cli_args = cli::parse_args();
+    if let Err(err_msg) = load_config(cli_args.config_path.as_deref()) {
+        eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+        std::process::exit(2);
+    }
+    set_sequential_fetch(cli_args.sequential_fetch);
+    init_logging();
+
+    if let Some(file_path) = &cli_args.import_opml {
+        match import_opml(file_path).await {
+            Ok(import_report) => {
+                println!("{} from {}", import_report.summary(), file_path);
+                for invalid_feed in import_report.invalid.iter() {
+                    println!(
+                        "  Invalid entry {} ({}): {}",
+                        invalid_feed.opml_feed.name,
+                        invalid_feed.opml_feed.url,
+                        invalid_feed.reason
+                    );
+                }
+                return Ok(());
+            }
+            Err(err_msg) => {
+                eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let feed_list_import = match (&cli_args.add_feeds, &cli_args.import_newsboat) {
+        (Some(file_path), _) if file_path == "-" => Some((file_path, add_feeds_from_stdin().await)),
+        (Some(file_path), _) => Some((file_path, add_feeds_from_file(file_path).await)),
+        (None, Some(file_path)) => Some((file_path, import_newsboat(file_path).await)),
+        (None, None) => None,
+    };
+
+    if let Some((file_path, feed_list_report)) = feed_list_import {
+        match feed_list_report {
+            Ok(feed_list_report) => {
+                println!("{} from {}", feed_list_report.summary(), file_path);
+                for failed_line in feed_list_report.failed.iter() {
+                    println!(
+                        "  Line {} ({}): {}",
+                        failed_line.line_number, failed_line.line, failed_line.reason
+                    );
+                }
+                return Ok(());
+            }
+            Err(err_msg) => {
+                eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(file_path) = &cli_args.export_history {
+        match export_read_history(file_path, ReadHistoryFormat::from_path(file_path)) {
+            Ok(num_entries) => {
+                println!("Exported {} viewed articles to {}", num_entries, file_path);
+                return Ok(());
+            }
+            Err(err_msg) => {
+                eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if cli_args.reset_db {
+        println!("This clears all RSS feeds and articles (a backup is made first).");
+        println!("Type \"{}\" to confirm:", RESET_CONFIRMATION);
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim() != RESET_CONFIRMATION {
+            println!("Nothing was reset");
+            return Ok(());
+        }
+        match reset_db() {
+            Ok(backup_dir) => {
+                println!(
+                    "Reset RSS feeds and articles, backup in {}",
+                    backup_dir.display()
+                );
+                return Ok(());
+            }
+            Err(err_msg) => {
+                eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(repair) = cli_args.check_db {
+        println!("{}", check_db_consistency(repair).summary());
+        return Ok(());
+    }
+
+    if let Some(file_path) = &cli_args.export_opml {
+        match export_opml_file(file_path) {
+            Ok(()) => {
+                println!("Exported RSS feeds to {}", file_path);
+                return Ok(());
+            }
+            Err(err_msg) => {
+                eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let (Some(port), true) = (cli_args.serve_port, cli_args.headless) {
+        if let Err(err_msg) = serve(port).await {
+            eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let mut terminal = setup_terminal().unwrap_or_else(|err_msg| {
+        eprintln!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+        eprintln!("{}", UNSUPPORTED_TERMINAL_HELP);
+        std::process::exit(1);
     });
 
     let mut popup_app = PopupApp::new();
+    let mut status_bar_app = StatusBarApp::new();
     let mut inputbox_app = InputBoxApp::new();
 
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let mut terminal = Terminal::new(CrosstermBackend::new(stdout)).unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0002_NEW_CROSSTERM_TERMINAL_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
-    terminal.clear().unwrap_or_else(|_err| {
-        let err_msg = ErrorMessages::new(ErrorCodes::E0003_TERMINAL_CLEAR_FAILURE);
-        panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-    });
-
     let mut rss_list_state = ListState::default();
     rss_list_state.select(Some(0));
 
     let mut articles_list_state = ListState::default();
+    let mut articles_table_state = TableState::default();
+    let mut prompt_app = PromptApp::default();
+    // Ids of the selected RSS feed and article, for keeping the selection across reloads
+    let mut selected_ids: (usize, Option<usize>) = (0, None);
     articles_list_state.select(Some(0));
 
+    let mut errors_list_state = ListState::default();
+    let mut tagged_list_state = ListState::default();
+    let mut archive_list_state = ListState::default();
+    let mut snoozed_list_state = ListState::default();
+    let mut log_list_state = ListState::default();
+    let mut discovered_feeds_list_state = ListState::default();
+    let mut ui_state = read_ui_state();
+    set_feed_sort(ui_state.feed_sort);
+    // Nothing to summarize on the first-ever run, without any previous session
+    if let Some(last_session_at) = ui_state.last_session_at {
+        let (num_articles, num_feeds) = count_new_articles_since(last_session_at);
+        if num_articles > 0 {
+            popup_app.whats_new = Some(format!(
+                "Since your last session ({}): {} new articles across {} feeds",
+                format_timestamp(&last_session_at),
+                num_articles,
+                num_feeds
+            ));
+            inputbox_app.input_mode = InputMode::Popup;
+        }
+    }
+    let mut render_options = RenderOptions {
+        summary_emphasis: ui_state.summary_emphasis,
+        ..RenderOptions::default()
+    };
+    let keymap = Keymap::default();
+    // Status messages sent by background tasks, shown in the footer once received
+    let (status_sender, status_receiver) = mpsc::channel::<String>();
+
+    // Alongside the UI, a server failing to start is reported without stopping the application
+    if let Some(port) = cli_args.serve_port {
+        let status_sender = status_sender.clone();
+        tokio::spawn(async move {
+            if let Err(err_msg) = serve(port).await {
+                log::error!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                let _ = status_sender.send(format!(
+                    "{:?} - {}",
+                    err_msg.error_code, err_msg.error_message
+                ));
+            }
+        });
+    }
+
     loop {
+        while let Ok(message) = status_receiver.try_recv() {
+            status_bar_app.set(message);
+        }
+
+        // Refreshes change unread counts, so the RSS feeds list can be re-sorted between renders
+        if let Some(rss_selected) = read_rss_feed_list()
+            .iter()
+            .position(|r| r.rss_id == selected_ids.0)
+        {
+            rss_list_state.select(Some(rss_selected));
+        }
+
         terminal.draw(|rect| {
             let size = rect.size();
+
+            if size.width < MIN_TERMINAL_WIDTH || size.height < MIN_TERMINAL_HEIGHT {
+                let too_small = Paragraph::new(format!(
+                    "Terminal too small ({}x{}) - please resize to at least {}x{}",
+                    size.width, size.height, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
+                ))
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true });
+                rect.render_widget(too_small, size);
+                return;
+            }
+            let is_editing = matches!(inputbox_app.input_mode, InputMode::Editing);
+            let footer_message = match (status_bar_app.current(), is_editing) {
+                (Some(message), _) => Some(Spans::from(Span::styled(
+                    message.to_string(),
+                    Style::default().fg(Color::Yellow),
+                ))),
+                (None, true) => Some(Spans::from(Span::styled(
+                    "Tab / Shift+Tab: switch field | Enter: add RSS feed | Ctrl+P: preview | Esc: cancel",
+                    Style::default().fg(Color::LightCyan),
+                ))),
+                (None, false) if get_config().show_legend => {
+                    Some(Spans::from(get_config().theme.legend()))
+                }
+                (None, false) if get_config().show_footer => Some(Spans::from(Span::styled(
+                    "Released and maintained under GPL-3.0 license",
+                    Style::default().fg(Color::LightCyan),
+                ))),
+                (None, false) => None,
+            };
+
+            // Without anything to show in the footer, its rows go to the RSS feeds and articles
+            let mut constraints = vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(2),
+                Constraint::Length(3),
+            ];
+            if footer_message.is_some() {
+                constraints.push(Constraint::Length(3));
+            }
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(2)
-                .constraints(
-                    [
-                        Constraint::Length(3),
-                        Constraint::Length(3),
-                        Constraint::Min(2),
-                        Constraint::Length(3),
-                        Constraint::Length(3),
-                    ]
-                    .as_ref(),
-                )
+                .constraints(constraints)
                 .split(size);
 
             let heading = Paragraph::new(APP_HEADING)
@@ -178,7 +848,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             rect.render_widget(menu_titles, chunks[1]);
 
+            // Collapsing the summary pane gives its width to the RSS feeds and articles lists
+            let rss_constraints = match ui_state.summary_collapsed {
+                true => vec![Constraint::Percentage(30), Constraint::Percentage(70)],
+                false => vec![
+                    Constraint::Percentage(20),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(50),
+                ],
+            };
             let rss_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(rss_constraints)
+                .split(chunks[2]);
+
+            render_options.feeds_pane_width = rss_chunks[0].width;
+            render_options.articles_pane_width = rss_chunks[1].width;
+            if let Some(summary_chunk) = rss_chunks.get(2) {
+                render_options.summary_pane_width = summary_chunk.width;
+                render_options.summary_pane_height = summary_chunk.height;
+            }
+
+            let article_column_widths =
+                article_column_widths(&article_columns(), rss_chunks[1].width);
+            articles_table_state.select(articles_list_state.selected());
+
+            let (left, middle, right) = render_rss_feed_list(
+                &read_rss_feed_list(),
+                &read_articles_db(),
+                &rss_list_state,
+                &articles_list_state,
+                &mut render_options,
+                &article_column_widths,
+            );
+            rect.render_stateful_widget(left, rss_chunks[0], &mut rss_list_state);
+            rect.render_stateful_widget(middle, rss_chunks[1], &mut articles_table_state);
+            if let Some(summary_chunk) = rss_chunks.get(2) {
+                rect.render_widget(right, *summary_chunk);
+            }
+
+            let input_chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints(
                     [
@@ -188,46 +897,61 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ]
                     .as_ref(),
                 )
-                .split(chunks[2]);
+                .split(chunks[3]);
 
-            let (left, middle, right) = render_rss_feed_list(&rss_list_state, &articles_list_state);
-            rect.render_stateful_widget(left, rss_chunks[0], &mut rss_list_state);
-            rect.render_stateful_widget(middle, rss_chunks[1], &mut articles_list_state);
-            rect.render_widget(right, rss_chunks[2]);
-
-            let rss_url = Paragraph::new(inputbox_app.text_input.as_ref())
-                .style(match inputbox_app.input_mode {
-                    InputMode::Normal => Style::default(),
-                    InputMode::Editing => Style::default().fg(Color::Yellow),
-                    InputMode::Popup => Style::default(),
-                })
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title("Add new RSS feed (<RSS category> | <RSS Name> | <RSS Url>). Press <Enter> to submit."),
-                );
-            rect.render_widget(rss_url, chunks[3]);
+            for (field, field_title) in ADD_FEED_FIELDS.iter().enumerate() {
+                let text_input = &inputbox_app.fields[field].text_input;
+                let is_focused = is_editing && field == inputbox_app.focused_field;
+                let is_invalid = !text_input.is_empty() && !inputbox_app.is_field_valid(field);
 
-            match inputbox_app.input_mode {
-                InputMode::Normal => {}
-                InputMode::Editing => rect.set_cursor(
-                    chunks[3].x + inputbox_app.text_input.width() as u16 + 1,
-                    chunks[3].y + 1,
-                ),
-                InputMode::Popup => {}
+                let field_title = match (field == URL_FIELD && is_invalid, field) {
+                    (true, _) => format!("{} (invalid)", field_title),
+                    (false, 0) if !is_editing => String::from("Category (press a to add new RSS feed)"),
+                    _ => field_title.to_string(),
+                };
+
+                let border_style = match (is_focused, is_invalid) {
+                    (_, true) => Style::default().fg(Color::LightRed),
+                    (true, false) => Style::default().fg(Color::Yellow),
+                    (false, false) => Style::default(),
+                };
+
+                let field_input = Paragraph::new(text_input.as_ref())
+                    .style(match is_focused {
+                        true => Style::default().fg(Color::Yellow),
+                        false => Style::default(),
+                    })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_style(border_style)
+                            .title(field_title),
+                    );
+                rect.render_widget(field_input, input_chunks[field]);
             }
 
-            let license = Paragraph::new("Released and maintained under GPL-3.0 license")
-                .style(Style::default().fg(Color::LightCyan))
-                .alignment(Alignment::Center)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .style(Style::default().fg(Color::White))
-                        .border_type(BorderType::Plain),
+            if is_editing {
+                let focused_chunk = input_chunks[inputbox_app.focused_field];
+                let text_before_cursor =
+                    inputbox_app.fields[inputbox_app.focused_field].text_before_cursor();
+                rect.set_cursor(
+                    focused_chunk.x + text_before_cursor.width() as u16 + 1,
+                    focused_chunk.y + 1,
                 );
+            }
 
-            rect.render_widget(license, chunks[4]);
+            if let (Some(message), Some(footer_chunk)) = (footer_message, chunks.get(4)) {
+                let footer = Paragraph::new(message)
+                    .alignment(Alignment::Center)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .border_type(BorderType::Plain),
+                    );
+
+                rect.render_widget(footer, *footer_chunk);
+            }
 
             if popup_app.show_refresh_popup {
                 let area = show_popup(50, 15, size);
@@ -248,107 +972,421 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 rect.render_widget(popup_text, area);
             }
 
-            if popup_app.show_help_popup {
-                let area = show_popup(60, 40, size);
+            if popup_app.show_discovered_feeds_popup {
+                let area = show_popup(70, 40, size);
 
-                let rss_chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints(
-                    [
-                        Constraint::Percentage(35),
-                        Constraint::Percentage(65),
-                    ]
-                    .as_ref(),
-                )
-                .split(area);
+                let items: Vec<_> = popup_app
+                    .discovered_feeds
+                    .iter()
+                    .map(|feed_url| ListItem::new(feed_url.clone()))
+                    .collect();
 
-                let popup_title_text = Paragraph::new(vec![
-                    Spans::from(vec![Span::raw("")]),
+                let discovered_feeds_list = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("RSS Feeds found on website (Press Enter to add, Esc to go back)")
+                            .border_type(BorderType::Plain),
+                    )
+                    .highlight_style(
+                        get_config().theme.highlight_style(),
+                    );
+
+                rect.render_widget(Clear, area);
+                rect.render_stateful_widget(
+                    discovered_feeds_list,
+                    area,
+                    &mut discovered_feeds_list_state,
+                );
+            }
+
+            if let InputMode::Prompting = inputbox_app.input_mode {
+                let area = show_popup(50, 20, size);
+                let (prompt_title, input_title, help_text) = match prompt_app.kind {
+                    PromptKind::MarkReadBefore => (
+                        "Mark older articles as read (Enter: confirm, Esc: cancel)",
+                        "Older than (e.g. 12h, 7d, 2w)",
+                        format!(
+                            "Applies to: {} (Tab to switch). Bookmarked articles are kept unread.",
+                            match prompt_app.all_feeds {
+                                true => "all RSS feeds",
+                                false => "selected RSS feed",
+                            }
+                        ),
+                    ),
+                    PromptKind::ImportOpml => (
+                        "Import RSS feeds from OPML file (Enter: import, Esc: cancel)",
+                        "OPML file path",
+                        String::from("RSS feeds already present are skipped."),
+                    ),
+                    PromptKind::RenameCategory => (
+                        "Rename category (Enter: confirm, Esc: cancel)",
+                        "New category",
+                        format!(
+                            "Renames \"{}\" on all RSS feeds filed under it.",
+                            read_selected_rss_feed(&rss_list_state).category
+                        ),
+                    ),
+                    PromptKind::DuplicateFeed => (
+                        "Copy RSS feed under another category (Enter: copy, Esc: cancel)",
+                        "Category",
+                        format!(
+                            "Copies \"{}\"; the copy fetches and keeps its own articles.",
+                            read_selected_rss_feed(&rss_list_state).label()
+                        ),
+                    ),
+                    PromptKind::MuteKeywords => (
+                        "Muted keywords of RSS feed (Enter: confirm, Esc: cancel)",
+                        "Keywords (comma-separated)",
+                        String::from(
+                            "Articles mentioning any of them in their title or summary are left out of the list. Leave empty to mute nothing.",
+                        ),
+                    ),
+                    PromptKind::EditTags => (
+                        "Tags of article (Enter: confirm, Esc: cancel)",
+                        "Tags (comma-separated)",
+                        String::from("e.g. to-blog, reference. Leave empty to remove all tags."),
+                    ),
+                    PromptKind::ShowTaggedArticles => (
+                        "Show articles with tag (Enter: show, Esc: cancel)",
+                        "Tag",
+                        match read_article_tags() {
+                            tags if tags.is_empty() => {
+                                String::from("No tags yet, press T on an article to tag it.")
+                            }
+                            tags => format!(
+                                "Tags: {}",
+                                tags.iter()
+                                    .map(|(tag, num_articles)| format!("{} ({})", tag, num_articles))
+                                    .collect::<Vec<String>>()
+                                    .join(", ")
+                            ),
+                        },
+                    ),
+                    PromptKind::SearchArchive => (
+                        "Search archived articles (Enter: search, Esc: cancel)",
+                        "Text",
+                        String::from(
+                            "Archived articles with the text in their title or summary are listed.",
+                        ),
+                    ),
+                    PromptKind::SnoozeArticle => (
+                        "Snooze article (Enter: confirm, Esc: cancel)",
+                        "Snooze for (e.g. 12h, 1d, 2w)",
+                        String::from(
+                            "The article is left out of the list until then, and comes back unread. Press H to see snoozed articles.",
+                        ),
+                    ),
+                    PromptKind::ResetDb => (
+                        "Reset all RSS feeds and articles (Enter: confirm, Esc: cancel)",
+                        "Confirmation",
+                        format!(
+                            "Type \"{}\" to clear all RSS feeds, articles and archived articles. The JSON files are backed up first.",
+                            RESET_CONFIRMATION
+                        ),
+                    ),
+                    PromptKind::RenameFeed => (
+                        "Set display name of RSS feed (Enter: confirm, Esc: cancel)",
+                        "Display name",
+                        format!(
+                            "Shown instead of \"{}\". Leave empty to show the RSS feed name.",
+                            read_selected_rss_feed(&rss_list_state).name
+                        ),
+                    ),
+                };
+
+                let prompt_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title(prompt_title)
+                    .border_type(BorderType::Plain);
+                let prompt_area = prompt_block.inner(area);
+
+                let prompt_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+                    .split(prompt_area);
+
+                let duration_input =
+                    Paragraph::new(prompt_app.input.text_input.as_ref())
+                        .style(Style::default().fg(Color::Yellow))
+                        .block(
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .border_style(Style::default().fg(Color::Yellow))
+                                .title(input_title),
+                        );
+
+                let scope_text = Paragraph::new(help_text)
+                    .style(Style::default().fg(Color::LightCyan))
+                    .wrap(Wrap { trim: true });
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(prompt_block, area);
+                rect.render_widget(duration_input, prompt_chunks[0]);
+                rect.render_widget(scope_text, prompt_chunks[1]);
+                rect.set_cursor(
+                    prompt_chunks[0].x
+                        + prompt_app.input.text_before_cursor().width() as u16
+                        + 1,
+                    prompt_chunks[0].y + 1,
+                );
+            }
+
+            if let Some(import_report) = &popup_app.import_report {
+                let area = show_popup(70, 60, size);
+
+                let mut report_lines = vec![
                     Spans::from(vec![Span::styled(
-                        "Welcome to Byte-Bite",
-                        Style::default()
-                            .fg(Color::LightBlue)
-                            .add_modifier(Modifier::BOLD),
+                        import_report.summary(),
+                        Style::default().fg(Color::LightCyan).add_modifier(Modifier::BOLD),
                     )]),
                     Spans::from(vec![Span::raw("")]),
+                ];
+                let report_sections = [
+                    ("Imported", &import_report.imported, Color::LightGreen),
+                    ("Skipped (already present)", &import_report.duplicates, Color::Gray),
+                ];
+                for (section_title, opml_feeds, color) in report_sections {
+                    if opml_feeds.is_empty() {
+                        continue;
+                    }
+                    report_lines.push(Spans::from(vec![Span::styled(
+                        format!("{}:", section_title),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )]));
+                    report_lines.extend(opml_feeds.iter().map(|opml_feed| {
+                        Spans::from(vec![Span::styled(
+                            format!("  {} - {} ({})", opml_feed.category, opml_feed.name, opml_feed.url),
+                            Style::default().fg(color),
+                        )])
+                    }));
+                    report_lines.push(Spans::from(vec![Span::raw("")]));
+                }
+                if !import_report.invalid.is_empty() {
+                    report_lines.push(Spans::from(vec![Span::styled(
+                        "Invalid entries:",
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )]));
+                    report_lines.extend(import_report.invalid.iter().map(|invalid_feed| {
+                        Spans::from(vec![Span::styled(
+                            format!(
+                                "  {} ({}): {}",
+                                invalid_feed.opml_feed.name, invalid_feed.opml_feed.url, invalid_feed.reason
+                            ),
+                            Style::default().fg(Color::LightRed),
+                        )])
+                    }));
+                }
+
+                let report_text = Paragraph::new(report_lines)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("OPML Import (arrow-up / arrow-down to scroll, Esc to go back)")
+                            .border_type(BorderType::Plain),
+                    )
+                    .wrap(Wrap { trim: false })
+                    .scroll((popup_app.import_scroll, 0));
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(report_text, area);
+            }
+
+            if let Some(preview_articles) = &popup_app.preview_articles {
+                let area = show_popup(70, 60, size);
+
+                let mut preview_lines = vec![
                     Spans::from(vec![Span::styled(
-                        "Take a bite out of the news and updates with ByteBite, the bite-sized RSS feed reader that delivers all the essential  news in a pocket-size format.",
-                        Style::default().fg(Color::LightBlue),
+                        format!("{} latest articles", preview_articles.len()),
+                        Style::default().fg(Color::LightCyan).add_modifier(Modifier::BOLD),
                     )]),
                     Spans::from(vec![Span::raw("")]),
-                ])
-                .alignment(Alignment::Center)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
+                ];
+                for article in preview_articles.iter() {
+                    let excerpt: String = article_body_text(article, false).chars().take(200).collect();
+                    preview_lines.push(Spans::from(vec![
+                        Span::styled(
+                            article.title.clone(),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" ({})", format_timestamp(&article.pub_date)),
+                            Style::default().fg(Color::Gray),
+                        ),
+                    ]));
+                    preview_lines.push(Spans::from(vec![Span::styled(
+                        excerpt,
+                        Style::default().fg(Color::LightBlue),
+                    )]));
+                    preview_lines.push(Spans::from(vec![Span::raw("")]));
+                }
+
+                let preview_text = Paragraph::new(preview_lines)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("Preview (Enter: subscribe, Esc: discard, arrow-up / arrow-down to scroll)")
+                            .border_type(BorderType::Plain),
+                    )
+                    .wrap(Wrap { trim: true })
+                    .scroll((popup_app.preview_scroll, 0));
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(preview_text, area);
+            }
+
+            if popup_app.show_quit_popup {
+                let area = show_popup(50, 15, size);
+                let popup_message = match is_any_feed_refreshing() {
+                    true => "Refresh in progress - quit anyway? (y: quit, n/Esc: cancel)",
+                    false => "Refresh finished - quit now? (y: quit, n/Esc: cancel)",
+                };
+
+                let popup_text = Paragraph::new(popup_message)
+                    .style(Style::default().fg(Color::LightCyan))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .border_type(BorderType::Plain),
+                    );
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(popup_text, area);
+            }
+
+            if let Some(whats_new) = &popup_app.whats_new {
+                let area = show_popup(50, 15, size);
+                let popup_text = Paragraph::new(format!("{} (Press Esc to go back)", whats_new))
+                    .style(Style::default().fg(Color::LightCyan))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("What's new")
+                            .border_type(BorderType::Plain),
+                    );
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(popup_text, area);
+            }
+
+            if popup_app.show_open_unread_popup {
+                let area = show_popup(50, 15, size);
+                let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                let num_unread = read_unread_articles(selected_rss_feed.rss_id).len();
+
+                let popup_text = Paragraph::new(format!(
+                    "Open {} unread articles of \"{}\" in the browser? (y: open all, Esc: cancel)",
+                    num_unread,
+                    selected_rss_feed.label()
+                ))
+                .style(Style::default().fg(Color::LightCyan))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
                         .style(Style::default().fg(Color::White))
                         .border_type(BorderType::Plain),
                 );
 
-                let popup_help_text = Paragraph::new(vec![
+                rect.render_widget(Clear, area);
+                rect.render_widget(popup_text, area);
+            }
+
+            if popup_app.show_help_popup {
+                let area = show_popup(70, 80, size);
+
+                let rss_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(7),
+                        Constraint::Min(3),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+
+                let popup_title_text = Paragraph::new(vec![
                     Spans::from(vec![Span::raw("")]),
                     Spans::from(vec![Span::styled(
-                        "       Keyboard Navigation Help",
-                        Style::default().fg(Color::Yellow),
+                        "Welcome to Byte-Bite",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
                     )]),
                     Spans::from(vec![Span::raw("")]),
                     Spans::from(vec![Span::styled(
-                        "       a                     ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Add new RSS feed url",
-                        Style::default().fg(Color::White),
-                    )]),
-                    Spans::from(vec![Span::styled(
-                        "       d                     ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Delete existing RSS feed",
-                        Style::default().fg(Color::White),
-                    )]),
-                    Spans::from(vec![Span::styled(
-                        "       r                     ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Refresh articles for RSS feed",
-                        Style::default().fg(Color::White),
-                    )]),
-                    Spans::from(vec![Span::styled(
-                        "       page-up / page-down   ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Navigate through list of RSS feeds",
-                        Style::default().fg(Color::White),
-                    )]),
-                    Spans::from(vec![Span::styled(
-                        "       arrow-up / arrow-down ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Navigate through list of articles in each RSS feed",
-                        Style::default().fg(Color::White),
-                    )]),
-                    Spans::from(vec![Span::styled(
-                        "       esc                   ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Exit RSS add option / Exit popup windows",
-                        Style::default().fg(Color::White),
+                        "Take a bite out of the news and updates with ByteBite, the bite-sized RSS feed reader that delivers all the essential  news in a pocket-size format.",
+                        Style::default().fg(Color::LightBlue),
                     )]),
+                    Spans::from(vec![Span::raw("")]),
+                ])
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::White))
+                        .border_type(BorderType::Plain),
+                );
+
+                let mut legend = vec![Span::raw("       ")];
+                legend.extend(get_config().theme.legend());
+
+                let mut help_lines = vec![
+                    Spans::from(vec![Span::raw("")]),
                     Spans::from(vec![Span::styled(
-                        "       h                     ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Open help menu",
-                        Style::default().fg(Color::White),
+                        "       Legend",
+                        Style::default().fg(Color::Yellow),
                     )]),
+                    Spans::from(vec![Span::raw("")]),
+                    Spans::from(legend),
+                    Spans::from(vec![Span::raw("")]),
                     Spans::from(vec![Span::styled(
-                        "       q                     ",
-                        Style::default().fg(Color::LightGreen),
-                    ), Span::styled(
-                        " --> Exit the application",
-                        Style::default().fg(Color::White),
+                        "       Keyboard Navigation Help",
+                        Style::default().fg(Color::Yellow),
                     )]),
-                ])
+                ];
+
+                for context in [KeyContext::Global, KeyContext::Feeds, KeyContext::Articles] {
+                    help_lines.push(Spans::from(vec![Span::raw("")]));
+                    help_lines.push(Spans::from(vec![Span::styled(
+                        format!("       {}", context.title()),
+                        Style::default().fg(Color::LightBlue).add_modifier(Modifier::BOLD),
+                    )]));
+
+                    for binding in keymap.bindings(context) {
+                        help_lines.push(Spans::from(vec![
+                            Span::styled(
+                                format!("       {:<22}", key_label(binding.code, binding.modifiers)),
+                                Style::default().fg(Color::LightGreen),
+                            ),
+                            Span::styled(
+                                format!(" --> {}", binding.description),
+                                Style::default().fg(Color::White),
+                            ),
+                        ]));
+                    }
+                }
+
+                // Keeps the last lines of help at the bottom of the popup, whatever its height
+                let visible_height = rss_chunks[1].height.saturating_sub(2);
+                popup_app.help_scroll = popup_app
+                    .help_scroll
+                    .min((help_lines.len() as u16).saturating_sub(visible_height));
+
+                let popup_help_text = Paragraph::new(help_lines)
+                .scroll((popup_app.help_scroll, 0))
                 .alignment(Alignment::Left)
                 .block(
                     Block::default()
@@ -361,64 +1399,1121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 rect.render_widget(popup_title_text, rss_chunks[0]);
                 rect.render_widget(popup_help_text, rss_chunks[1]);
             }
+
+            if popup_app.show_feed_details_popup {
+                let area = show_popup(70, 50, size);
+                let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                let rss_articles = read_rss_articles(selected_rss_feed.rss_id);
+                let not_set = || String::from("-");
+
+                let details = [
+                    ("Name", selected_rss_feed.name.clone()),
+                    (
+                        "Display name",
+                        selected_rss_feed.display_name.clone().unwrap_or_else(not_set),
+                    ),
+                    ("Category", selected_rss_feed.category.clone()),
+                    ("URL", selected_rss_feed.url.clone()),
+                    (
+                        "Channel title",
+                        selected_rss_feed.channel_title.clone().unwrap_or_else(not_set),
+                    ),
+                    (
+                        "Website",
+                        selected_rss_feed.site_url.clone().unwrap_or_else(not_set),
+                    ),
+                    (
+                        "Logo",
+                        selected_rss_feed.logo_url.clone().unwrap_or_else(not_set),
+                    ),
+                    (
+                        "Status",
+                        String::from(match selected_rss_feed.enabled {
+                            true => "active",
+                            false => "paused",
+                        }),
+                    ),
+                    ("Added on", format_timestamp(&selected_rss_feed.created_at())),
+                    (
+                        "Last modified",
+                        selected_rss_feed.last_modified.clone().unwrap_or_else(not_set),
+                    ),
+                    (
+                        "Update hints",
+                        selected_rss_feed
+                            .update_hints
+                            .as_ref()
+                            .map(|update_hints| update_hints.describe())
+                            .unwrap_or_else(not_set),
+                    ),
+                    (
+                        "Max items per refresh",
+                        selected_rss_feed
+                            .max_items_per_refresh
+                            .map(|max_items| max_items.to_string())
+                            .unwrap_or_else(|| String::from("unlimited")),
+                    ),
+                    (
+                        "Articles",
+                        format!(
+                            "{} ({} unread)",
+                            rss_articles.len(),
+                            rss_articles.iter().filter(|r| !r.read).count()
+                        ),
+                    ),
+                    (
+                        "Fetches",
+                        match read_fetch_stats(selected_rss_feed.rss_id) {
+                            Some(fetch_stats) => format!(
+                                "{} ms on average, {:.0}% failed (last {} fetches)",
+                                fetch_stats.avg_response_time.as_millis(),
+                                fetch_stats.failure_rate * 100.0,
+                                fetch_stats.num_fetches
+                            ),
+                            None => String::from("not fetched since startup"),
+                        },
+                    ),
+                ];
+
+                let details_text = Paragraph::new(
+                    details
+                        .into_iter()
+                        .map(|(label, value)| {
+                            Spans::from(vec![
+                                Span::styled(
+                                    format!("{}: ", label),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::styled(value, Style::default().fg(Color::LightCyan)),
+                            ])
+                        })
+                        .collect::<Vec<Spans>>(),
+                )
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .style(Style::default().fg(Color::White))
+                        .title("RSS Feed Details (W: open website, x: show raw feed, Esc: go back)")
+                        .border_type(BorderType::Plain),
+                )
+                .wrap(Wrap { trim: false });
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(details_text, area);
+            }
+
+            if let Some(raw_feed) = &popup_app.raw_feed {
+                let area = show_popup(80, 80, size);
+
+                let raw_feed_text = Paragraph::new(raw_feed.as_str())
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("Raw RSS Feed (c: copy to clipboard, arrow-up / arrow-down to scroll, Esc: go back)")
+                            .border_type(BorderType::Plain),
+                    )
+                    .wrap(Wrap { trim: false })
+                    .scroll((popup_app.raw_feed_scroll, 0));
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(raw_feed_text, area);
+            }
+
+            if popup_app.show_stats_popup {
+                let area = show_popup(70, 70, size);
+                let feed_stats = compute_feed_stats();
+
+                let stats_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title("Statistics (Press Esc to go back)")
+                    .border_type(BorderType::Plain);
+                let stats_area = stats_block.inner(area);
+
+                let stats_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(
+                        [
+                            Constraint::Length(2),
+                            Constraint::Min(5),
+                            Constraint::Length(feed_stats.most_active_feeds.len().max(1) as u16 + 2),
+                            Constraint::Length(feed_stats.slowest_feeds.len().max(1) as u16 + 2),
+                        ]
+                        .as_ref(),
+                    )
+                    .split(stats_area);
+
+                let totals_text = Paragraph::new(format!(
+                    "RSS feeds: {} | Articles: {} | Unread: {}",
+                    feed_stats.num_feeds, feed_stats.num_articles, feed_stats.num_unread
+                ))
+                .style(Style::default().fg(Color::LightCyan))
+                .alignment(Alignment::Center);
+
+                let chart_data: Vec<(&str, u64)> = feed_stats
+                    .articles_per_day
+                    .iter()
+                    .map(|(day, num_articles)| (day.as_str(), *num_articles))
+                    .collect();
+                let bar_width = (stats_chunks[1].width.saturating_sub(2) / STATS_DAYS as u16)
+                    .saturating_sub(1)
+                    .max(1);
+                let articles_chart = BarChart::default()
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(format!("Articles added per day (last {} days)", STATS_DAYS)),
+                    )
+                    .data(&chart_data)
+                    .bar_width(bar_width)
+                    .bar_gap(1)
+                    .bar_style(Style::default().fg(Color::LightCyan))
+                    .value_style(
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::LightCyan)
+                            .add_modifier(Modifier::BOLD),
+                    );
+
+                let active_feeds: Vec<ListItem> = match feed_stats.most_active_feeds.is_empty() {
+                    true => vec![ListItem::new("No articles added recently")],
+                    false => feed_stats
+                        .most_active_feeds
+                        .iter()
+                        .map(|(feed_name, num_articles)| {
+                            ListItem::new(Spans::from(vec![
+                                Span::styled(
+                                    feed_name.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::raw(format!(" ({} articles)", num_articles)),
+                            ]))
+                        })
+                        .collect(),
+                };
+                let active_feeds_list = List::new(active_feeds).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!("Most active RSS feeds (last {} days)", STATS_DAYS)),
+                );
+
+                let slowest_feeds: Vec<ListItem> = match feed_stats.slowest_feeds.is_empty() {
+                    true => vec![ListItem::new("No RSS feeds fetched since startup")],
+                    false => feed_stats
+                        .slowest_feeds
+                        .iter()
+                        .map(|(feed_name, fetch_stats)| {
+                            ListItem::new(Spans::from(vec![
+                                Span::styled(
+                                    feed_name.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::raw(format!(
+                                    " ({} ms on average, {:.0}% failed)",
+                                    fetch_stats.avg_response_time.as_millis(),
+                                    fetch_stats.failure_rate * 100.0
+                                )),
+                            ]))
+                        })
+                        .collect(),
+                };
+                let slowest_feeds_list = List::new(slowest_feeds).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Slowest RSS feeds (recent fetches since startup)"),
+                );
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(stats_block, area);
+                rect.render_widget(totals_text, stats_chunks[0]);
+                rect.render_widget(articles_chart, stats_chunks[1]);
+                rect.render_widget(active_feeds_list, stats_chunks[2]);
+                rect.render_widget(slowest_feeds_list, stats_chunks[3]);
+            }
+
+            if let Some(duplicate_feeds) = &popup_app.duplicate_feeds {
+                let area = show_popup(70, 50, size);
+
+                let mut popup_lines = vec![
+                    Spans::from(vec![Span::styled(
+                        "These RSS feeds serve the same articles, merge each duplicate into the RSS feed kept?",
+                        Style::default().fg(Color::LightCyan),
+                    )]),
+                    Spans::from(vec![Span::raw("")]),
+                ];
+                for candidate in duplicate_feeds {
+                    popup_lines.push(Spans::from(vec![
+                        Span::styled(
+                            candidate.duplicate.label().to_string(),
+                            Style::default()
+                                .fg(Color::LightRed)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(" -> "),
+                        Span::styled(
+                            candidate.kept.label().to_string(),
+                            Style::default()
+                                .fg(Color::LightGreen)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(format!(" ({} shared articles)", candidate.num_shared)),
+                    ]));
+                }
+
+                let popup_text = Paragraph::new(popup_lines)
+                    .wrap(Wrap { trim: true })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .style(Style::default().fg(Color::White))
+                            .title("Duplicate RSS feeds (y: merge all, n/Esc: cancel)")
+                            .border_type(BorderType::Plain),
+                    );
+
+                rect.render_widget(Clear, area);
+                rect.render_widget(popup_text, area);
+            }
+
+            if let Some(tag) = &popup_app.tag_view {
+                let area = show_popup(70, 50, size);
+                let tagged_articles = read_tagged_articles(tag);
+                let rss_feed_list = read_rss_db();
+
+                let tagged_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title(format!(
+                        "Articles tagged \"{}\" (Press Enter to go to article, Esc to go back)",
+                        tag
+                    ))
+                    .border_type(BorderType::Plain);
+
+                rect.render_widget(Clear, area);
+
+                if tagged_articles.is_empty() {
+                    tagged_list_state.select(None);
+
+                    let popup_text = Paragraph::new("No articles with this tag.")
+                        .style(Style::default().fg(Color::LightCyan))
+                        .alignment(Alignment::Center)
+                        .block(tagged_block);
+
+                    rect.render_widget(popup_text, area);
+                } else {
+                    match tagged_list_state.selected() {
+                        Some(selected) if selected < tagged_articles.len() => {}
+                        _ => tagged_list_state.select(Some(0)),
+                    }
+
+                    let items: Vec<_> = tagged_articles
+                        .iter()
+                        .map(|article| {
+                            let feed_label = rss_feed_list
+                                .iter()
+                                .find(|r| r.rss_id == article.rss_id)
+                                .map(|r| r.label().to_string())
+                                .unwrap_or_default();
+                            ListItem::new(Spans::from(vec![
+                                Span::styled(
+                                    article.title.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::raw(format!(
+                                    " ({}, {})",
+                                    feed_label,
+                                    format_timestamp(&article.pub_date)
+                                )),
+                            ]))
+                        })
+                        .collect();
+
+                    let tagged_list = List::new(items).block(tagged_block).highlight_style(
+                        get_config().theme.highlight_style(),
+                    );
+
+                    rect.render_stateful_widget(tagged_list, area, &mut tagged_list_state);
+                }
+            }
+
+            if popup_app.show_snoozed_popup {
+                let area = show_popup(70, 50, size);
+                let snoozed_articles = read_snoozed_articles();
+                let rss_feed_list = read_rss_db();
+
+                let snoozed_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title("Snoozed articles (Press Enter to bring back now, Esc to go back)")
+                    .border_type(BorderType::Plain);
+
+                rect.render_widget(Clear, area);
+
+                if snoozed_articles.is_empty() {
+                    snoozed_list_state.select(None);
+
+                    let popup_text = Paragraph::new("No snoozed articles, press Z on an article to snooze it.")
+                        .style(Style::default().fg(Color::LightCyan))
+                        .alignment(Alignment::Center)
+                        .block(snoozed_block);
+
+                    rect.render_widget(popup_text, area);
+                } else {
+                    match snoozed_list_state.selected() {
+                        Some(selected) if selected < snoozed_articles.len() => {}
+                        _ => snoozed_list_state.select(Some(0)),
+                    }
+
+                    let items: Vec<_> = snoozed_articles
+                        .iter()
+                        .map(|article| {
+                            let feed_label = rss_feed_list
+                                .iter()
+                                .find(|r| r.rss_id == article.rss_id)
+                                .map(|r| r.label().to_string())
+                                .unwrap_or_default();
+                            ListItem::new(Spans::from(vec![
+                                Span::styled(
+                                    article.title.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::raw(format!(
+                                    " ({}, back on {})",
+                                    feed_label,
+                                    article
+                                        .snoozed_until
+                                        .as_ref()
+                                        .map(format_timestamp)
+                                        .unwrap_or_default()
+                                )),
+                            ]))
+                        })
+                        .collect();
+
+                    let snoozed_list = List::new(items).block(snoozed_block).highlight_style(
+                        get_config().theme.highlight_style(),
+                    );
+
+                    rect.render_stateful_widget(snoozed_list, area, &mut snoozed_list_state);
+                }
+            }
+
+            if let Some(query) = &popup_app.archive_query {
+                let area = show_popup(70, 50, size);
+                let archived_articles = search_archive(query);
+                let rss_feed_list = read_rss_db();
+
+                let archive_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title(format!(
+                        "Archived articles matching \"{}\" (Press Enter to open in browser, Esc to go back)",
+                        query
+                    ))
+                    .border_type(BorderType::Plain);
+
+                rect.render_widget(Clear, area);
+
+                if archived_articles.is_empty() {
+                    archive_list_state.select(None);
+
+                    let popup_text = Paragraph::new("No archived articles found.")
+                        .style(Style::default().fg(Color::LightCyan))
+                        .alignment(Alignment::Center)
+                        .block(archive_block);
+
+                    rect.render_widget(popup_text, area);
+                } else {
+                    match archive_list_state.selected() {
+                        Some(selected) if selected < archived_articles.len() => {}
+                        _ => archive_list_state.select(Some(0)),
+                    }
+
+                    let items: Vec<_> = archived_articles
+                        .iter()
+                        .map(|article| {
+                            let feed_label = rss_feed_list
+                                .iter()
+                                .find(|r| r.rss_id == article.rss_id)
+                                .map(|r| r.label().to_string())
+                                .unwrap_or_default();
+                            ListItem::new(Spans::from(vec![
+                                Span::styled(
+                                    article.title.clone(),
+                                    Style::default().add_modifier(Modifier::BOLD),
+                                ),
+                                Span::raw(format!(
+                                    " ({}, {})",
+                                    feed_label,
+                                    format_timestamp(&article.pub_date)
+                                )),
+                            ]))
+                        })
+                        .collect();
+
+                    let archive_list = List::new(items).block(archive_block).highlight_style(
+                        get_config().theme.highlight_style(),
+                    );
+
+                    rect.render_stateful_widget(archive_list, area, &mut archive_list_state);
+                }
+            }
+
+            if popup_app.show_errors_popup {
+                let area = show_popup(70, 50, size);
+                let feed_errors = read_feed_errors();
+
+                let errors_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title("RSS Feed Errors (Press Enter to go to feed, Esc to go back)")
+                    .border_type(BorderType::Plain);
+
+                rect.render_widget(Clear, area);
+
+                if feed_errors.is_empty() {
+                    errors_list_state.select(None);
+
+                    let popup_text = Paragraph::new("No RSS feeds are in an error state.")
+                        .style(Style::default().fg(Color::LightCyan))
+                        .alignment(Alignment::Center)
+                        .block(errors_block);
+
+                    rect.render_widget(popup_text, area);
+                } else {
+                    match errors_list_state.selected() {
+                        Some(selected) if selected < feed_errors.len() => {}
+                        _ => errors_list_state.select(Some(0)),
+                    }
+
+                    let items: Vec<_> = feed_errors
+                        .iter()
+                        .map(|feed_error| {
+                            ListItem::new(vec![
+                                Spans::from(vec![
+                                    Span::styled(
+                                        feed_error.feed_name.clone(),
+                                        Style::default().add_modifier(Modifier::BOLD),
+                                    ),
+                                    Span::raw(format!(
+                                        " ({})",
+                                        format_timestamp(&feed_error.occurred_at)
+                                    )),
+                                ]),
+                                Spans::from(vec![Span::styled(
+                                    format!(
+                                        "{:?} - {}",
+                                        feed_error.error_code, feed_error.error_message
+                                    ),
+                                    Style::default().fg(Color::LightRed),
+                                )]),
+                            ])
+                        })
+                        .collect();
+
+                    let errors_list = List::new(items).block(errors_block).highlight_style(
+                        get_config().theme.highlight_style(),
+                    );
+
+                    rect.render_stateful_widget(errors_list, area, &mut errors_list_state);
+                }
+            }
+
+            if popup_app.show_log_popup {
+                let area = show_popup(80, 70, size);
+                let log_lines = read_log_tail(LOG_VIEWER_LINES);
+
+                let log_block = Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title("Application Logs (Press Esc to go back)")
+                    .border_type(BorderType::Plain);
+
+                rect.render_widget(Clear, area);
+
+                if log_lines.is_empty() {
+                    log_list_state.select(None);
+
+                    let popup_text = Paragraph::new(format!(
+                        "No logs found in \"{}\".",
+                        get_config().log_file
+                    ))
+                    .style(Style::default().fg(Color::LightCyan))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true })
+                    .block(log_block);
+
+                    rect.render_widget(popup_text, area);
+                } else {
+                    match log_list_state.selected() {
+                        Some(selected) if selected < log_lines.len() => {}
+                        _ => log_list_state.select(Some(log_lines.len() - 1)),
+                    }
+
+                    let items: Vec<_> = log_lines
+                        .into_iter()
+                        .map(|line| {
+                            let color = match log_line_level(&line) {
+                                Some(Level::Error) => Color::LightRed,
+                                Some(Level::Warn) => Color::Yellow,
+                                Some(Level::Info) => Color::White,
+                                Some(Level::Debug) | Some(Level::Trace) => Color::Gray,
+                                None => Color::White,
+                            };
+                            ListItem::new(Spans::from(vec![Span::styled(
+                                line,
+                                Style::default().fg(color),
+                            )]))
+                        })
+                        .collect();
+
+                    let log_list = List::new(items)
+                        .block(log_block)
+                        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+                    rect.render_stateful_widget(log_list, area, &mut log_list_state);
+                }
+            }
         }).unwrap_or_else(|_err| {
             let err_msg = ErrorMessages::new(ErrorCodes::E0004_APP_RENDERING_FAILURE);
             panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
         });
+        let last_draw = Instant::now();
+        let refreshing_feeds = read_refreshing_feeds();
 
-        if let CEvent::Key(key) = event::read().unwrap_or_else(|_err| {
-            let err_msg = ErrorMessages::new(ErrorCodes::E0005_KEYBOARD_READ_FAILURE);
-            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-        }) {
+        // Waits for input without redrawing until the screen is dirty (state changed by an
+        // event, a status message or a refresh starting or completing), or else until the slow
+        // tick, for timed elements such as relative times and status expiry
+        let mut dirty = false;
+        let event = loop {
+            let event_available = event::poll(TICK_RATE).unwrap_or_else(|_err| {
+                let err_msg = ErrorMessages::new(ErrorCodes::E0005_KEYBOARD_READ_FAILURE);
+                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+            });
+
+            if event_available {
+                let event = event::read().unwrap_or_else(|_err| {
+                    let err_msg = ErrorMessages::new(ErrorCodes::E0005_KEYBOARD_READ_FAILURE);
+                    panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                });
+                match event {
+                    // Mouse events (e.g. motion) change nothing
+                    CEvent::Mouse(_) => continue,
+                    // Lays the screen out again right away at the new size, instead of on the
+                    // next key press; scroll offsets depending on the visible height are clamped
+                    // while drawing
+                    CEvent::Resize(_width, _height) => {
+                        terminal.autoresize().unwrap_or_else(|_err| {
+                            let err_msg =
+                                ErrorMessages::new(ErrorCodes::E0004_APP_RENDERING_FAILURE);
+                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                        });
+                        dirty = true;
+                    }
+                    event => break Some(event),
+                }
+            }
+
+            while let Ok(message) = status_receiver.try_recv() {
+                status_bar_app.set(message);
+                dirty = true;
+            }
+            if read_refreshing_feeds() != refreshing_feeds {
+                dirty = true;
+            }
+            if dirty || last_draw.elapsed() >= IDLE_REDRAW_INTERVAL {
+                break None;
+            }
+        };
+
+        let Some(event) = event else {
+            continue;
+        };
+
+        if let CEvent::Paste(pasted_text) = &event {
             match inputbox_app.input_mode {
-                InputMode::Normal => match key.code {
-                    KeyCode::Char('a') => {
+                InputMode::Editing => inputbox_app.focused_input().insert_str(pasted_text),
+                InputMode::Prompting => prompt_app.input.insert_str(pasted_text),
+                _ => {}
+            }
+        }
+
+        if let CEvent::Key(key) = event {
+            if let InputMode::Normal = inputbox_app.input_mode {
+                match keymap.context_for(&key) {
+                    Some(KeyContext::Feeds) => render_options.feeds_focused = true,
+                    Some(KeyContext::Articles) => render_options.feeds_focused = false,
+                    _ => {}
+                }
+            }
+
+            match inputbox_app.input_mode {
+                InputMode::Normal => match keymap.action_for(&key) {
+                    Some(Action::AddFeed) => {
                         inputbox_app.input_mode = InputMode::Editing;
                     }
-                    KeyCode::Char('d') => {
-                        let selected = rss_list_state.selected().unwrap_or_else(|| {
-                            let err_msg =
-                                ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
-                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                        });
-                        if selected > 0 {
-                            update_rss_db(&mut rss_list_state);
+                    Some(Action::AddFeedFromClipboard) => match read_from_clipboard() {
+                        Ok(text) if is_valid_feed_url(text.trim()) => {
+                            let url = text.trim().to_string();
+                            let url_input = &mut inputbox_app.fields[URL_FIELD];
+                            url_input.take_text();
+                            url_input.insert_str(&url);
+                            inputbox_app.focused_field = 0;
+                            inputbox_app.input_mode = InputMode::Editing;
+
+                            // Page URLs are replaced by the RSS feeds they advertise
+                            if let Ok(feed_urls) = discover_feeds(&url).await {
+                                if !feed_urls.contains(&url) {
+                                    popup_app.discovered_feeds = feed_urls;
+                                    discovered_feeds_list_state.select(Some(0));
+                                    popup_app.show_discovered_feeds_popup = true;
+                                    inputbox_app.input_mode = InputMode::Popup;
+                                }
+                            }
+                        }
+                        Ok(_) => {
+                            status_bar_app.set(String::from("Clipboard does not contain a URL"))
+                        }
+                        Err(err_msg) => status_bar_app.set_error(&err_msg),
+                    },
+                    Some(Action::DeleteFeed) => {
+                        let selected = rss_list_state.selected().unwrap_or_else(|| {
+                            let err_msg =
+                                ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
+                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                        });
+                        if selected > 0 {
+                            update_rss_db(&mut rss_list_state);
+                        }
+                    }
+                    Some(Action::RefreshFeed) => {
+                        let selected = rss_list_state.selected().unwrap_or_else(|| {
+                            let err_msg =
+                                ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
+                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
+                        });
+
+                        if selected > 0
+                            && is_feed_refreshing(read_selected_rss_feed(&rss_list_state).rss_id)
+                        {
+                            status_bar_app.set_error(&ErrorMessages::new(
+                                ErrorCodes::E0029_FEED_REFRESH_IN_PROGRESS,
+                            ));
+                        } else if selected > 0 && !read_selected_rss_feed(&rss_list_state).enabled {
+                            status_bar_app.set(String::from(
+                                "RSS feed is paused, press t to resume it before refreshing",
+                            ));
+                        } else if selected > 0 {
+                            let rss_id = read_selected_rss_feed(&rss_list_state).rss_id;
+                            run_in_background(async move {
+                                let _ = refresh_rss_feed(rss_id).await;
+                            });
+                            popup_app.show_refresh_popup = true;
+                            inputbox_app.input_mode = InputMode::Popup;
+                        }
+                    }
+                    Some(Action::RefreshAllFeeds) => {
+                        let now = Utc::now();
+                        let rss_ids: Vec<usize> = read_rss_db()
+                            .iter()
+                            .skip(1)
+                            .filter(|r| r.enabled)
+                            .filter(|r| {
+                                let is_due = r.is_refresh_due(now);
+                                if !is_due {
+                                    log::info!(
+                                        "Skipped RSS feed \"{}\", not due as per its update hints",
+                                        r.name
+                                    );
+                                }
+                                is_due
+                            })
+                            .map(|r| r.rss_id)
+                            .collect();
+
+                        run_in_background(async move {
+                            refresh_rss_feeds(&rss_ids).await;
+                        });
+                        popup_app.show_refresh_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::RefreshErroredFeeds) => {
+                        let rss_ids: Vec<usize> = read_feed_errors()
+                            .iter()
+                            .map(|feed_error| feed_error.rss_id)
+                            .collect();
+
+                        if rss_ids.is_empty() {
+                            status_bar_app.set(String::from("No RSS feeds are in an error state"));
+                        } else {
+                            let num_rss_feeds = rss_ids.len();
+                            let status_sender = status_sender.clone();
+                            run_in_background(async move {
+                                let results = refresh_rss_feeds(&rss_ids).await;
+                                let num_recovered =
+                                    results.iter().filter(|(_, r)| r.is_ok()).count();
+                                let _ = status_sender.send(format!(
+                                    "Recovered {} of {} RSS feeds with errors, {} still failing",
+                                    num_recovered,
+                                    results.len(),
+                                    results.len() - num_recovered
+                                ));
+                            });
+                            status_bar_app.set(format!(
+                                "Refreshing {} RSS feeds with errors in background",
+                                num_rss_feeds
+                            ));
+                        }
+                    }
+                    Some(Action::MarkReadBefore) => {
+                        prompt_app = PromptApp::new(PromptKind::MarkReadBefore);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::CopyOpml) => {
+                        let opml = export_opml();
+                        match copy_to_clipboard(&opml) {
+                            Ok(()) => status_bar_app.set(format!(
+                                "RSS feeds copied to clipboard as OPML ({} bytes)",
+                                opml.len()
+                            )),
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    Some(Action::ImportOpml) => {
+                        prompt_app = PromptApp::new(PromptKind::ImportOpml);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::RenameCategory) if rss_list_state.selected() > Some(0) => {
+                        prompt_app = PromptApp::new(PromptKind::RenameCategory);
+                        prompt_app
+                            .input
+                            .insert_str(&read_selected_rss_feed(&rss_list_state).category);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::DuplicateFeed) if rss_list_state.selected() > Some(0) => {
+                        prompt_app = PromptApp::new(PromptKind::DuplicateFeed);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::MuteKeywords) if rss_list_state.selected() > Some(0) => {
+                        prompt_app = PromptApp::new(PromptKind::MuteKeywords);
+                        prompt_app.input.insert_str(
+                            &read_selected_rss_feed(&rss_list_state)
+                                .muted_keywords
+                                .join(", "),
+                        );
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::RenameFeed) if rss_list_state.selected() > Some(0) => {
+                        prompt_app = PromptApp::new(PromptKind::RenameFeed);
+                        if let Some(display_name) =
+                            read_selected_rss_feed(&rss_list_state).display_name
+                        {
+                            prompt_app.input.insert_str(&display_name);
+                        }
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::ToggleBookmark) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match toggle_article_bookmark(article.article_id) {
+                                true => status_bar_app.set(String::from("Article bookmarked")),
+                                false => status_bar_app.set(String::from("Bookmark removed")),
+                            }
+                        }
+                    }
+                    Some(Action::ToggleRead) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match toggle_article_read(article.article_id) {
+                                true => status_bar_app.set(String::from("Article marked as read")),
+                                false => {
+                                    status_bar_app.set(String::from("Article marked as unread"))
+                                }
+                            }
+                        }
+                    }
+                    Some(Action::HideArticle) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let rss_articles_list: Vec<Articles> =
+                            read_rss_articles(selected_rss_feed.rss_id);
+
+                        if let Some(selected) = articles_list_state.selected() {
+                            if let Some(article) = rss_articles_list.get(selected) {
+                                hide_article(article.article_id);
+                                if selected > 0 && selected >= rss_articles_list.len() - 1 {
+                                    articles_list_state.select(Some(selected - 1));
+                                }
+                            }
+                        }
+                    }
+                    Some(Action::ArchiveArticle) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let rss_articles_list: Vec<Articles> =
+                            read_rss_articles(selected_rss_feed.rss_id);
+
+                        if let Some(selected) = articles_list_state.selected() {
+                            if let Some(article) = rss_articles_list.get(selected) {
+                                if archive_article(article.article_id) {
+                                    status_bar_app.set(String::from("Article archived"));
+                                }
+                                if selected > 0 && selected >= rss_articles_list.len() - 1 {
+                                    articles_list_state.select(Some(selected - 1));
+                                }
+                            }
+                        }
+                    }
+                    Some(Action::ArchiveReadArticles) if rss_list_state.selected() > Some(0) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let num_archived = archive_read_articles(selected_rss_feed.rss_id);
+                        articles_list_state.select(Some(0));
+                        status_bar_app.set(format!(
+                            "Archived {} read articles of \"{}\"",
+                            num_archived,
+                            selected_rss_feed.label()
+                        ));
+                    }
+                    Some(Action::SearchArchive) => {
+                        prompt_app = PromptApp::new(PromptKind::SearchArchive);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::ResetDb) => {
+                        prompt_app = PromptApp::new(PromptKind::ResetDb);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::Help) => {
+                        popup_app.help_scroll = 0;
+                        popup_app.show_help_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::OpenArticle) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match open_articles_in_browser(&[article]) {
+                                (_, Some(err_msg)) => status_bar_app.set_error(&err_msg),
+                                (_, None) => {
+                                    status_bar_app.set(String::from("Article opened in browser"))
+                                }
+                            }
+                        }
+                    }
+                    Some(Action::FocusNextLink) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            let show_full_content =
+                                render_options.show_full_content && article.full_content.is_some();
+                            let links = article_links(&article, show_full_content);
+
+                            render_options.focused_link = match render_options.focused_link {
+                                Some(link) if link + 1 < links.len() => Some(link + 1),
+                                _ if links.is_empty() => None,
+                                _ => Some(0),
+                            };
+                            match render_options.focused_link {
+                                Some(link) => status_bar_app.set(format!(
+                                    "Link {}/{}: {}",
+                                    link + 1,
+                                    links.len(),
+                                    links[link].url
+                                )),
+                                None => status_bar_app.set(String::from("No links in summary")),
+                            }
+                        }
+                    }
+                    Some(Action::OpenFocusedLink) => {
+                        let focused_link = render_options.focused_link.and_then(|link| {
+                            let article =
+                                read_selected_article(&rss_list_state, &articles_list_state)?;
+                            let show_full_content =
+                                render_options.show_full_content && article.full_content.is_some();
+                            article_links(&article, show_full_content)
+                                .into_iter()
+                                .nth(link)
+                        });
+
+                        if let Some(focused_link) = focused_link {
+                            match open_in_browser(&focused_link.url) {
+                                Ok(()) => status_bar_app
+                                    .set(format!("Link opened in browser: {}", focused_link.url)),
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                        }
+                    }
+                    Some(Action::OpenWebsite) if rss_list_state.selected() > Some(0) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        match open_in_browser(selected_rss_feed.website_url()) {
+                            Ok(()) => status_bar_app.set(String::from("Website opened in browser")),
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    Some(Action::OpenUnreadArticles) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let unread_articles = read_unread_articles(selected_rss_feed.rss_id);
+
+                        if unread_articles.len() > get_config().max_open_tabs {
+                            popup_app.show_open_unread_popup = true;
+                            inputbox_app.input_mode = InputMode::Popup;
+                        } else {
+                            status_bar_app.set_open_articles_result(open_articles_in_browser(
+                                &unread_articles,
+                            ));
+                        }
+                    }
+                    Some(Action::ExportArticle) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match export_article(article.article_id, &get_config().export_dir) {
+                                Ok(file_path) => status_bar_app
+                                    .set(format!("Article exported to {}", file_path.display())),
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                        }
+                    }
+                    Some(Action::CopyArticle) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match copy_to_clipboard(&article_to_markdown_snippet(&article)) {
+                                Ok(()) => status_bar_app
+                                    .set(String::from("Article copied to clipboard as Markdown")),
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                        }
+                    }
+                    Some(Action::CopySummaryText) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            let summary_text = clean_html(&article.summary).trim().to_string();
+                            if summary_text.is_empty() {
+                                status_bar_app
+                                    .set(String::from("Selected article has no summary to copy"));
+                            } else {
+                                match copy_to_clipboard(&summary_text) {
+                                    Ok(()) => status_bar_app.set(format!(
+                                        "Summary copied to clipboard as plain text ({} characters)",
+                                        summary_text.chars().count()
+                                    )),
+                                    Err(err_msg) => status_bar_app.set_error(&err_msg),
+                                }
+                            }
+                        }
+                    }
+                    Some(Action::ShareArticle) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            match open_in_mail_client(&build_mailto(&article)) {
+                                Ok(()) => status_bar_app
+                                    .set(String::from("Article opened in mail client")),
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                        }
+                    }
+                    Some(Action::ToggleFullContent) => {
+                        render_options.show_full_content = !render_options.show_full_content;
+                        render_options.summary_scroll = 0;
+                        render_options.focused_link = None;
+                    }
+                    Some(Action::ScrollSummaryDown) => {
+                        render_options.summary_scroll =
+                            render_options.summary_scroll.saturating_add(1);
+                    }
+                    Some(Action::ScrollSummaryUp) => {
+                        render_options.summary_scroll =
+                            render_options.summary_scroll.saturating_sub(1);
+                    }
+                    Some(Action::CycleSummaryEmphasis) => {
+                        ui_state.summary_emphasis = ui_state.summary_emphasis.next();
+                        render_options.summary_emphasis = ui_state.summary_emphasis;
+                        write_ui_state(&ui_state);
+                        status_bar_app.set(format!(
+                            "Summary text style: {}",
+                            ui_state.summary_emphasis.label()
+                        ));
+                    }
+                    Some(Action::ToggleSummaryPane) => {
+                        ui_state.summary_collapsed = !ui_state.summary_collapsed;
+                        write_ui_state(&ui_state);
+                        status_bar_app.set(String::from(match ui_state.summary_collapsed {
+                            true => "Summary pane collapsed, press w to bring it back",
+                            false => "Summary pane expanded",
+                        }));
+                    }
+                    Some(Action::ToggleUnreadOnly) => {
+                        set_unread_only(!is_unread_only());
+                        articles_list_state.select(Some(0));
+                        status_bar_app.set(String::from(match is_unread_only() {
+                            true => "Showing unread articles only",
+                            false => "Showing all articles",
+                        }));
+                    }
+                    Some(Action::ToggleArticlePreview) => {
+                        render_options.show_article_preview = !render_options.show_article_preview;
+                    }
+                    Some(Action::EditTags) => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            prompt_app = PromptApp::new(PromptKind::EditTags);
+                            prompt_app.input.insert_str(&article.tags.join(", "));
+                            inputbox_app.input_mode = InputMode::Prompting;
                         }
                     }
-                    KeyCode::Char('r') => {
-                        let selected = rss_list_state.selected().unwrap_or_else(|| {
-                            let err_msg =
-                                ErrorMessages::new(ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE);
-                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                        });
-
-                        if selected > 0 {
-                            thread::spawn(move || {
-                                let rt = tokio::runtime::Builder::new_multi_thread()
-                                    .enable_all()
-                                    .build()
-                                    .unwrap_or_else(|_err| {
-                                        let err_msg = ErrorMessages::new(
-                                            ErrorCodes::E0018_TOKIO_RUNTIME_BUILDER_FAILURE,
-                                        );
-                                        panic!(
-                                            "{:?} - {}",
-                                            err_msg.error_code, err_msg.error_message
-                                        );
-                                    });
-                                rt.block_on(async {
-                                    let _ = write_articles_db(selected).await;
-                                });
-                            });
-                            popup_app.show_refresh_popup = true;
+                    Some(Action::SnoozeArticle)
+                        if read_selected_article(&rss_list_state, &articles_list_state)
+                            .is_some() =>
+                    {
+                        prompt_app = PromptApp::new(PromptKind::SnoozeArticle);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::ShowSnoozedArticles) => {
+                        snoozed_list_state.select(Some(0));
+                        popup_app.show_snoozed_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::ShowTaggedArticles) => {
+                        prompt_app = PromptApp::new(PromptKind::ShowTaggedArticles);
+                        inputbox_app.input_mode = InputMode::Prompting;
+                    }
+                    Some(Action::ShowErrors) => {
+                        popup_app.show_errors_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::SelectText)
+                        if read_selected_article(&rss_list_state, &articles_list_state)
+                            .is_some() =>
+                    {
+                        render_options.text_selection = Some((0, 0));
+                        inputbox_app.input_mode = InputMode::Selecting;
+                    }
+                    Some(Action::ShowFeedDetails) => {
+                        popup_app.show_feed_details_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::ShowStats) => {
+                        popup_app.show_stats_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::FindDuplicateFeeds) => {
+                        let duplicate_feeds = find_duplicate_feeds();
+                        if duplicate_feeds.is_empty() {
+                            status_bar_app.set(String::from("No duplicate RSS feeds found"));
+                        } else {
+                            popup_app.duplicate_feeds = Some(duplicate_feeds);
                             inputbox_app.input_mode = InputMode::Popup;
                         }
                     }
-                    KeyCode::Char('h') => {
-                        popup_app.show_help_popup = true;
+                    Some(Action::ShowLogs) => {
+                        log_list_state.select(None);
+                        popup_app.show_log_popup = true;
                         inputbox_app.input_mode = InputMode::Popup;
                     }
-                    KeyCode::PageDown => {
+                    Some(Action::NextFeed) => {
                         if let Some(selected) = rss_list_state.selected() {
                             let num_rss_feeds = read_rss_db().len();
                             if selected >= num_rss_feeds - 1 {
@@ -429,7 +2524,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         }
                         articles_list_state.select(Some(0));
                     }
-                    KeyCode::PageUp => {
+                    Some(Action::PreviousFeed) => {
                         if let Some(selected) = rss_list_state.selected() {
                             let num_rss_feeds = read_rss_db().len();
                             if selected > 0 {
@@ -440,122 +2535,851 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         }
                         articles_list_state.select(Some(0));
                     }
-                    KeyCode::Down => {
-                        let rss_feed_list = read_rss_db();
-
-                        let selected_rss_feed = rss_feed_list
-                            .get(rss_list_state.selected().unwrap_or_else(|| {
-                                let err_msg = ErrorMessages::new(
-                                    ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE,
-                                );
-                                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                            }))
-                            .unwrap_or_else(|| {
-                                let err_msg =
-                                    ErrorMessages::new(ErrorCodes::E0014_RSS_LIST_READ_FAILURE);
-                                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                            })
-                            .clone();
+                    Some(action @ (Action::NextUnreadFeed | Action::PreviousUnreadFeed)) => {
+                        match find_unread_feed(
+                            &rss_list_state,
+                            action == Action::NextUnreadFeed,
+                            get_config().wrap_feed_navigation,
+                        ) {
+                            Some((rss_selected, article_selected)) => {
+                                rss_list_state.select(Some(rss_selected));
+                                articles_list_state.select(Some(article_selected));
+                            }
+                            None => status_bar_app
+                                .set(String::from("No other RSS feed with unread articles")),
+                        }
+                    }
+                    Some(Action::ToggleFeedEnabled) => {
+                        match toggle_rss_feed_enabled(&rss_list_state) {
+                            Some(true) => status_bar_app.set(String::from("RSS feed resumed")),
+                            Some(false) => status_bar_app.set(String::from(
+                                "RSS feed paused, it is skipped by refresh all",
+                            )),
+                            None => {}
+                        }
+                    }
+                    Some(Action::ReloadDb) => match reload_db() {
+                        Ok((num_rss_feeds, num_articles)) => {
+                            let (rss_id, article_id) = selected_ids;
+                            let rss_selected = read_rss_feed_list()
+                                .iter()
+                                .position(|r| r.rss_id == rss_id)
+                                .unwrap_or(0);
+                            let article_selected = read_rss_articles(rss_id)
+                                .iter()
+                                .position(|r| Some(r.article_id) == article_id)
+                                .unwrap_or(0);
 
-                        let rss_articles_list: Vec<Articles> = read_articles_db()
-                            .into_iter()
-                            .filter(|r| r.rss_id == selected_rss_feed.rss_id)
-                            .collect();
+                            rss_list_state.select(Some(rss_selected));
+                            articles_list_state.select(Some(article_selected));
+                            status_bar_app.set(format!(
+                                "Reloaded {} RSS feeds and {} articles from disk",
+                                num_rss_feeds, num_articles
+                            ));
+                        }
+                        Err(err_msg) => status_bar_app.set_error(&err_msg),
+                    },
+                    Some(Action::FlushToDisk) => {
+                        // RSS feeds and articles are written as they change, so only waits for
+                        // any database write in flight (e.g. a background refresh) to complete
+                        drop(lock_db_writes());
+                        status_bar_app.set(String::from(match flush_ui_state(&ui_state) {
+                            true => "Saved display preferences to disk",
+                            false => "Nothing to save, all changes are already on disk",
+                        }));
+                    }
+                    Some(Action::MoveFeedUp | Action::MoveFeedDown)
+                        if feed_sort() != FeedSort::Insertion =>
+                    {
+                        status_bar_app.set(String::from(
+                            "RSS feeds can only be moved while sorted in insertion order (press s)",
+                        ));
+                    }
+                    Some(Action::CycleFeedSort) => {
+                        ui_state.feed_sort = ui_state.feed_sort.next();
+                        set_feed_sort(ui_state.feed_sort);
+                        write_ui_state(&ui_state);
+                        status_bar_app.set(format!(
+                            "RSS feeds sorted by {}",
+                            ui_state.feed_sort.label()
+                        ));
+                    }
+                    Some(Action::MoveFeedUp) => {
+                        move_rss_feed(&mut rss_list_state, true);
+                    }
+                    Some(Action::MoveFeedDown) => {
+                        move_rss_feed(&mut rss_list_state, false);
+                    }
+                    Some(action @ (Action::GoToTop | Action::GoToBottom))
+                        if render_options.feeds_focused =>
+                    {
+                        let num_rss_feeds = read_rss_feed_list().len();
+                        if num_rss_feeds > 0 {
+                            rss_list_state.select(Some(match action {
+                                Action::GoToTop => 0,
+                                _ => num_rss_feeds - 1,
+                            }));
+                            articles_list_state.select(Some(0));
+                        }
+                    }
+                    Some(action @ (Action::GoToTop | Action::GoToBottom)) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let num_articles = read_rss_articles(selected_rss_feed.rss_id).len();
+                        if num_articles > 0 {
+                            articles_list_state.select(Some(match action {
+                                Action::GoToTop => 0,
+                                _ => num_articles - 1,
+                            }));
+                        }
+                    }
+                    Some(Action::NextArticle) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let rss_articles_list: Vec<Articles> =
+                            read_rss_articles(selected_rss_feed.rss_id);
 
                         if let Some(selected) = articles_list_state.selected() {
                             let num_articles = rss_articles_list.len();
-                            if selected >= num_articles - 1 {
+                            if selected + 1 >= num_articles {
                                 articles_list_state.select(Some(0));
                             } else {
                                 articles_list_state.select(Some(selected + 1));
                             }
                         }
                     }
-                    KeyCode::Up => {
-                        let rss_feed_list = read_rss_db();
-
-                        let selected_rss_feed = rss_feed_list
-                            .get(rss_list_state.selected().unwrap_or_else(|| {
-                                let err_msg = ErrorMessages::new(
-                                    ErrorCodes::E0008_LIST_STATE_SELECTION_FAILURE,
-                                );
-                                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                            }))
-                            .unwrap_or_else(|| {
-                                let err_msg =
-                                    ErrorMessages::new(ErrorCodes::E0014_RSS_LIST_READ_FAILURE);
-                                panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                            })
-                            .clone();
-
-                        let rss_articles_list: Vec<Articles> = read_articles_db()
-                            .into_iter()
-                            .filter(|r| r.rss_id == selected_rss_feed.rss_id)
-                            .collect();
+                    Some(Action::PreviousArticle) => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let rss_articles_list: Vec<Articles> =
+                            read_rss_articles(selected_rss_feed.rss_id);
 
                         if let Some(selected) = articles_list_state.selected() {
                             let num_articles = rss_articles_list.len();
                             if selected > 0 {
                                 articles_list_state.select(Some(selected - 1));
                             } else {
-                                articles_list_state.select(Some(num_articles - 1));
+                                articles_list_state.select(Some(num_articles.saturating_sub(1)));
                             }
                         }
                     }
-                    KeyCode::Char('q') => {
-                        disable_raw_mode().unwrap_or_else(|_err| {
-                            let err_msg =
-                                ErrorMessages::new(ErrorCodes::E0015_DISABLE_RAW_MODE_FAILURE);
-                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                        });
-
-                        execute!(
-                            terminal.backend_mut(),
-                            LeaveAlternateScreen,
-                            DisableMouseCapture
-                        )?;
-
-                        terminal.clear().unwrap_or_else(|_err| {
-                            let err_msg =
-                                ErrorMessages::new(ErrorCodes::E0015_TERMINAL_CLEAR_FAILURE);
-                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                        });
-
-                        terminal.show_cursor().unwrap_or_else(|_err| {
-                            let err_msg =
-                                ErrorMessages::new(ErrorCodes::E0016_TERMINAL_SHOW_CURSOR_FAILURE);
-                            panic!("{:?} - {}", err_msg.error_code, err_msg.error_message);
-                        });
+                    Some(Action::Quit) if is_any_feed_refreshing() => {
+                        popup_app.show_quit_popup = true;
+                        inputbox_app.input_mode = InputMode::Popup;
+                    }
+                    Some(Action::Quit) => {
+                        ui_state.last_session_at = Some(Utc::now());
+                        write_ui_state(&ui_state);
+                        restore_terminal(&mut terminal)?;
                         return Ok(());
                     }
+                    Some(Action::Back) => render_options.focused_link = None,
                     _ => {}
                 },
                 InputMode::Editing => match key.code {
+                    _ if keymap.action_for(&key) == Some(Action::Back) => {
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
                     KeyCode::Enter => {
-                        let input_text: String =
-                            inputbox_app.text_input.drain(..).collect::<String>();
-                        write_rss_db(input_text).await;
+                        if inputbox_app.is_valid() {
+                            let url = inputbox_app.fields[URL_FIELD].text_input.trim().to_string();
+                            match discover_feeds(&url).await {
+                                Ok(feed_urls) if !feed_urls.contains(&url) => {
+                                    popup_app.discovered_feeds = feed_urls;
+                                    discovered_feeds_list_state.select(Some(0));
+                                    popup_app.show_discovered_feeds_popup = true;
+                                    inputbox_app.input_mode = InputMode::Popup;
+                                }
+                                _ => inputbox_app.submit(&mut status_bar_app).await,
+                            }
+                        } else {
+                            status_bar_app.set_error(&ErrorMessages::new(
+                                ErrorCodes::E0031_INVALID_FEED_INPUT,
+                            ));
+                        }
+                    }
+                    KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        let url = inputbox_app.fields[URL_FIELD].text_input.trim().to_string();
+                        match preview_feed(&url).await {
+                            Ok(preview_articles) => {
+                                popup_app.preview_articles = Some(preview_articles);
+                                popup_app.preview_scroll = 0;
+                                inputbox_app.input_mode = InputMode::Popup;
+                            }
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    KeyCode::Tab => {
+                        inputbox_app.focus_next_field();
+                    }
+                    KeyCode::BackTab => {
+                        inputbox_app.focus_previous_field();
                     }
                     KeyCode::Char(c) => {
-                        inputbox_app.text_input.push(c);
+                        inputbox_app.focused_input().insert_char(c);
                     }
                     KeyCode::Backspace => {
-                        inputbox_app.text_input.pop();
+                        inputbox_app.focused_input().delete_char_before_cursor();
+                    }
+                    KeyCode::Delete => {
+                        inputbox_app.focused_input().delete_char_at_cursor();
+                    }
+                    KeyCode::Left => {
+                        inputbox_app.focused_input().move_cursor_left();
+                    }
+                    KeyCode::Right => {
+                        inputbox_app.focused_input().move_cursor_right();
+                    }
+                    KeyCode::Home => {
+                        inputbox_app.focused_input().move_cursor_home();
+                    }
+                    KeyCode::End => {
+                        inputbox_app.focused_input().move_cursor_end();
+                    }
+                    _ => {}
+                },
+                InputMode::Prompting => match key.code {
+                    _ if keymap.action_for(&key) == Some(Action::Back) => {
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::ImportOpml => {
+                        match store_opml_feeds(prompt_app.input.text_input.trim()) {
+                            Ok(import_report) => {
+                                let rss_ids = import_report.new_rss_ids.clone();
+                                run_in_background(async move {
+                                    refresh_rss_feeds(&rss_ids).await;
+                                });
+
+                                popup_app.import_report = Some(import_report);
+                                popup_app.import_scroll = 0;
+                                inputbox_app.input_mode = InputMode::Popup;
+                            }
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::RenameCategory => {
+                        let old_category = read_selected_rss_feed(&rss_list_state).category;
+                        let new_category = prompt_app.input.text_input.trim();
+                        if !new_category.is_empty() {
+                            let num_renamed = rename_category(&old_category, new_category);
+                            status_bar_app.set(format!(
+                                "Renamed category \"{}\" to \"{}\" on {} RSS feeds",
+                                old_category, new_category, num_renamed
+                            ));
+                            inputbox_app.input_mode = InputMode::Normal;
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::DuplicateFeed => {
+                        let rss_feed = read_selected_rss_feed(&rss_list_state);
+                        let category = prompt_app.input.text_input.trim().to_string();
+                        if !category.is_empty() {
+                            match duplicate_rss_feed(rss_feed.rss_id, &category) {
+                                Ok(rss_id) => {
+                                    run_in_background(async move {
+                                        let _ = refresh_rss_feed(rss_id).await;
+                                    });
+                                    status_bar_app.set(format!(
+                                        "Copied \"{}\" under \"{}\", fetching its articles",
+                                        rss_feed.label(),
+                                        category
+                                    ));
+                                }
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                            inputbox_app.input_mode = InputMode::Normal;
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::MuteKeywords => {
+                        if set_rss_feed_muted_keywords(
+                            &rss_list_state,
+                            &prompt_app.input.text_input,
+                        ) {
+                            articles_list_state.select(Some(0));
+                            status_bar_app.set(format!(
+                                "Muted keywords: {}",
+                                read_selected_rss_feed(&rss_list_state).muted_keywords.len()
+                            ));
+                        }
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::EditTags => {
+                        if let Some(article) =
+                            read_selected_article(&rss_list_state, &articles_list_state)
+                        {
+                            let tags = parse_keywords(&prompt_app.input.text_input);
+                            for tag in article.tags.iter().filter(|t| !tags.contains(t)) {
+                                untag_article(article.article_id, tag);
+                            }
+                            for tag in tags.iter() {
+                                tag_article(article.article_id, tag);
+                            }
+                            status_bar_app.set(match tags.is_empty() {
+                                true => String::from("Tags removed"),
+                                false => format!("Tags set to {}", tags.join(", ")),
+                            });
+                        }
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::ShowTaggedArticles => {
+                        let tag = prompt_app.input.text_input.trim();
+                        if !tag.is_empty() {
+                            popup_app.tag_view = Some(tag.to_string());
+                            tagged_list_state.select(Some(0));
+                            inputbox_app.input_mode = InputMode::Popup;
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::SearchArchive => {
+                        let query = prompt_app.input.text_input.trim();
+                        if !query.is_empty() {
+                            popup_app.archive_query = Some(query.to_string());
+                            archive_list_state.select(Some(0));
+                            inputbox_app.input_mode = InputMode::Popup;
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::SnoozeArticle => {
+                        match parse_duration(&prompt_app.input.text_input) {
+                            Some(duration) => {
+                                let snoozed_until = Utc::now() + duration;
+                                if let Some(article) =
+                                    read_selected_article(&rss_list_state, &articles_list_state)
+                                {
+                                    if snooze_article(article.article_id, Some(snoozed_until)) {
+                                        let num_articles = read_rss_articles(article.rss_id).len();
+                                        if let Some(selected) = articles_list_state.selected() {
+                                            if selected >= num_articles {
+                                                articles_list_state
+                                                    .select(Some(num_articles.saturating_sub(1)));
+                                            }
+                                        }
+                                        status_bar_app.set(format!(
+                                            "Article snoozed until {}",
+                                            format_timestamp(&snoozed_until)
+                                        ));
+                                    }
+                                }
+                                inputbox_app.input_mode = InputMode::Normal;
+                            }
+                            None => status_bar_app.set_error(&ErrorMessages::new(
+                                ErrorCodes::E0036_DURATION_PARSE_FAILURE,
+                            )),
+                        }
+                    }
+                    KeyCode::Enter if prompt_app.kind == PromptKind::ResetDb => {
+                        if prompt_app.input.text_input.trim() != RESET_CONFIRMATION {
+                            status_bar_app.set(format!(
+                                "Type \"{}\" to confirm, or press Esc to cancel",
+                                RESET_CONFIRMATION
+                            ));
+                        } else {
+                            match reset_db() {
+                                Ok(backup_dir) => {
+                                    rss_list_state.select(Some(0));
+                                    articles_list_state.select(Some(0));
+                                    status_bar_app.set(format!(
+                                        "Reset RSS feeds and articles, backup in {}",
+                                        backup_dir.display()
+                                    ));
+                                }
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                            inputbox_app.input_mode = InputMode::Normal;
+                        }
                     }
-                    KeyCode::Esc => {
+                    KeyCode::Enter if prompt_app.kind == PromptKind::RenameFeed => {
+                        let display_name = prompt_app.input.text_input.trim();
+                        if set_rss_feed_display_name(&rss_list_state, display_name) {
+                            status_bar_app.set(match display_name.is_empty() {
+                                true => String::from("Display name cleared"),
+                                false => format!("Display name set to \"{}\"", display_name),
+                            });
+                        }
                         inputbox_app.input_mode = InputMode::Normal;
                     }
+                    KeyCode::Enter => match parse_duration(&prompt_app.input.text_input) {
+                        Some(duration) => {
+                            let rss_id = match prompt_app.all_feeds {
+                                true => None,
+                                false => Some(read_selected_rss_feed(&rss_list_state).rss_id),
+                            };
+                            let num_marked = mark_read_before(rss_id, Utc::now() - duration);
+                            status_bar_app.set(format!("Marked {} articles as read", num_marked));
+                            inputbox_app.input_mode = InputMode::Normal;
+                        }
+                        None => status_bar_app.set_error(&ErrorMessages::new(
+                            ErrorCodes::E0036_DURATION_PARSE_FAILURE,
+                        )),
+                    },
+                    KeyCode::Tab if prompt_app.kind == PromptKind::MarkReadBefore => {
+                        prompt_app.all_feeds = !prompt_app.all_feeds;
+                    }
+                    KeyCode::Char(c) => {
+                        prompt_app.input.insert_char(c);
+                    }
+                    KeyCode::Backspace => {
+                        prompt_app.input.delete_char_before_cursor();
+                    }
+                    KeyCode::Delete => {
+                        prompt_app.input.delete_char_at_cursor();
+                    }
+                    KeyCode::Left => {
+                        prompt_app.input.move_cursor_left();
+                    }
+                    KeyCode::Right => {
+                        prompt_app.input.move_cursor_right();
+                    }
+                    KeyCode::Home => {
+                        prompt_app.input.move_cursor_home();
+                    }
+                    KeyCode::End => {
+                        prompt_app.input.move_cursor_end();
+                    }
                     _ => {}
                 },
+                InputMode::Selecting => {
+                    let body_length = read_selected_article(&rss_list_state, &articles_list_state)
+                        .map(|article| {
+                            article_body_text(&article, render_options.show_full_content)
+                                .chars()
+                                .count()
+                        })
+                        .unwrap_or_default();
+                    let line_width = (render_options.summary_pane_width as usize)
+                        .saturating_sub(2)
+                        .max(1);
+                    let max_offset = body_length.saturating_sub(1);
+
+                    if let Some((anchor, cursor)) = render_options.text_selection {
+                        match key.code {
+                            _ if keymap.action_for(&key) == Some(Action::Back) => {
+                                render_options.text_selection = None;
+                                inputbox_app.input_mode = InputMode::Normal;
+                            }
+                            KeyCode::Left | KeyCode::Char('h') => {
+                                render_options.text_selection =
+                                    Some((anchor, cursor.saturating_sub(1)));
+                            }
+                            KeyCode::Right | KeyCode::Char('l') => {
+                                render_options.text_selection =
+                                    Some((anchor, (cursor + 1).min(max_offset)));
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                render_options.text_selection =
+                                    Some((anchor, cursor.saturating_sub(line_width)));
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                render_options.text_selection =
+                                    Some((anchor, (cursor + line_width).min(max_offset)));
+                            }
+                            KeyCode::Char('y') => {
+                                if let Some(article) =
+                                    read_selected_article(&rss_list_state, &articles_list_state)
+                                {
+                                    let selected_text: String = article_body_text(
+                                        &article,
+                                        render_options.show_full_content,
+                                    )
+                                    .chars()
+                                    .skip(anchor.min(cursor))
+                                    .take(anchor.abs_diff(cursor) + 1)
+                                    .collect();
+
+                                    match copy_to_clipboard(&selected_text) {
+                                        Ok(()) => status_bar_app.set(format!(
+                                            "Copied {} characters to clipboard",
+                                            selected_text.chars().count()
+                                        )),
+                                        Err(err_msg) => status_bar_app.set_error(&err_msg),
+                                    }
+                                }
+                                render_options.text_selection = None;
+                                inputbox_app.input_mode = InputMode::Normal;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
                 InputMode::Popup => match key.code {
-                    KeyCode::Esc => {
+                    _ if keymap.action_for(&key) == Some(Action::Back)
+                        && popup_app.show_discovered_feeds_popup =>
+                    {
+                        popup_app.show_discovered_feeds_popup = false;
+                        inputbox_app.input_mode = InputMode::Editing;
+                    }
+                    _ if keymap.action_for(&key) == Some(Action::Back)
+                        && popup_app.preview_articles.is_some() =>
+                    {
+                        popup_app.preview_articles = None;
+                        inputbox_app.input_mode = InputMode::Editing;
+                    }
+                    _ if keymap.action_for(&key) == Some(Action::Back)
+                        && popup_app.raw_feed.is_some() =>
+                    {
+                        popup_app.raw_feed = None;
+                    }
+                    KeyCode::Down if popup_app.raw_feed.is_some() => {
+                        popup_app.raw_feed_scroll = popup_app.raw_feed_scroll.saturating_add(1);
+                    }
+                    KeyCode::Up if popup_app.raw_feed.is_some() => {
+                        popup_app.raw_feed_scroll = popup_app.raw_feed_scroll.saturating_sub(1);
+                    }
+                    KeyCode::Char('c') if popup_app.raw_feed.is_some() => {
+                        let raw_feed = popup_app.raw_feed.as_deref().unwrap_or_default();
+                        match copy_to_clipboard(raw_feed) {
+                            Ok(()) => status_bar_app.set(format!(
+                                "Raw RSS feed copied to clipboard ({} bytes)",
+                                raw_feed.len()
+                            )),
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    KeyCode::Char('W') if popup_app.show_feed_details_popup => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        match open_in_browser(selected_rss_feed.website_url()) {
+                            Ok(()) => status_bar_app.set(String::from("Website opened in browser")),
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    KeyCode::Char('x') if popup_app.show_feed_details_popup => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        match fetch_raw_feed(&selected_rss_feed).await {
+                            Ok(raw_feed) => {
+                                popup_app.raw_feed = Some(raw_feed);
+                                popup_app.raw_feed_scroll = 0;
+                            }
+                            Err(err_msg) => status_bar_app.set_error(&err_msg),
+                        }
+                    }
+                    KeyCode::Enter if popup_app.preview_articles.is_some() => {
+                        popup_app.preview_articles = None;
+                        inputbox_app.submit(&mut status_bar_app).await;
+                    }
+                    KeyCode::Down if popup_app.preview_articles.is_some() => {
+                        popup_app.preview_scroll = popup_app.preview_scroll.saturating_add(1);
+                    }
+                    KeyCode::Up if popup_app.preview_articles.is_some() => {
+                        popup_app.preview_scroll = popup_app.preview_scroll.saturating_sub(1);
+                    }
+                    _ if keymap.action_for(&key) == Some(Action::Back) => {
                         popup_app.show_refresh_popup = false;
                         popup_app.show_help_popup = false;
+                        popup_app.show_errors_popup = false;
+                        popup_app.tag_view = None;
+                        popup_app.archive_query = None;
+                        popup_app.show_snoozed_popup = false;
+                        popup_app.whats_new = None;
+                        popup_app.duplicate_feeds = None;
+                        popup_app.show_log_popup = false;
+                        popup_app.show_stats_popup = false;
+                        popup_app.show_feed_details_popup = false;
+                        popup_app.show_open_unread_popup = false;
+                        popup_app.show_quit_popup = false;
+                        popup_app.import_report = None;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Down if popup_app.import_report.is_some() => {
+                        popup_app.import_scroll = popup_app.import_scroll.saturating_add(1);
+                    }
+                    KeyCode::Up if popup_app.import_report.is_some() => {
+                        popup_app.import_scroll = popup_app.import_scroll.saturating_sub(1);
+                    }
+                    KeyCode::Enter if popup_app.whats_new.is_some() => {
+                        popup_app.whats_new = None;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('y') | KeyCode::Enter if popup_app.show_quit_popup => {
+                        // Waits for any database write in flight, and keeps later ones from starting
+                        let _db_lock = lock_db_writes();
+                        ui_state.last_session_at = Some(Utc::now());
+                        write_ui_state(&ui_state);
+                        restore_terminal(&mut terminal)?;
+                        return Ok(());
+                    }
+                    KeyCode::Char('n') if popup_app.show_quit_popup => {
+                        popup_app.show_quit_popup = false;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('y') | KeyCode::Enter if popup_app.duplicate_feeds.is_some() => {
+                        let duplicate_feeds = popup_app.duplicate_feeds.take().unwrap_or_default();
+                        let mut selected_rss_id = read_selected_rss_feed(&rss_list_state).rss_id;
+                        let mut merged = Vec::new();
+
+                        for candidate in &duplicate_feeds {
+                            if let Some(outcome) = merge_duplicate_feeds(candidate) {
+                                merged.push(format!(
+                                    "\"{}\" into \"{}\" ({} articles moved, {} already there)",
+                                    outcome.duplicate_name,
+                                    outcome.kept_name,
+                                    outcome.num_moved,
+                                    outcome.num_dropped
+                                ));
+                                if candidate.duplicate.rss_id == selected_rss_id {
+                                    selected_rss_id = candidate.kept.rss_id;
+                                }
+                            }
+                        }
+
+                        // Merged RSS feeds are removed, so the selection follows the RSS feed kept
+                        let rss_selected = read_rss_feed_list()
+                            .iter()
+                            .position(|r| r.rss_id == selected_rss_id)
+                            .unwrap_or(0);
+                        rss_list_state.select(Some(rss_selected));
+
+                        match merged.is_empty() {
+                            true => status_bar_app.set(String::from("No RSS feeds merged")),
+                            false => status_bar_app.set(format!(
+                                "Merged {} RSS feeds: {}",
+                                merged.len(),
+                                merged.join(", ")
+                            )),
+                        }
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('n') if popup_app.duplicate_feeds.is_some() => {
+                        popup_app.duplicate_feeds = None;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('y') | KeyCode::Enter if popup_app.show_open_unread_popup => {
+                        let selected_rss_feed = read_selected_rss_feed(&rss_list_state);
+                        status_bar_app.set_open_articles_result(open_articles_in_browser(
+                            &read_unread_articles(selected_rss_feed.rss_id),
+                        ));
+                        popup_app.show_open_unread_popup = false;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Down if popup_app.show_discovered_feeds_popup => {
+                        if let Some(selected) = discovered_feeds_list_state.selected() {
+                            let num_feeds = popup_app.discovered_feeds.len();
+                            discovered_feeds_list_state.select(Some((selected + 1) % num_feeds));
+                        }
+                    }
+                    KeyCode::Up if popup_app.show_discovered_feeds_popup => {
+                        if let Some(selected) = discovered_feeds_list_state.selected() {
+                            let num_feeds = popup_app.discovered_feeds.len();
+                            discovered_feeds_list_state
+                                .select(Some((selected + num_feeds - 1) % num_feeds));
+                        }
+                    }
+                    KeyCode::Enter if popup_app.show_discovered_feeds_popup => {
+                        let selected_feed = discovered_feeds_list_state
+                            .selected()
+                            .and_then(|selected| popup_app.discovered_feeds.get(selected));
+
+                        if let Some(feed_url) = selected_feed {
+                            let url_input = &mut inputbox_app.fields[URL_FIELD];
+                            url_input.take_text();
+                            url_input.insert_str(feed_url);
+                        }
+
+                        popup_app.show_discovered_feeds_popup = false;
+                        if inputbox_app.is_valid() {
+                            inputbox_app.submit(&mut status_bar_app).await;
+                        } else {
+                            // Added from clipboard, category and name are still to be filled in
+                            inputbox_app.input_mode = InputMode::Editing;
+                        }
+                    }
+                    KeyCode::Down if popup_app.show_help_popup => {
+                        popup_app.help_scroll = popup_app.help_scroll.saturating_add(1);
+                    }
+                    KeyCode::Up if popup_app.show_help_popup => {
+                        popup_app.help_scroll = popup_app.help_scroll.saturating_sub(1);
+                    }
+                    KeyCode::Down if popup_app.show_log_popup => {
+                        if let Some(selected) = log_list_state.selected() {
+                            log_list_state.select(Some(selected + 1));
+                        }
+                    }
+                    KeyCode::Up if popup_app.show_log_popup => {
+                        if let Some(selected) = log_list_state.selected() {
+                            log_list_state.select(Some(selected.saturating_sub(1)));
+                        }
+                    }
+                    KeyCode::Down if popup_app.tag_view.is_some() => {
+                        let num_articles = popup_app
+                            .tag_view
+                            .as_deref()
+                            .map(|tag| read_tagged_articles(tag).len())
+                            .unwrap_or_default();
+                        if let Some(selected) = tagged_list_state.selected() {
+                            if selected + 1 >= num_articles {
+                                tagged_list_state.select(Some(0));
+                            } else {
+                                tagged_list_state.select(Some(selected + 1));
+                            }
+                        }
+                    }
+                    KeyCode::Up if popup_app.tag_view.is_some() => {
+                        let num_articles = popup_app
+                            .tag_view
+                            .as_deref()
+                            .map(|tag| read_tagged_articles(tag).len())
+                            .unwrap_or_default();
+                        if let Some(selected) = tagged_list_state.selected() {
+                            if selected > 0 {
+                                tagged_list_state.select(Some(selected - 1));
+                            } else if num_articles > 0 {
+                                tagged_list_state.select(Some(num_articles - 1));
+                            }
+                        }
+                    }
+                    KeyCode::Enter if popup_app.tag_view.is_some() => {
+                        let tagged_articles = popup_app
+                            .tag_view
+                            .as_deref()
+                            .map(read_tagged_articles)
+                            .unwrap_or_default();
+                        let selected_article = tagged_list_state
+                            .selected()
+                            .and_then(|selected| tagged_articles.get(selected));
+
+                        if let Some(article) = selected_article {
+                            if let Some(selected) = read_rss_feed_list()
+                                .iter()
+                                .position(|r| r.rss_id == article.rss_id)
+                            {
+                                let article_selected = read_rss_articles(article.rss_id)
+                                    .iter()
+                                    .position(|r| r.article_id == article.article_id)
+                                    .unwrap_or(0);
+                                rss_list_state.select(Some(selected));
+                                articles_list_state.select(Some(article_selected));
+                            }
+                        }
+
+                        popup_app.tag_view = None;
+                        inputbox_app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Down if popup_app.archive_query.is_some() => {
+                        let num_articles = popup_app
+                            .archive_query
+                            .as_deref()
+                            .map(|query| search_archive(query).len())
+                            .unwrap_or_default();
+                        if let Some(selected) = archive_list_state.selected() {
+                            if selected + 1 >= num_articles {
+                                archive_list_state.select(Some(0));
+                            } else {
+                                archive_list_state.select(Some(selected + 1));
+                            }
+                        }
+                    }
+                    KeyCode::Up if popup_app.archive_query.is_some() => {
+                        let num_articles = popup_app
+                            .archive_query
+                            .as_deref()
+                            .map(|query| search_archive(query).len())
+                            .unwrap_or_default();
+                        if let Some(selected) = archive_list_state.selected() {
+                            if selected > 0 {
+                                archive_list_state.select(Some(selected - 1));
+                            } else if num_articles > 0 {
+                                archive_list_state.select(Some(num_articles - 1));
+                            }
+                        }
+                    }
+                    KeyCode::Enter if popup_app.archive_query.is_some() => {
+                        let archived_articles = popup_app
+                            .archive_query
+                            .as_deref()
+                            .map(search_archive)
+                            .unwrap_or_default();
+                        let selected_article = archive_list_state
+                            .selected()
+                            .and_then(|selected| archived_articles.get(selected));
+
+                        if let Some(article) = selected_article {
+                            match open_in_browser(&article.article_link) {
+                                Ok(()) => {
+                                    status_bar_app.set(String::from("Article opened in browser"))
+                                }
+                                Err(err_msg) => status_bar_app.set_error(&err_msg),
+                            }
+                        }
+                    }
+                    KeyCode::Down if popup_app.show_snoozed_popup => {
+                        let num_articles = read_snoozed_articles().len();
+                        if let Some(selected) = snoozed_list_state.selected() {
+                            if selected + 1 >= num_articles {
+                                snoozed_list_state.select(Some(0));
+                            } else {
+                                snoozed_list_state.select(Some(selected + 1));
+                            }
+                        }
+                    }
+                    KeyCode::Up if popup_app.show_snoozed_popup => {
+                        let num_articles = read_snoozed_articles().len();
+                        if let Some(selected) = snoozed_list_state.selected() {
+                            if selected > 0 {
+                                snoozed_list_state.select(Some(selected - 1));
+                            } else if num_articles > 0 {
+                                snoozed_list_state.select(Some(num_articles - 1));
+                            }
+                        }
+                    }
+                    KeyCode::Enter if popup_app.show_snoozed_popup => {
+                        let snoozed_articles = read_snoozed_articles();
+                        let selected_article = snoozed_list_state
+                            .selected()
+                            .and_then(|selected| snoozed_articles.get(selected));
+
+                        if let Some(article) = selected_article {
+                            if snooze_article(article.article_id, None) {
+                                status_bar_app.set(format!("\"{}\" is back", article.title));
+                            }
+                        }
+                    }
+                    KeyCode::Down if popup_app.show_errors_popup => {
+                        let num_feed_errors = read_feed_errors().len();
+                        if let Some(selected) = errors_list_state.selected() {
+                            if selected + 1 >= num_feed_errors {
+                                errors_list_state.select(Some(0));
+                            } else {
+                                errors_list_state.select(Some(selected + 1));
+                            }
+                        }
+                    }
+                    KeyCode::Up if popup_app.show_errors_popup => {
+                        let num_feed_errors = read_feed_errors().len();
+                        if let Some(selected) = errors_list_state.selected() {
+                            if selected > 0 {
+                                errors_list_state.select(Some(selected - 1));
+                            } else if num_feed_errors > 0 {
+                                errors_list_state.select(Some(num_feed_errors - 1));
+                            }
+                        }
+                    }
+                    KeyCode::Enter if popup_app.show_errors_popup => {
+                        let feed_errors = read_feed_errors();
+                        let selected_error = errors_list_state
+                            .selected()
+                            .and_then(|selected| feed_errors.get(selected));
+
+                        if let Some(feed_error) = selected_error {
+                            if let Some(selected) = read_rss_feed_list()
+                                .iter()
+                                .position(|r| r.rss_id == feed_error.rss_id)
+                            {
+                                rss_list_state.select(Some(selected));
+                                articles_list_state.select(Some(0));
+                            }
+                        }
+
+                        popup_app.show_errors_popup = false;
                         inputbox_app.input_mode = InputMode::Normal;
                     }
                     _ => {}
                 },
             }
+
+            let selected_article = read_selected_article(&rss_list_state, &articles_list_state);
+            if let Some(article) = &selected_article {
+                if article.viewed_at.is_none() {
+                    mark_article_viewed(article.article_id);
+                }
+            }
+
+            selected_ids = (
+                read_selected_rss_feed(&rss_list_state).rss_id,
+                selected_article.map(|article| article.article_id),
+            );
         }
     }
 }
@@ -0,0 +1,82 @@
+//! Shows desktop notifications when refreshes find new articles, and new articles matching
+//! the alert keywords (with `alert_notifications` set in config)
+//!
+//! Notifications are shown only when the application is built with the "notifications"
+//! feature and `desktop_notifications` is set in config. Environments without a notification
+//! daemon are silently skipped.
+//!
+
+use crate::config::get_config;
+use log::debug;
+
+/// Notifies about the RSS feeds (by name) with new articles found by a single refresh run
+///
+/// At most `max_notifications_per_refresh` (see config) notifications are shown per run, the
+/// remaining RSS feeds are summarized in a last notification.
+pub fn notify_new_articles(new_articles: &[(String, usize)]) {
+    let config = get_config();
+    if !config.desktop_notifications {
+        return;
+    }
+
+    let new_articles: Vec<&(String, usize)> = new_articles.iter().filter(|(_, n)| *n > 0).collect();
+    let max_notifications = config.max_notifications_per_refresh.max(1);
+
+    for (idx, (feed_name, num_articles)) in new_articles.iter().enumerate() {
+        if idx + 1 == max_notifications && new_articles.len() > max_notifications {
+            let remaining = &new_articles[idx..];
+            show_notification(&format!(
+                "{} new articles in {} RSS feeds",
+                remaining.iter().map(|(_, n)| n).sum::<usize>(),
+                remaining.len()
+            ));
+            break;
+        }
+        show_notification(&format!("{} new articles in {}", num_articles, feed_name));
+    }
+}
+
+/// Notifies about the new articles (by title) matching the alert keywords, found by a single
+/// refresh run
+///
+/// Capped like [`notify_new_articles`], the remaining articles are summarized in a last
+/// notification.
+pub fn notify_alert_articles(article_titles: &[String]) {
+    let config = get_config();
+    if !config.desktop_notifications || !config.alert_notifications {
+        return;
+    }
+
+    let max_notifications = config.max_notifications_per_refresh.max(1);
+    for (idx, article_title) in article_titles.iter().enumerate() {
+        if idx + 1 == max_notifications && article_titles.len() > max_notifications {
+            show_notification(&format!(
+                "{} more articles matching alert keywords",
+                article_titles.len() - idx
+            ));
+            break;
+        }
+        show_notification(&format!("Alert: {}", article_title));
+    }
+}
+
+#[cfg(feature = "notifications")]
+fn show_notification(body: &str) {
+    let result = notify_rust::Notification::new()
+        .appname("Byte-Bite")
+        .summary("Byte-Bite")
+        .body(body)
+        .show();
+
+    if let Err(err) = result {
+        debug!("Unable to show desktop notification \"{}\": {}", body, err);
+    }
+}
+
+#[cfg(not(feature = "notifications"))]
+fn show_notification(body: &str) {
+    debug!(
+        "Desktop notification \"{}\" skipped, built without the \"notifications\" feature",
+        body
+    );
+}
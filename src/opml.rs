@@ -0,0 +1,292 @@
+//! Imports RSS feeds from OPML files exported by other RSS feed readers, and exports them
+//! back to OPML
+//!
+//! Nested outlines (category, subcategory, ...) are imported as a single category path joined
+//! with [`CATEGORY_SEPARATOR`] (e.g. "News/World/Europe"), and exported nested again.
+//!
+
+use crate::db_writer::lock_db_writes;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::feed_layers::write_feed_layers;
+use crate::fetcher::refresh_rss_feeds;
+use crate::{is_valid_feed_url, next_rss_id, read_rss_db, RSSFeed};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{encoding::Decoder, Reader};
+use std::fs;
+
+/// Category used for imported RSS feeds which are not nested in any OPML outline
+pub(crate) const DEFAULT_IMPORT_CATEGORY: &str = "Imported";
+
+/// Separator between the levels of nested categories (e.g. "News/World/Europe")
+pub const CATEGORY_SEPARATOR: char = '/';
+
+/// Defines the metadata of an RSS feed found in an OPML file
+#[derive(Clone, Debug)]
+pub struct OpmlFeed {
+    /// RSS feed category (titles of the parent outlines, joined with [`CATEGORY_SEPARATOR`])
+    pub category: String,
+    /// RSS feed name
+    pub name: String,
+    /// RSS feed URL
+    pub url: String,
+}
+
+/// Defines an OPML entry which could not be imported, and why
+#[derive(Clone, Debug)]
+pub struct InvalidOpmlFeed {
+    /// RSS feed found in the OPML file
+    pub opml_feed: OpmlFeed,
+    /// Reason the RSS feed was not imported
+    pub reason: String,
+}
+
+/// Defines the outcome of an OPML import
+#[derive(Clone, Debug, Default)]
+pub struct ImportReport {
+    /// RSS feeds added by the import
+    pub imported: Vec<OpmlFeed>,
+    /// RSS ids of the RSS feeds added by the import, to be refreshed
+    pub new_rss_ids: Vec<usize>,
+    /// RSS feeds skipped as already present (same URL)
+    pub duplicates: Vec<OpmlFeed>,
+    /// RSS feeds which could not be imported
+    pub invalid: Vec<InvalidOpmlFeed>,
+}
+
+impl ImportReport {
+    /// Summarizes the import in a single line
+    pub fn summary(&self) -> String {
+        format!(
+            "Imported {} RSS feeds, skipped {} duplicates, {} invalid entries",
+            self.imported.len(),
+            self.duplicates.len(),
+            self.invalid.len()
+        )
+    }
+}
+
+/// Imports the RSS feeds of given OPML file, and refreshes the new feeds
+///
+/// See [`store_opml_feeds`] for the import itself. The new RSS feeds are refreshed through
+/// the bounded concurrency fetcher.
+pub async fn import_opml(file_path: &str) -> Result<ImportReport, ErrorMessages> {
+    let import_report = store_opml_feeds(file_path)?;
+    refresh_rss_feeds(&import_report.new_rss_ids).await;
+    Ok(import_report)
+}
+
+/// Stores the RSS feeds of given OPML file, without refreshing them
+///
+/// RSS feeds already present (by URL) are skipped, and so are RSS feeds with an invalid URL.
+/// All the new RSS feeds are stored in a single write.
+pub fn store_opml_feeds(file_path: &str) -> Result<ImportReport, ErrorMessages> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0007_FILE_READ_FAILURE))?;
+    let opml_feeds = parse_opml(&content)?;
+
+    let _db_lock = lock_db_writes();
+    let mut rss_feed_list: Vec<RSSFeed> = read_rss_db();
+    let mut import_report = ImportReport::default();
+
+    for opml_feed in opml_feeds.into_iter() {
+        if !is_valid_feed_url(&opml_feed.url) {
+            import_report.invalid.push(InvalidOpmlFeed {
+                opml_feed,
+                reason: String::from("URL is not a valid HTTP(S) URL"),
+            });
+            continue;
+        }
+
+        if rss_feed_list
+            .iter()
+            .any(|r| r.url.trim() == opml_feed.url.trim())
+        {
+            import_report.duplicates.push(opml_feed);
+            continue;
+        }
+
+        let rss_id = next_rss_id(&rss_feed_list);
+        rss_feed_list.push(RSSFeed::new(
+            rss_id,
+            &opml_feed.category,
+            &opml_feed.name,
+            &opml_feed.url,
+        ));
+        import_report.new_rss_ids.push(rss_id);
+        import_report.imported.push(opml_feed);
+    }
+
+    if !import_report.new_rss_ids.is_empty() {
+        write_feed_layers(&rss_feed_list);
+    }
+
+    Ok(import_report)
+}
+
+/// Splits a category into the titles of its levels (see [`CATEGORY_SEPARATOR`]), dropping
+/// empty levels
+pub fn category_path(category: &str) -> Vec<&str> {
+    category
+        .split(CATEGORY_SEPARATOR)
+        .map(str::trim)
+        .filter(|level| !level.is_empty())
+        .collect()
+}
+
+/// Serializes given RSS feeds into an OPML document, with one outline per category, nested
+/// as per the levels of the categories
+///
+/// Categories are listed in the order they first appear in the RSS feeds list.
+pub fn feeds_to_opml(rss_feeds: &[RSSFeed]) -> String {
+    let mut opml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n  <head>\n    <title>Byte-Bite RSS feeds</title>\n  </head>\n  <body>\n",
+    );
+
+    let feeds: Vec<(Vec<&str>, &RSSFeed)> = rss_feeds
+        .iter()
+        .map(|rss_feed| (category_path(&rss_feed.category), rss_feed))
+        .collect();
+    push_opml_outlines(&mut opml, &feeds, 0);
+
+    opml.push_str("  </body>\n</opml>\n");
+    opml
+}
+
+/// Serializes the RSS feeds at given category level, as the outlines of their categories at
+/// that level, or as RSS feed outlines for the RSS feeds without any deeper level
+fn push_opml_outlines(opml: &mut String, feeds: &[(Vec<&str>, &RSSFeed)], level: usize) {
+    let indent = "  ".repeat(level + 2);
+
+    // RSS feeds filed right under this level (at the top level, RSS feeds without any category)
+    for (_, rss_feed) in feeds.iter().filter(|(path, _)| path.len() <= level) {
+        opml.push_str(&format!(
+            "{0}<outline type=\"rss\" text=\"{1}\" title=\"{1}\" xmlUrl=\"{2}\"/>\n",
+            indent,
+            escape(rss_feed.name.as_str()),
+            escape(rss_feed.url.as_str())
+        ));
+    }
+
+    let mut categories: Vec<&str> = Vec::new();
+    for (path, _) in feeds.iter().filter(|(path, _)| path.len() > level) {
+        if !categories.contains(&path[level]) {
+            categories.push(path[level]);
+        }
+    }
+
+    for category in categories {
+        opml.push_str(&format!(
+            "{0}<outline text=\"{1}\" title=\"{1}\">\n",
+            indent,
+            escape(category)
+        ));
+        let sub_feeds: Vec<(Vec<&str>, &RSSFeed)> = feeds
+            .iter()
+            .filter(|(path, _)| path.len() > level && path[level] == category)
+            .cloned()
+            .collect();
+        push_opml_outlines(opml, &sub_feeds, level + 1);
+        opml.push_str(&format!("{}</outline>\n", indent));
+    }
+}
+
+/// Serializes all the RSS feeds (except the welcome feed) into an OPML document
+pub fn export_opml() -> String {
+    let rss_feed_list: Vec<RSSFeed> = read_rss_db().into_iter().skip(1).collect();
+    feeds_to_opml(&rss_feed_list)
+}
+
+/// Exports all the RSS feeds (except the welcome feed) into given OPML file
+pub fn export_opml_file(file_path: &str) -> Result<(), ErrorMessages> {
+    fs::write(file_path, export_opml())
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0009_FILE_WRITE_FAILURE))
+}
+
+/// Parses the RSS feeds (outlines with an `xmlUrl` attribute) of given OPML content
+pub fn parse_opml(content: &str) -> Result<Vec<OpmlFeed>, ErrorMessages> {
+    let mut reader = Reader::from_str(content);
+    let mut opml_feeds: Vec<OpmlFeed> = Vec::new();
+    let mut categories: Vec<Option<String>> = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element)) if element.name().as_ref() == b"outline" => {
+                let outline = read_outline(&element, reader.decoder());
+                match &outline.url {
+                    Some(_) => categories.push(None),
+                    None => categories.push(outline.title.clone()),
+                }
+                push_opml_feed(&mut opml_feeds, outline, &categories);
+            }
+            Ok(Event::Empty(element)) if element.name().as_ref() == b"outline" => {
+                push_opml_feed(
+                    &mut opml_feeds,
+                    read_outline(&element, reader.decoder()),
+                    &categories,
+                );
+            }
+            Ok(Event::End(element)) if element.name().as_ref() == b"outline" => {
+                categories.pop();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(_err) => return Err(ErrorMessages::new(ErrorCodes::E0032_OPML_PARSE_FAILURE)),
+        }
+    }
+
+    Ok(opml_feeds)
+}
+
+/// Defines the attributes of an OPML outline used for importing RSS feeds
+struct Outline {
+    title: Option<String>,
+    url: Option<String>,
+}
+
+/// Reads the title and feed URL attributes of an OPML outline
+fn read_outline(element: &BytesStart, decoder: Decoder) -> Outline {
+    let mut title: Option<String> = None;
+    let mut text: Option<String> = None;
+    let mut url: Option<String> = None;
+
+    for attribute in element.attributes().flatten() {
+        let value = match attribute.decode_and_unescape_value(decoder) {
+            Ok(value) => value.trim().to_string(),
+            Err(_err) => continue,
+        };
+
+        if value.is_empty() {
+            continue;
+        }
+
+        match attribute.key.as_ref() {
+            b"title" => title = Some(value),
+            b"text" => text = Some(value),
+            b"xmlUrl" => url = Some(value),
+            _ => {}
+        }
+    }
+
+    Outline {
+        title: title.or(text),
+        url,
+    }
+}
+
+/// Adds the outline to the RSS feeds found, if it is an RSS feed
+fn push_opml_feed(opml_feeds: &mut Vec<OpmlFeed>, outline: Outline, categories: &[Option<String>]) {
+    if let Some(url) = outline.url {
+        let category_levels: Vec<&str> = categories.iter().flatten().map(String::as_str).collect();
+        let category = match category_levels.is_empty() {
+            true => String::from(DEFAULT_IMPORT_CATEGORY),
+            false => category_levels.join(&CATEGORY_SEPARATOR.to_string()),
+        };
+
+        opml_feeds.push(OpmlFeed {
+            category,
+            name: outline.title.unwrap_or_else(|| url.clone()),
+            url,
+        });
+    }
+}
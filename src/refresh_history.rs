@@ -0,0 +1,119 @@
+//! Keeps a persistent history of RSS feed refreshes, as JSON lines
+//!
+//! Every refresh appends one [`RefreshOutcome`] to the refresh history file. Once the file
+//! grows past `MAX_REFRESH_HISTORY_SIZE` bytes, it is rotated to a single backup file
+//! (".1" suffix), replacing the previous backup. Refreshes always run on background threads,
+//! so appending to the history never blocks the terminal UI.
+//!
+
+use crate::config::data_path;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::RSSFeed;
+use chrono::prelude::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::sync::Mutex;
+
+/// File name for the refresh history (one JSON entry per line), in the data folder
+pub const REFRESH_HISTORY_FILE: &str = "refresh_history.jsonl";
+
+/// Size (in bytes) above which the refresh history file is rotated
+const MAX_REFRESH_HISTORY_SIZE: u64 = 1024 * 1024;
+
+static REFRESH_HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Defines the metadata for storing the outcome of a single RSS feed refresh
+pub struct RefreshOutcome {
+    /// Timestamp of the refresh
+    pub refreshed_at: DateTime<Utc>,
+    /// Unique identifier for the refreshed RSS feed
+    pub rss_id: usize,
+    /// RSS feed name
+    pub feed_name: String,
+    /// Number of new articles stored by the refresh
+    pub new_articles: usize,
+    /// Refresh status ("success" or "failure")
+    pub status: String,
+    /// Error code and description of failed refreshes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RefreshOutcome {
+    /// Builds the outcome of a refresh of given RSS feed
+    pub fn new(rss_feed: &RSSFeed, result: &Result<usize, ErrorMessages>) -> RefreshOutcome {
+        let (new_articles, status, error) = match result {
+            Ok(new_articles) => (*new_articles, "success", None),
+            Err(err_msg) => (
+                0,
+                "failure",
+                Some(format!(
+                    "{:?} - {}",
+                    err_msg.error_code, err_msg.error_message
+                )),
+            ),
+        };
+
+        RefreshOutcome {
+            refreshed_at: Utc::now(),
+            rss_id: rss_feed.rss_id,
+            feed_name: rss_feed.name.clone(),
+            new_articles,
+            status: String::from(status),
+            error,
+        }
+    }
+}
+
+/// Appends the outcome of a refresh to the refresh history file, rotating it when too large
+///
+/// Failures are logged and otherwise ignored, as the history is not essential to a refresh.
+pub fn log_refresh_outcome(outcome: &RefreshOutcome) {
+    let _history_lock = REFRESH_HISTORY_LOCK
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+
+    if let Err(err_msg) = append_refresh_outcome(outcome) {
+        warn!(
+            "Unable to record refresh of RSS feed \"{}\": {:?} - {}",
+            outcome.feed_name, err_msg.error_code, err_msg.error_message
+        );
+    }
+}
+
+fn append_refresh_outcome(outcome: &RefreshOutcome) -> Result<(), ErrorMessages> {
+    let history_error = |_err| ErrorMessages::new(ErrorCodes::E0034_REFRESH_HISTORY_WRITE_FAILURE);
+
+    let history_path = data_path(REFRESH_HISTORY_FILE);
+    let history_size = fs::metadata(&history_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    if history_size > MAX_REFRESH_HISTORY_SIZE {
+        fs::rename(&history_path, format!("{}.1", history_path)).map_err(history_error)?;
+    }
+
+    let mut entry = serde_json::to_string(outcome)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0006_SERDE_JSON_SERIALIZATION_FAILURE))?;
+    entry.push('\n');
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&history_path)
+        .and_then(|mut file| file.write_all(entry.as_bytes()))
+        .map_err(history_error)
+}
+
+/// Reads the refresh history, oldest first, skipping entries which can't be parsed
+///
+/// Only the current history file is read, not its rotated backup.
+pub fn read_refresh_history() -> Vec<RefreshOutcome> {
+    fs::read_to_string(data_path(REFRESH_HISTORY_FILE))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RefreshOutcome>(line).ok())
+        .collect()
+}
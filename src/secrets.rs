@@ -0,0 +1,63 @@
+//! Resolves the secret references used in the request headers of RSS feeds
+//!
+//! Header values may reference secrets as `${NAME}` (e.g. `"Authorization": "Bearer ${NEWS_TOKEN}"`),
+//! so that API tokens are never stored in the RSS database. References are resolved on every
+//! refresh, first from the environment variable `NAME`, then from the `NAME` entry of the
+//! secrets file (`secrets_file` in config, a JSON object of names to values).
+//!
+
+use crate::config::get_config;
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Replaces the secret references (`${NAME}`) of given header value with the secrets they name
+///
+/// Fails with `E0045_UNRESOLVED_SECRET_REFERENCE` when a secret can't be found.
+pub fn resolve_secret_references(value: &str) -> Result<String, ErrorMessages> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let end = match rest[start..].find('}') {
+            Some(t) => start + t,
+            None => break,
+        };
+
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(&resolve_secret(rest[start + 2..end].trim())?);
+        rest = &rest[end + 1..];
+    }
+
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+/// Resolves a single secret by name, from the environment and then from the secrets file
+pub fn resolve_secret(name: &str) -> Result<String, ErrorMessages> {
+    if let Some(secret) = std::env::var(name).ok().filter(|t| !t.is_empty()) {
+        return Ok(secret);
+    }
+
+    read_secrets_file()
+        .remove(name)
+        .ok_or_else(|| unresolved_secret_error(name))
+}
+
+/// Reads the secrets file set in config, an empty list of secrets when missing or invalid
+fn read_secrets_file() -> BTreeMap<String, String> {
+    fs::read_to_string(&get_config().secrets_file)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn unresolved_secret_error(name: &str) -> ErrorMessages {
+    let mut err_msg = ErrorMessages::new(ErrorCodes::E0045_UNRESOLVED_SECRET_REFERENCE);
+    err_msg.error_message = format!(
+        "{} (\"{}\")",
+        err_msg.error_message.trim_end_matches('.'),
+        name
+    );
+    err_msg
+}
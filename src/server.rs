@@ -0,0 +1,54 @@
+//! Serves the RSS feeds and articles data as read-only JSON endpoints over HTTP
+//!
+//! Available only when built with the `serve` feature, and started with `--serve <port>`.
+//!
+
+use crate::error_db::{ErrorCodes, ErrorMessages};
+use crate::{read_articles_db, read_rss_articles, read_rss_db, Articles, RSSFeed};
+use axum::{extract::Path, http::StatusCode, routing::get, Json, Router};
+use std::net::SocketAddr;
+
+/// Starts the HTTP server on the given port and serves the JSON endpoints, until it fails
+pub async fn serve(port: u16) -> Result<(), ErrorMessages> {
+    let app = Router::new()
+        .route("/feeds", get(list_feeds))
+        .route("/feeds/:rss_id/articles", get(list_feed_articles))
+        .route("/articles/:article_id", get(get_article));
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+
+    axum::Server::try_bind(&addr)
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0025_HTTP_SERVER_FAILURE))?
+        .serve(app.into_make_service())
+        .await
+        .map_err(|_err| ErrorMessages::new(ErrorCodes::E0025_HTTP_SERVER_FAILURE))
+}
+
+/// Lists all the RSS feeds
+///
+/// Request headers of the RSS feeds are left out, as they may contain credentials.
+async fn list_feeds() -> Json<Vec<RSSFeed>> {
+    let mut rss_feed_list = read_rss_db();
+    for rss_feed in rss_feed_list.iter_mut() {
+        rss_feed.headers.clear();
+    }
+    Json(rss_feed_list)
+}
+
+/// Lists the articles of given RSS feed, latest first
+async fn list_feed_articles(Path(rss_id): Path<usize>) -> Result<Json<Vec<Articles>>, StatusCode> {
+    if !read_rss_db().iter().any(|r| r.rss_id == rss_id) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(Json(read_rss_articles(rss_id)))
+}
+
+/// Returns a single article
+async fn get_article(Path(article_id): Path<usize>) -> Result<Json<Articles>, StatusCode> {
+    read_articles_db()
+        .into_iter()
+        .find(|r| r.article_id == article_id)
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
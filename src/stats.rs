@@ -0,0 +1,102 @@
+//! Computes reading statistics from the RSS feeds and articles stored in the JSON databases
+//!
+
+use crate::config::get_config;
+use crate::feed_status::{read_slowest_feeds, FetchStats};
+use crate::{read_articles_db, read_rss_db, Articles, RSSFeed};
+use chrono::prelude::{DateTime, Local, NaiveDate, Utc};
+use chrono::Duration;
+
+/// Number of days covered by the articles per day chart
+pub const STATS_DAYS: i64 = 7;
+
+/// Maximum number of RSS feeds listed as most active
+pub const MAX_ACTIVE_FEEDS: usize = 5;
+
+/// Maximum number of RSS feeds listed as slowest
+pub const MAX_SLOWEST_FEEDS: usize = 5;
+
+#[derive(Clone, Debug)]
+/// Defines the statistics shown in the statistics popup
+pub struct FeedStats {
+    /// Number of RSS feeds (excluding the welcome page)
+    pub num_feeds: usize,
+    /// Number of articles (excluding hidden articles)
+    pub num_articles: usize,
+    /// Number of articles not read yet
+    pub num_unread: usize,
+    /// Number of articles added on each of the last `STATS_DAYS` days, oldest first
+    pub articles_per_day: Vec<(String, u64)>,
+    /// RSS feeds with the most articles added over the last `STATS_DAYS` days, most active first
+    pub most_active_feeds: Vec<(String, usize)>,
+    /// RSS feeds with the slowest recent fetches since startup, slowest first
+    pub slowest_feeds: Vec<(String, FetchStats)>,
+}
+
+/// Computes the statistics of the stored RSS feeds and articles
+pub fn compute_feed_stats() -> FeedStats {
+    let rss_feed_list: Vec<RSSFeed> = read_rss_db().into_iter().skip(1).collect();
+    let articles_list: Vec<Articles> = read_articles_db()
+        .into_iter()
+        .filter(|r| !r.hidden)
+        .collect();
+
+    let today = local_date(&Utc::now());
+    let days: Vec<NaiveDate> = (0..STATS_DAYS)
+        .rev()
+        .map(|days_ago| today - Duration::days(days_ago))
+        .collect();
+    let first_day = days[0];
+
+    let articles_per_day = days
+        .iter()
+        .map(|day| {
+            let num_articles = articles_list
+                .iter()
+                .filter(|r| local_date(&r.created_at) == *day)
+                .count();
+            (day.format("%d %b").to_string(), num_articles as u64)
+        })
+        .collect();
+
+    let mut most_active_feeds: Vec<(String, usize)> = rss_feed_list
+        .iter()
+        .map(|rss_feed| {
+            let num_articles = articles_list
+                .iter()
+                .filter(|r| r.rss_id == rss_feed.rss_id && local_date(&r.created_at) >= first_day)
+                .count();
+            (rss_feed.label().to_string(), num_articles)
+        })
+        .filter(|(_, num_articles)| *num_articles > 0)
+        .collect();
+    most_active_feeds.sort_by_key(|(_, num_articles)| std::cmp::Reverse(*num_articles));
+    most_active_feeds.truncate(MAX_ACTIVE_FEEDS);
+
+    let slowest_feeds = read_slowest_feeds()
+        .into_iter()
+        .filter_map(|(rss_id, fetch_stats)| {
+            rss_feed_list
+                .iter()
+                .find(|r| r.rss_id == rss_id)
+                .map(|rss_feed| (rss_feed.label().to_string(), fetch_stats))
+        })
+        .take(MAX_SLOWEST_FEEDS)
+        .collect();
+
+    FeedStats {
+        num_feeds: rss_feed_list.len(),
+        num_articles: articles_list.len(),
+        num_unread: articles_list.iter().filter(|r| !r.read).count(),
+        articles_per_day,
+        most_active_feeds,
+        slowest_feeds,
+    }
+}
+
+fn local_date(timestamp: &DateTime<Utc>) -> NaiveDate {
+    match get_config().use_local_timezone {
+        true => timestamp.with_timezone(&Local).date_naive(),
+        false => timestamp.date_naive(),
+    }
+}
@@ -0,0 +1,35 @@
+//! Keeps the links of the articles which are not added back on refresh
+//!
+//! Articles hidden by the user, and articles evicted to keep at most `max_articles` (see
+//! config), have their links recorded in the suppression set. Refreshes skip the articles
+//! whose link is in the set, even once the article itself is gone from the articles database.
+//! The set is cleared along with the databases on reset.
+//!
+
+use crate::config::data_path;
+use crate::db_writer::write_db_file;
+use std::collections::BTreeSet;
+use std::fs;
+
+/// JSON file name for the links of the articles not added back on refresh, in the data folder
+pub const SUPPRESSED_LINKS_FILE: &str = "suppressed_links.json";
+
+/// Reads the links of the articles not added back on refresh
+pub fn read_suppressed_links() -> BTreeSet<String> {
+    fs::read_to_string(data_path(SUPPRESSED_LINKS_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Adds the given article links to the suppression set
+///
+/// Callers are expected to hold the database write lock.
+pub fn suppress_links<I: IntoIterator<Item = String>>(links: I) {
+    let mut suppressed_links = read_suppressed_links();
+    let num_links = suppressed_links.len();
+    suppressed_links.extend(links);
+    if suppressed_links.len() != num_links {
+        write_db_file(&data_path(SUPPRESSED_LINKS_FILE), &suppressed_links);
+    }
+}
@@ -0,0 +1,215 @@
+//! Defines the colors and markers used for rendering the RSS feeds and articles lists, and the
+//! legend explaining them
+//!
+//! The theme is part of the application configuration (`theme`), colors being given by name
+//! (e.g. "light_magenta") or as "#rrggbb".
+//!
+
+use serde::{Deserialize, Serialize};
+use tui::style::{Color, Modifier, Style};
+use tui::text::Span;
+
+/// Text modifiers accepted in the theme
+pub const MODIFIER_NAMES: [&str; 6] = [
+    "bold",
+    "dim",
+    "italic",
+    "underlined",
+    "reversed",
+    "crossed_out",
+];
+
+/// Color names accepted in the theme, besides "#rrggbb"
+pub const COLOR_NAMES: [&str; 17] = [
+    "reset",
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "gray",
+    "dark_gray",
+    "light_red",
+    "light_green",
+    "light_yellow",
+    "light_blue",
+    "light_magenta",
+    "light_cyan",
+    "white",
+];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+/// Defines the styling conventions of the RSS feeds and articles lists
+pub struct Theme {
+    /// Color of the titles of unread articles (shown in bold)
+    pub unread_color: String,
+    /// Color of the titles of read articles
+    pub read_color: String,
+    /// Color of the titles of articles matching an alert keyword
+    pub alert_color: String,
+    /// Marker put before the titles of bookmarked articles
+    pub bookmark_marker: String,
+    /// Marker put before the titles of articles matching an alert keyword
+    pub alert_marker: String,
+    /// Text color of the selected item of the lists
+    pub highlight_fg: String,
+    /// Background color of the selected item of the lists
+    pub highlight_bg: String,
+    /// Text modifiers of the selected item of the lists (e.g. "bold", "underlined")
+    pub highlight_modifiers: Vec<String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            unread_color: String::from("reset"),
+            read_color: String::from("reset"),
+            alert_color: String::from("light_magenta"),
+            bookmark_marker: String::from("★"),
+            alert_marker: String::from("⚑"),
+            highlight_fg: String::from("black"),
+            highlight_bg: String::from("yellow"),
+            highlight_modifiers: vec![String::from("bold")],
+        }
+    }
+}
+
+impl Theme {
+    /// Returns the style of the titles of unread articles
+    pub fn unread_style(&self) -> Style {
+        theme_style(&self.unread_color).add_modifier(Modifier::BOLD)
+    }
+
+    /// Returns the style of the titles of read articles
+    pub fn read_style(&self) -> Style {
+        theme_style(&self.read_color)
+    }
+
+    /// Returns the color of the titles of articles matching an alert keyword
+    pub fn alert_color(&self) -> Color {
+        parse_color(&self.alert_color).unwrap_or(Color::LightMagenta)
+    }
+
+    /// Returns the style of the selected item of the lists
+    pub fn highlight_style(&self) -> Style {
+        let modifiers = self
+            .highlight_modifiers
+            .iter()
+            .filter_map(|modifier| parse_modifier(modifier))
+            .fold(Modifier::empty(), |modifiers, modifier| {
+                modifiers | modifier
+            });
+
+        theme_style(&self.highlight_fg)
+            .patch(match parse_color(&self.highlight_bg) {
+                Some(Color::Reset) | None => Style::default(),
+                Some(color) => Style::default().bg(color),
+            })
+            .add_modifier(modifiers)
+    }
+
+    /// Returns the style of paused RSS feeds
+    pub fn paused_style(&self) -> Style {
+        Style::default().add_modifier(Modifier::DIM | Modifier::CROSSED_OUT)
+    }
+
+    /// Lists the names of the theme colors which are not valid colors
+    pub fn invalid_colors(&self) -> Vec<&'static str> {
+        [
+            ("unread_color", &self.unread_color),
+            ("read_color", &self.read_color),
+            ("alert_color", &self.alert_color),
+            ("highlight_fg", &self.highlight_fg),
+            ("highlight_bg", &self.highlight_bg),
+        ]
+        .into_iter()
+        .filter(|(_setting, color)| parse_color(color).is_none())
+        .map(|(setting, _color)| setting)
+        .collect()
+    }
+
+    /// Lists the highlight modifiers which are not valid modifiers
+    pub fn invalid_modifiers(&self) -> Vec<String> {
+        self.highlight_modifiers
+            .iter()
+            .filter(|modifier| parse_modifier(modifier).is_none())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the legend of the styling conventions, each entry rendered in its own style
+    pub fn legend(&self) -> Vec<Span<'static>> {
+        let separator = || Span::raw("  ");
+        vec![
+            Span::styled("unread", self.unread_style()),
+            separator(),
+            Span::styled("read", self.read_style()),
+            separator(),
+            Span::raw(format!("{} bookmarked", self.bookmark_marker)),
+            separator(),
+            Span::styled(
+                format!("{} alert keyword", self.alert_marker),
+                Style::default().fg(self.alert_color()),
+            ),
+            separator(),
+            Span::styled("paused", self.paused_style()),
+        ]
+    }
+}
+
+/// Parses a theme color, given by name (see [`COLOR_NAMES`]) or as "#rrggbb"
+pub fn parse_color(color: &str) -> Option<Color> {
+    let color = color.trim().to_lowercase();
+    if let Some(hex) = color.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |idx: usize| u8::from_str_radix(hex.get(idx..idx + 2)?, 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    match color.as_str() {
+        "reset" => Some(Color::Reset),
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "gray" => Some(Color::Gray),
+        "dark_gray" => Some(Color::DarkGray),
+        "light_red" => Some(Color::LightRed),
+        "light_green" => Some(Color::LightGreen),
+        "light_yellow" => Some(Color::LightYellow),
+        "light_blue" => Some(Color::LightBlue),
+        "light_magenta" => Some(Color::LightMagenta),
+        "light_cyan" => Some(Color::LightCyan),
+        "white" => Some(Color::White),
+        _ => None,
+    }
+}
+
+/// Parses a theme text modifier (see [`MODIFIER_NAMES`])
+pub fn parse_modifier(modifier: &str) -> Option<Modifier> {
+    match modifier.trim().to_lowercase().as_str() {
+        "bold" => Some(Modifier::BOLD),
+        "dim" => Some(Modifier::DIM),
+        "italic" => Some(Modifier::ITALIC),
+        "underlined" => Some(Modifier::UNDERLINED),
+        "reversed" => Some(Modifier::REVERSED),
+        "crossed_out" => Some(Modifier::CROSSED_OUT),
+        _ => None,
+    }
+}
+
+/// Returns the style with given theme color, the terminal default for invalid colors
+fn theme_style(color: &str) -> Style {
+    match parse_color(color) {
+        Some(Color::Reset) | None => Style::default(),
+        Some(color) => Style::default().fg(color),
+    }
+}
@@ -0,0 +1,45 @@
+//! Applies transforms to the articles fetched on refresh, before they are checked for
+//! duplicates and stored
+//!
+//! Transforms are plain functions changing an article in place (e.g. rewriting AMP links,
+//! hiding articles matching a pattern by setting `hidden`). They run in-process and
+//! synchronously, in the order they were registered, after the built-in transforms enabled in
+//! config. Register them with [`register_article_transform`] before the first refresh.
+//!
+
+use crate::config::get_config;
+use crate::links::clean_url;
+use crate::Articles;
+use std::sync::{Mutex, MutexGuard};
+
+/// Function changing a fetched article in place
+pub type ArticleTransform = Box<dyn Fn(&mut Articles) + Send + Sync>;
+
+static ARTICLE_TRANSFORMS: Mutex<Vec<ArticleTransform>> = Mutex::new(Vec::new());
+
+/// Registers a transform applied to every article fetched from then on
+pub fn register_article_transform(transform: ArticleTransform) {
+    lock_article_transforms().push(transform);
+}
+
+/// Applies the built-in transforms enabled in config, then the registered ones, to given article
+pub fn apply_article_transforms(article: &mut Articles) {
+    if get_config().strip_tracking_params_on_store {
+        strip_tracking_params(article);
+    }
+    for transform in lock_article_transforms().iter() {
+        transform(article);
+    }
+}
+
+/// Built-in transform removing the tracking query parameters (e.g. `utm_source`) from the
+/// article link, enabled by `strip_tracking_params_on_store`
+pub fn strip_tracking_params(article: &mut Articles) {
+    article.article_link = clean_url(&article.article_link);
+}
+
+fn lock_article_transforms() -> MutexGuard<'static, Vec<ArticleTransform>> {
+    ARTICLE_TRANSFORMS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,131 @@
+//! Persists the display preferences chosen in the terminal UI across sessions
+//!
+
+use crate::config::data_path;
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// JSON file name for the display preferences of the terminal UI, in the data folder
+pub const UI_STATE_FILE: &str = "ui_state.json";
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+/// Defines the text styles of the article summary, for legibility
+pub enum SummaryEmphasis {
+    /// Regular summary text
+    #[default]
+    Normal,
+    /// Bold summary text
+    Bold,
+    /// Bold bright white summary text on a black background
+    HighContrast,
+}
+
+impl SummaryEmphasis {
+    /// Returns the next text style, cycling back to normal after high contrast
+    pub fn next(&self) -> SummaryEmphasis {
+        match self {
+            SummaryEmphasis::Normal => SummaryEmphasis::Bold,
+            SummaryEmphasis::Bold => SummaryEmphasis::HighContrast,
+            SummaryEmphasis::HighContrast => SummaryEmphasis::Normal,
+        }
+    }
+
+    /// Returns the name of the text style shown in the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            SummaryEmphasis::Normal => "normal",
+            SummaryEmphasis::Bold => "bold",
+            SummaryEmphasis::HighContrast => "high contrast",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+/// Defines the orders of the RSS feeds list
+pub enum FeedSort {
+    /// Order of the JSON file, as set when adding and moving RSS feeds
+    #[default]
+    Insertion,
+    /// Alphabetical order of the RSS feed names
+    Name,
+    /// RSS feeds with the most unread articles first
+    UnreadCount,
+    /// Most recently refreshed RSS feeds first
+    LastRefreshed,
+    /// Tree of the categories (nested as per their levels), RSS feeds sorted by name within them
+    Category,
+}
+
+impl FeedSort {
+    /// Returns the next order, cycling back to insertion order after category
+    pub fn next(&self) -> FeedSort {
+        match self {
+            FeedSort::Insertion => FeedSort::Name,
+            FeedSort::Name => FeedSort::UnreadCount,
+            FeedSort::UnreadCount => FeedSort::LastRefreshed,
+            FeedSort::LastRefreshed => FeedSort::Category,
+            FeedSort::Category => FeedSort::Insertion,
+        }
+    }
+
+    /// Returns the name of the order shown in the status bar and the RSS feeds pane title
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeedSort::Insertion => "insertion order",
+            FeedSort::Name => "name",
+            FeedSort::UnreadCount => "unread count",
+            FeedSort::LastRefreshed => "last refreshed",
+            FeedSort::Category => "category",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+#[serde(default)]
+/// Defines the display preferences of the terminal UI
+pub struct UiState {
+    /// Text style of the article summary
+    pub summary_emphasis: SummaryEmphasis,
+    /// Order of the RSS feeds list
+    pub feed_sort: FeedSort,
+    /// Flag for hiding the summary pane, giving its width to the RSS feeds and articles lists
+    pub summary_collapsed: bool,
+    /// Time at which the terminal UI was last quit, for summarizing the articles added since then
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_session_at: Option<DateTime<Utc>>,
+}
+
+/// Reads the display preferences, falling back to defaults when missing or invalid
+pub fn read_ui_state() -> UiState {
+    fs::read_to_string(data_path(UI_STATE_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Stores the display preferences, logging failures instead of interrupting the terminal UI
+pub fn write_ui_state(ui_state: &UiState) {
+    let result = serde_json::to_vec(ui_state)
+        .map_err(|err| err.to_string())
+        .and_then(|content| {
+            fs::write(data_path(UI_STATE_FILE), content).map_err(|err| err.to_string())
+        });
+
+    if let Err(err) = result {
+        warn!("Unable to store the display preferences: {}", err);
+    }
+}
+
+/// Stores the display preferences unless they are already on disk, returning whether they were
+/// written
+pub fn flush_ui_state(ui_state: &UiState) -> bool {
+    if read_ui_state() == *ui_state {
+        return false;
+    }
+    write_ui_state(ui_state);
+    true
+}
@@ -0,0 +1,215 @@
+//! Reads the update frequency hints advertised by RSS feeds, for refreshing them no more often
+//! than their publisher asks for
+//!
+//! Hints are read from the `<ttl>` element (minutes the channel can be cached), the
+//! `<sy:updatePeriod>` and `<sy:updateFrequency>` elements of the syndication module, and the
+//! `<skipHours>` and `<skipDays>` elements (hours in GMT and days during which the channel is
+//! not updated).
+//!
+
+use chrono::{DateTime, Duration, Timelike, Utc};
+use rss::extension::syndication::UpdatePeriod;
+use rss::Channel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+/// Defines the update frequency hints of an RSS feed
+pub struct UpdateHints {
+    /// Minutes the channel can be cached before refreshing it (`<ttl>`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_minutes: Option<u32>,
+    /// Minutes between two updates of the channel (`<sy:updatePeriod>` / `<sy:updateFrequency>`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_period_minutes: Option<u32>,
+    /// Hours (0 to 23, GMT) during which the channel is not updated (`<skipHours>`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip_hours: Vec<u32>,
+    /// Days (e.g. "Saturday") during which the channel is not updated (`<skipDays>`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip_days: Vec<String>,
+}
+
+impl UpdateHints {
+    /// Reads the update frequency hints of given channel, `None` when it has none
+    pub fn from_channel(channel: &Channel) -> Option<UpdateHints> {
+        let ttl_minutes = channel
+            .ttl()
+            .and_then(|ttl| ttl.trim().parse::<u32>().ok())
+            .filter(|ttl| *ttl > 0);
+
+        let update_period_minutes = channel.syndication_ext().map(|syndication| {
+            let period_minutes = match syndication.period() {
+                UpdatePeriod::Hourly => 60,
+                UpdatePeriod::Daily => 60 * 24,
+                UpdatePeriod::Weekly => 60 * 24 * 7,
+                UpdatePeriod::Monthly => 60 * 24 * 30,
+                UpdatePeriod::Yearly => 60 * 24 * 365,
+            };
+            period_minutes / syndication.frequency().max(1)
+        });
+
+        let mut skip_hours: Vec<u32> = channel
+            .skip_hours()
+            .iter()
+            .filter_map(|hour| hour.trim().parse::<u32>().ok())
+            .map(|hour| hour % 24)
+            .collect();
+        skip_hours.sort_unstable();
+        skip_hours.dedup();
+
+        let skip_days: Vec<String> = channel
+            .skip_days()
+            .iter()
+            .map(|day| day.trim().to_string())
+            .filter(|day| !day.is_empty())
+            .collect();
+
+        let update_hints = UpdateHints {
+            ttl_minutes,
+            update_period_minutes,
+            skip_hours,
+            skip_days,
+        };
+        match update_hints == UpdateHints::default() {
+            true => None,
+            false => Some(update_hints),
+        }
+    }
+
+    /// Returns the minimum time between two refreshes, the longest of the TTL and update period
+    pub fn min_refresh_interval(&self) -> Option<Duration> {
+        self.ttl_minutes
+            .max(self.update_period_minutes)
+            .map(|minutes| Duration::minutes(minutes as i64))
+    }
+
+    /// Checks whether a scheduled refresh of the RSS feed is due at given time
+    ///
+    /// Refreshes are not due before the minimum refresh interval has passed since the last
+    /// refresh, nor during the skipped hours and days.
+    pub fn is_refresh_due(&self, last_refresh: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        let interval_passed = match (last_refresh, self.min_refresh_interval()) {
+            (Some(last_refresh), Some(interval)) => now - last_refresh >= interval,
+            _ => true,
+        };
+        let day = now.format("%A").to_string();
+
+        interval_passed
+            && !self.skip_hours.contains(&now.hour())
+            && !self
+                .skip_days
+                .iter()
+                .any(|skip_day| skip_day.eq_ignore_ascii_case(&day))
+    }
+
+    /// Returns the hints as shown in the RSS feed details, e.g. "every 60 min (ttl), skips
+    /// Saturday, Sunday"
+    pub fn describe(&self) -> String {
+        let mut hints: Vec<String> = Vec::new();
+        if let Some(ttl_minutes) = self.ttl_minutes {
+            hints.push(format!("every {} min (ttl)", ttl_minutes));
+        }
+        if let Some(update_period_minutes) = self.update_period_minutes {
+            hints.push(format!("updated every {} min", update_period_minutes));
+        }
+        if !self.skip_hours.is_empty() {
+            let skip_hours: Vec<String> = self.skip_hours.iter().map(|h| h.to_string()).collect();
+            hints.push(format!("skips hours {} (GMT)", skip_hours.join(", ")));
+        }
+        if !self.skip_days.is_empty() {
+            hints.push(format!("skips {}", self.skip_days.join(", ")));
+        }
+        hints.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn read_channel(channel_elements: &str) -> Channel {
+        let rss_xml = format!(
+            r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:sy="http://purl.org/rss/1.0/modules/syndication/">
+<channel>
+<title>Test feed</title>
+<link>https://example.com</link>
+<description>Test feed</description>
+{}
+</channel>
+</rss>"#,
+            channel_elements
+        );
+        Channel::read_from(rss_xml.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn reads_no_hints() {
+        assert_eq!(UpdateHints::from_channel(&read_channel("")), None);
+    }
+
+    #[test]
+    fn reads_ttl() {
+        let update_hints = UpdateHints::from_channel(&read_channel("<ttl>90</ttl>")).unwrap();
+        assert_eq!(update_hints.ttl_minutes, Some(90));
+        assert_eq!(
+            update_hints.min_refresh_interval(),
+            Some(Duration::minutes(90))
+        );
+    }
+
+    #[test]
+    fn reads_syndication_update_period() {
+        let update_hints = UpdateHints::from_channel(&read_channel(
+            "<sy:updatePeriod>daily</sy:updatePeriod><sy:updateFrequency>4</sy:updateFrequency>",
+        ))
+        .unwrap();
+        assert_eq!(update_hints.update_period_minutes, Some(360));
+    }
+
+    #[test]
+    fn reads_skip_hours_and_days() {
+        let update_hints = UpdateHints::from_channel(&read_channel(
+            "<skipHours><hour>23</hour><hour>0</hour><hour>24</hour></skipHours>
+<skipDays><day>Saturday</day><day>Sunday</day></skipDays>",
+        ))
+        .unwrap();
+        assert_eq!(update_hints.skip_hours, vec![0, 23]);
+        assert_eq!(update_hints.skip_days, vec!["Saturday", "Sunday"]);
+    }
+
+    #[test]
+    fn refresh_is_due_after_the_longest_interval() {
+        let update_hints = UpdateHints {
+            ttl_minutes: Some(60),
+            update_period_minutes: Some(120),
+            ..UpdateHints::default()
+        };
+        let last_refresh = Utc.with_ymd_and_hms(2024, 1, 3, 10, 0, 0).unwrap();
+
+        assert!(update_hints.is_refresh_due(None, last_refresh));
+        assert!(
+            !update_hints.is_refresh_due(Some(last_refresh), last_refresh + Duration::minutes(90))
+        );
+        assert!(
+            update_hints.is_refresh_due(Some(last_refresh), last_refresh + Duration::minutes(120))
+        );
+    }
+
+    #[test]
+    fn refresh_is_not_due_during_skipped_hours_and_days() {
+        let update_hints = UpdateHints {
+            skip_hours: vec![3],
+            skip_days: vec![String::from("saturday")],
+            ..UpdateHints::default()
+        };
+
+        // 2024-01-03 is a Wednesday, 2024-01-06 a Saturday
+        let wednesday = Utc.with_ymd_and_hms(2024, 1, 3, 10, 0, 0).unwrap();
+        assert!(update_hints.is_refresh_due(None, wednesday));
+        assert!(!update_hints.is_refresh_due(None, wednesday.with_hour(3).unwrap()));
+        let saturday = Utc.with_ymd_and_hms(2024, 1, 6, 10, 0, 0).unwrap();
+        assert!(!update_hints.is_refresh_due(None, saturday));
+    }
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use byte_bite::archive::{archive_article, archive_read_articles, read_archive_db, search_archive};
+use byte_bite::{add_feed, mark_articles_read, read_articles_db, refresh_rss_feed, Articles};
+use common::{block_on, numbered_rss_document, setup, store_articles, stored_article, TestServer};
+use std::time::Duration;
+
+fn feed_articles() -> Vec<Articles> {
+    read_articles_db()
+        .into_iter()
+        .filter(|r| r.rss_id == 1)
+        .collect()
+}
+
+fn article_id(link: &str) -> usize {
+    feed_articles()
+        .into_iter()
+        .find(|r| r.article_link == link)
+        .unwrap()
+        .article_id
+}
+
+fn archived_links() -> Vec<String> {
+    read_archive_db()
+        .into_iter()
+        .map(|r| r.article_link)
+        .collect()
+}
+
+#[test]
+fn archived_articles_are_moved_and_not_added_back_on_refresh() {
+    let _test_lock = setup("archive", "");
+
+    block_on(async {
+        let server =
+            TestServer::start(Duration::ZERO, |_path| Some(numbered_rss_document("a", 4))).await;
+        add_feed("Test", "Test feed", &server.url("/feed.xml"))
+            .await
+            .unwrap();
+        assert_eq!(feed_articles().len(), 4);
+
+        assert!(archive_article(article_id("https://example.com/a/0")));
+        assert!(!archive_article(9999));
+        mark_articles_read(&[
+            article_id("https://example.com/a/1"),
+            article_id("https://example.com/a/2"),
+        ]);
+        assert_eq!(archive_read_articles(1), 2);
+        assert_eq!(archive_read_articles(1), 0);
+
+        assert_eq!(
+            archived_links(),
+            vec![
+                "https://example.com/a/0",
+                "https://example.com/a/1",
+                "https://example.com/a/2",
+            ]
+        );
+        let remaining: Vec<String> = feed_articles()
+            .into_iter()
+            .map(|r| r.article_link)
+            .collect();
+        assert_eq!(remaining, vec!["https://example.com/a/3"]);
+
+        assert_eq!(refresh_rss_feed(1).await.unwrap(), 0);
+        assert_eq!(feed_articles().len(), 1);
+        assert_eq!(search_archive("A ARTICLE 1").len(), 1);
+    });
+}
+
+#[test]
+fn articles_already_archived_are_not_archived_twice() {
+    let _test_lock = setup("archive", "");
+    store_articles(&[
+        stored_article(1, 0, "2024-01-01T12:00:00Z"),
+        stored_article(2, 0, "2024-01-02T12:00:00Z"),
+    ]);
+    assert!(archive_article(1));
+
+    // The same article stored again, e.g. restored from a backup
+    store_articles(&[stored_article(1, 0, "2024-01-01T12:00:00Z")]);
+    assert!(archive_article(1));
+    assert!(archive_article(2));
+
+    assert_eq!(
+        archived_links(),
+        vec![
+            "https://example.com/articles/1",
+            "https://example.com/articles/2",
+        ]
+    );
+    assert!(read_articles_db().iter().all(|r| r.article_id == 0));
+}
@@ -0,0 +1,273 @@
+//! Shared setup of the integration tests
+//!
+//! Each test binary points the data folder (`data_dir` in config) to its own temporary folder,
+//! seeded with the welcome feed and its article (as shipped in "data"), and runs its tests one
+//! at a time as they share the JSON files. RSS feeds are served by a local HTTP server.
+//!
+
+#![allow(dead_code)]
+
+use byte_bite::config::load_config;
+use std::fs;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+static TEST_LOCK: Mutex<()> = Mutex::new(());
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Loads the config of the test binary with given extra entries (JSON object fields, e.g.
+/// `"max_concurrency": 4`), once, and resets the data folder to the welcome feed only
+///
+/// The returned guard keeps the other tests of the binary waiting until dropped.
+pub fn setup(test_binary: &str, config_entries: &str) -> MutexGuard<'static, ()> {
+    let test_lock = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+
+    let data_dir = DATA_DIR.get_or_init(|| {
+        let test_dir = std::env::temp_dir().join(format!(
+            "byte_bite_test_{}_{}",
+            test_binary,
+            std::process::id()
+        ));
+        let data_dir = test_dir.join("data");
+        fs::create_dir_all(&data_dir).unwrap();
+
+        let config_path = test_dir.join("config.json");
+        let separator = if config_entries.trim().is_empty() {
+            ""
+        } else {
+            ","
+        };
+        fs::write(
+            &config_path,
+            format!(
+                "{{\"data_dir\": {:?}, \"log_file\": {:?}{} {}}}",
+                data_dir.to_string_lossy(),
+                test_dir.join("byte_bite.log").to_string_lossy(),
+                separator,
+                config_entries
+            ),
+        )
+        .unwrap();
+        load_config(Some(&config_path.to_string_lossy())).unwrap();
+        data_dir
+    });
+
+    reset_data_dir(data_dir);
+    test_lock
+}
+
+/// Runs given future to completion on a new runtime, for async tests holding the test lock
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+/// Returns the path of given file in the data folder of the test binary
+pub fn data_file(file_name: &str) -> PathBuf {
+    DATA_DIR.get().expect("setup not called").join(file_name)
+}
+
+/// Returns the path of given fixture file
+pub fn fixture_path(file_name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        file_name
+    )
+}
+
+fn reset_data_dir(data_dir: &PathBuf) {
+    fs::remove_dir_all(data_dir).unwrap();
+    fs::create_dir_all(data_dir).unwrap();
+    let seed_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data");
+    for file_name in ["rss_db.json", "article_db.json"] {
+        fs::copy(seed_dir.join(file_name), data_dir.join(file_name)).unwrap();
+    }
+}
+
+/// Builds an unread article of given RSS feed as stored in the articles database, published
+/// at given time (RFC 3339)
+pub fn stored_article(article_id: usize, rss_id: usize, pub_date: &str) -> serde_json::Value {
+    serde_json::json!({
+        "article_id": article_id,
+        "rss_id": rss_id,
+        "title": format!("Article {}", article_id),
+        "summary": format!("Summary of article {}", article_id),
+        "article_link": format!("https://example.com/articles/{}", article_id),
+        "author": "",
+        "pub_date": pub_date,
+        "created_at": pub_date
+    })
+}
+
+/// Checks whether given article is marked as read in the articles database
+pub fn is_read(article_id: usize) -> bool {
+    byte_bite::read_articles_db()
+        .into_iter()
+        .find(|r| r.article_id == article_id)
+        .unwrap()
+        .read
+}
+
+/// Stores given articles in the articles database, after the welcome article
+pub fn store_articles(articles: &[serde_json::Value]) {
+    let mut articles_list: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(data_file("article_db.json")).unwrap()).unwrap();
+    articles_list.extend(articles.iter().cloned());
+    fs::write(
+        data_file("article_db.json"),
+        serde_json::to_string(&articles_list).unwrap(),
+    )
+    .unwrap();
+}
+
+/// Builds an RSS feed document with given items (title, link, publishing date)
+pub fn rss_document(items: &[(String, String, String)]) -> String {
+    let items: String = items
+        .iter()
+        .map(|(title, link, pub_date)| {
+            format!(
+                "<item><title>{}</title><link>{}</link><description>Summary of {}</description>\
+                 <pubDate>{}</pubDate></item>",
+                title, link, title, pub_date
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Test feed</title>\
+         <link>https://example.com</link><description>Test feed</description>{}</channel></rss>",
+        items
+    )
+}
+
+/// Builds an RSS feed document with `num_items` items linking to `https://example.com/<prefix>/<n>`
+pub fn numbered_rss_document(prefix: &str, num_items: usize) -> String {
+    let items: Vec<(String, String, String)> = (0..num_items)
+        .map(|n| {
+            (
+                format!("{} article {}", prefix, n),
+                format!("https://example.com/{}/{}", prefix, n),
+                String::from("Mon, 01 Jan 2024 12:00:00 GMT"),
+            )
+        })
+        .collect();
+    rss_document(&items)
+}
+
+/// Requests received by the test server
+#[derive(Default)]
+pub struct ServerStats {
+    active: AtomicUsize,
+    /// Highest number of requests handled at the same time
+    pub max_active: AtomicUsize,
+    /// Path and time of arrival of each request, in order of arrival
+    pub requests: Mutex<Vec<(String, Instant)>>,
+}
+
+/// Local HTTP server answering each request with the body returned by its handler
+pub struct TestServer {
+    /// Address the server listens on
+    pub addr: SocketAddr,
+    /// Requests received so far
+    pub stats: Arc<ServerStats>,
+}
+
+impl TestServer {
+    /// Starts a server answering after `response_delay`, with the body returned by `handler`
+    /// for the request path (status 404 when `None`)
+    pub async fn start(
+        response_delay: Duration,
+        handler: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> TestServer {
+        TestServer::start_with(response_delay, true, handler).await
+    }
+
+    /// Starts a server like [`TestServer::start`], but streaming the bodies in chunks without
+    /// announcing their length (no `Content-Length`, the body ends with the connection)
+    pub async fn start_streaming(
+        response_delay: Duration,
+        handler: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> TestServer {
+        TestServer::start_with(response_delay, false, handler).await
+    }
+
+    async fn start_with(
+        response_delay: Duration,
+        content_length: bool,
+        handler: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> TestServer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let stats = Arc::new(ServerStats::default());
+        let handler = Arc::new(handler);
+
+        let server_stats = Arc::clone(&stats);
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let stats = Arc::clone(&server_stats);
+                let handler = Arc::clone(&handler);
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 4096];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match stream.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buffer[..n]),
+                        }
+                    }
+                    let request = String::from_utf8_lossy(&request);
+                    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+                    let active = stats.active.fetch_add(1, Ordering::SeqCst) + 1;
+                    stats.max_active.fetch_max(active, Ordering::SeqCst);
+                    stats
+                        .requests
+                        .lock()
+                        .unwrap()
+                        .push((path.clone(), Instant::now()));
+                    tokio::time::sleep(response_delay).await;
+                    stats.active.fetch_sub(1, Ordering::SeqCst);
+
+                    let (status, body) = match handler(&path) {
+                        Some(body) => ("200 OK", body),
+                        None => ("404 Not Found", String::new()),
+                    };
+                    let length_header = match content_length {
+                        true => format!("Content-Length: {}\r\n", body.len()),
+                        false => String::new(),
+                    };
+                    let head = format!(
+                        "HTTP/1.1 {}\r\nContent-Type: application/rss+xml\r\n{}\
+                         Connection: close\r\n\r\n",
+                        status, length_header
+                    );
+                    if stream.write_all(head.as_bytes()).await.is_err() {
+                        return;
+                    }
+                    for chunk in body.as_bytes().chunks(64 * 1024) {
+                        // The client may give up reading before the end of the body
+                        if stream.write_all(chunk).await.is_err() {
+                            return;
+                        }
+                    }
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        TestServer { addr, stats }
+    }
+
+    /// Returns the URL of given path on the server
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use byte_bite::consistency::check_db_consistency;
+use byte_bite::read_articles_db;
+use common::{setup, store_articles, stored_article};
+use std::collections::BTreeSet;
+
+fn store_orphaned_articles() {
+    // Only the welcome feed (RSS id 0) exists, RSS ids 4 and 9 were removed by hand
+    store_articles(&[
+        stored_article(1, 0, "2024-01-01T12:00:00Z"),
+        stored_article(2, 4, "2024-01-02T12:00:00Z"),
+        stored_article(3, 9, "2024-01-03T12:00:00Z"),
+        stored_article(4, 4, "2024-01-04T12:00:00Z"),
+    ]);
+}
+
+fn article_ids() -> Vec<usize> {
+    read_articles_db().iter().map(|r| r.article_id).collect()
+}
+
+#[test]
+fn reports_orphaned_articles_without_removing_them() {
+    let _test_lock = setup("consistency", "");
+    store_orphaned_articles();
+
+    let report = check_db_consistency(false);
+    assert_eq!(report.num_orphaned, 3);
+    assert_eq!(report.missing_rss_ids, BTreeSet::from([4, 9]));
+    assert_eq!(report.num_removed, 0);
+    assert_eq!(
+        report.summary(),
+        "Found 3 orphaned articles (RSS ids 4, 9), none removed"
+    );
+    assert_eq!(article_ids(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn repair_removes_orphaned_articles() {
+    let _test_lock = setup("consistency", "");
+    store_orphaned_articles();
+
+    let report = check_db_consistency(true);
+    assert_eq!(report.num_orphaned, 3);
+    assert_eq!(report.num_removed, 3);
+    assert_eq!(article_ids(), vec![0, 1]);
+
+    let report = check_db_consistency(true);
+    assert_eq!(report.num_orphaned, 0);
+    assert_eq!(
+        report.summary(),
+        "Found no orphaned articles, the databases agree"
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use byte_bite::feed_layers::read_feed_tombstones;
+use byte_bite::{move_rss_feed, read_rss_db, reset_db, update_rss_db, RSSFeed};
+use common::{data_file, fixture_path, setup};
+use std::fs;
+use tui::widgets::ListState;
+
+fn test_setup() -> std::sync::MutexGuard<'static, ()> {
+    setup(
+        "feed_layers",
+        &format!("\"base_feeds_file\": {:?}", fixture_path("base_feeds.json")),
+    )
+}
+
+fn rss_ids() -> Vec<usize> {
+    read_rss_db().iter().map(|r| r.rss_id).collect()
+}
+
+fn selected(position: usize) -> ListState {
+    let mut list_state = ListState::default();
+    list_state.select(Some(position));
+    list_state
+}
+
+#[test]
+fn base_feeds_are_listed_after_the_database_feeds() {
+    let _test_lock = test_setup();
+
+    assert_eq!(rss_ids(), vec![0, 1000, 1001, 1002]);
+}
+
+#[test]
+fn moving_a_base_feed_keeps_the_order_without_copying_it() {
+    let _test_lock = test_setup();
+
+    move_rss_feed(&mut selected(3), true);
+    assert_eq!(rss_ids(), vec![0, 1000, 1002, 1001]);
+
+    let stored: Vec<RSSFeed> =
+        serde_json::from_str(&fs::read_to_string(data_file("rss_db.json")).unwrap()).unwrap();
+    assert_eq!(stored.len(), 1);
+}
+
+#[test]
+fn deleted_base_feeds_stay_deleted_until_reset() {
+    let _test_lock = test_setup();
+
+    move_rss_feed(&mut selected(3), true);
+    update_rss_db(&mut selected(1));
+    assert_eq!(rss_ids(), vec![0, 1002, 1001]);
+    assert!(read_feed_tombstones().contains(&1000));
+
+    reset_db().unwrap();
+    assert_eq!(rss_ids(), vec![0, 1000, 1001, 1002]);
+    assert!(read_feed_tombstones().is_empty());
+}
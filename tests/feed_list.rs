@@ -0,0 +1,65 @@
+mod common;
+
+use byte_bite::feed_list::{add_feeds_from_file, import_newsboat};
+use byte_bite::read_rss_db;
+use common::{block_on, fixture_path, setup};
+
+#[test]
+fn adds_the_feeds_of_a_feed_list_file() {
+    let _test_lock = setup("feed_list", "");
+
+    let report = block_on(add_feeds_from_file(&fixture_path("feed_list.txt"))).unwrap();
+    assert_eq!(
+        report.added,
+        vec![
+            "http://127.0.0.1:9/rust.xml",
+            "http://127.0.0.1:9/imported.xml"
+        ]
+    );
+    let failed_lines: Vec<usize> = report.failed.iter().map(|r| r.line_number).collect();
+    assert_eq!(failed_lines, vec![5, 6, 7]);
+    assert_eq!(report.failed[1].reason, "RSS feed already present");
+
+    assert_eq!(
+        listed_feeds(),
+        vec![
+            "Technology|Rust blog",
+            "Imported|http://127.0.0.1:9/imported.xml",
+        ]
+    );
+}
+
+#[test]
+fn imports_the_feeds_of_a_newsboat_urls_file() {
+    let _test_lock = setup("feed_list", "");
+
+    let report = block_on(import_newsboat(&fixture_path("newsboat_urls"))).unwrap();
+    assert_eq!(
+        report.added,
+        vec![
+            "http://127.0.0.1:9/rust.xml",
+            "http://127.0.0.1:9/named.xml",
+            "http://127.0.0.1:9/untagged.xml"
+        ]
+    );
+    let failed_lines: Vec<usize> = report.failed.iter().map(|r| r.line_number).collect();
+    assert_eq!(failed_lines, vec![7, 8, 9]);
+
+    assert_eq!(
+        listed_feeds(),
+        vec![
+            "tech news|http://127.0.0.1:9/rust.xml",
+            "news|Named feed",
+            "Imported|http://127.0.0.1:9/untagged.xml",
+        ]
+    );
+}
+
+/// Lists the RSS feeds added (after the welcome feed) as "category|name"
+fn listed_feeds() -> Vec<String> {
+    read_rss_db()
+        .into_iter()
+        .skip(1)
+        .map(|r| format!("{}|{}", r.category, r.name))
+        .collect()
+}
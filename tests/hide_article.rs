@@ -0,0 +1,65 @@
+mod common;
+
+use byte_bite::{add_feed, hide_article, read_articles_db, refresh_rss_feed, Articles};
+use common::{block_on, data_file, rss_document, setup, TestServer};
+use std::fs;
+use std::time::Duration;
+
+fn feed_articles() -> Vec<Articles> {
+    read_articles_db()
+        .into_iter()
+        .filter(|r| r.rss_id == 1)
+        .collect()
+}
+
+#[test]
+fn hidden_articles_stay_hidden_after_refresh() {
+    let _test_lock = setup("hide_article", "");
+
+    block_on(async {
+        let server = TestServer::start(Duration::ZERO, |_path| {
+            Some(rss_document(&[
+                (
+                    String::from("Kept"),
+                    String::from("https://example.com/kept"),
+                    String::from("Mon, 01 Jan 2024 12:00:00 GMT"),
+                ),
+                (
+                    String::from("Hidden"),
+                    String::from("https://example.com/hidden"),
+                    String::from("Mon, 01 Jan 2024 13:00:00 GMT"),
+                ),
+            ]))
+        })
+        .await;
+        add_feed("Test", "Test feed", &server.url("/feed.xml"))
+            .await
+            .unwrap();
+        assert_eq!(feed_articles().len(), 2);
+
+        let hidden = feed_articles()
+            .into_iter()
+            .find(|r| r.title == "Hidden")
+            .unwrap();
+        hide_article(hidden.article_id);
+        assert_eq!(refresh_rss_feed(1).await.unwrap(), 0);
+        let articles = feed_articles();
+        assert_eq!(articles.len(), 2);
+        assert!(articles.iter().any(|r| r.title == "Hidden" && r.hidden));
+
+        // Once the hidden article is gone from the database, it is still not added back
+        let articles: Vec<Articles> = read_articles_db()
+            .into_iter()
+            .filter(|r| r.article_id != hidden.article_id)
+            .collect();
+        fs::write(
+            data_file("article_db.json"),
+            serde_json::to_string(&articles).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(refresh_rss_feed(1).await.unwrap(), 0);
+        let articles = feed_articles();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Kept");
+    });
+}
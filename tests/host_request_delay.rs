@@ -0,0 +1,45 @@
+mod common;
+
+use byte_bite::config::get_config;
+use byte_bite::feed_layers::write_feed_layers;
+use byte_bite::fetcher::refresh_rss_feeds;
+use byte_bite::{read_rss_db, RSSFeed};
+use common::{block_on, numbered_rss_document, setup, TestServer};
+use std::time::Duration;
+
+#[test]
+fn requests_to_the_same_host_are_spaced_by_the_host_delay() {
+    let _test_lock = setup(
+        "host_request_delay",
+        "\"max_concurrency\": 4, \"host_request_delay_ms\": 200",
+    );
+
+    block_on(async {
+        let server = TestServer::start(Duration::ZERO, |path| {
+            Some(numbered_rss_document(path.trim_start_matches('/'), 1))
+        })
+        .await;
+        let mut rss_feed_list = read_rss_db();
+        for rss_id in 1..=4 {
+            let url = server.url(&format!("/feed{}", rss_id));
+            rss_feed_list.push(RSSFeed::new(rss_id, "Test", "Test feed", &url));
+        }
+        write_feed_layers(&rss_feed_list);
+
+        let results = refresh_rss_feeds(&[1, 2, 3, 4]).await;
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+        let requests = server.stats.requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 4);
+        let host_delay = Duration::from_millis(get_config().host_request_delay_ms);
+        for pair in requests.windows(2) {
+            let spacing = pair[1].1 - pair[0].1;
+            // Requests are timed on arrival, which may be a few milliseconds after being sent
+            assert!(
+                spacing >= host_delay - Duration::from_millis(20),
+                "{:?}",
+                spacing
+            );
+        }
+    });
+}
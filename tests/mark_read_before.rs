@@ -0,0 +1,50 @@
+mod common;
+
+use byte_bite::{mark_read_before, read_articles_db};
+use chrono::{DateTime, Utc};
+use common::{setup, store_articles, stored_article};
+use std::collections::BTreeSet;
+
+fn read_article_ids() -> BTreeSet<usize> {
+    read_articles_db()
+        .into_iter()
+        .filter(|r| r.read)
+        .map(|r| r.article_id)
+        .collect()
+}
+
+#[test]
+fn marks_only_articles_published_before_the_cutoff() {
+    let _test_lock = setup("mark_read_before", "");
+    store_articles(&[
+        stored_article(1, 1, "2024-03-10T11:59:59Z"),
+        stored_article(2, 1, "2024-03-10T12:00:00Z"),
+        stored_article(3, 1, "2024-03-10T12:00:01Z"),
+    ]);
+    let cutoff: DateTime<Utc> = "2024-03-10T12:00:00Z".parse().unwrap();
+
+    assert_eq!(mark_read_before(Some(1), cutoff), 1);
+    assert_eq!(read_article_ids(), BTreeSet::from([1]));
+    // Articles already read are not counted again
+    assert_eq!(mark_read_before(Some(1), cutoff), 0);
+}
+
+#[test]
+fn leaves_bookmarked_articles_and_other_feeds_untouched() {
+    let _test_lock = setup("mark_read_before", "");
+    let mut bookmarked = stored_article(2, 1, "2024-03-01T12:00:00Z");
+    bookmarked["bookmarked"] = serde_json::json!(true);
+    store_articles(&[
+        stored_article(1, 1, "2024-03-01T12:00:00Z"),
+        bookmarked,
+        stored_article(3, 2, "2024-03-01T12:00:00Z"),
+    ]);
+    let cutoff: DateTime<Utc> = "2024-03-10T12:00:00Z".parse().unwrap();
+
+    assert_eq!(mark_read_before(Some(1), cutoff), 1);
+    assert_eq!(read_article_ids(), BTreeSet::from([1]));
+
+    // The welcome article is older than the cutoff too
+    assert_eq!(mark_read_before(None, cutoff), 2);
+    assert_eq!(read_article_ids(), BTreeSet::from([0, 1, 3]));
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use byte_bite::browser::mark_opened_articles_read;
+use byte_bite::toggle_article_read;
+use common::{is_read, setup, store_articles, stored_article};
+
+#[test]
+fn opening_articles_leaves_them_unread_when_turned_off() {
+    let _test_lock = setup("mark_read_on_open", "\"mark_read_on_open\": false");
+    store_articles(&[stored_article(1, 0, "2024-01-01T12:00:00Z")]);
+
+    mark_opened_articles_read(&[1]);
+    assert!(!is_read(1));
+
+    // Marking read by hand still works
+    assert!(toggle_article_read(1));
+    assert!(is_read(1));
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use byte_bite::{add_feed, read_articles_db, refresh_rss_feed};
+use common::{block_on, rss_document, setup, TestServer};
+use std::time::Duration;
+
+fn article_titles() -> Vec<String> {
+    let mut titles: Vec<String> = read_articles_db().into_iter().map(|r| r.title).collect();
+    titles.sort();
+    titles
+}
+
+#[test]
+fn evicted_articles_are_not_added_back_on_refresh() {
+    let _test_lock = setup("max_articles", "\"max_articles\": 3");
+
+    block_on(async {
+        let server = TestServer::start(Duration::ZERO, |_path| {
+            let items: Vec<(String, String, String)> = ["Mon", "Tue", "Wed", "Thu", "Fri"]
+                .iter()
+                .zip(1..=5)
+                .map(|(weekday, n)| {
+                    (
+                        format!("Article {}", n),
+                        format!("https://example.com/{}", n),
+                        format!("{}, 0{} Jan 2024 12:00:00 GMT", weekday, n),
+                    )
+                })
+                .collect();
+            Some(rss_document(&items))
+        })
+        .await;
+        add_feed("Test", "Test feed", &server.url("/feed.xml"))
+            .await
+            .unwrap();
+        // The welcome article is the oldest one, and goes first
+        assert_eq!(
+            article_titles(),
+            vec!["Article 3", "Article 4", "Article 5"]
+        );
+
+        assert_eq!(refresh_rss_feed(1).await.unwrap(), 0);
+        assert_eq!(
+            article_titles(),
+            vec!["Article 3", "Article 4", "Article 5"]
+        );
+    });
+}
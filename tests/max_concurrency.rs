@@ -0,0 +1,53 @@
+mod common;
+
+use byte_bite::config::{data_path, get_config};
+use byte_bite::db_writer::count_db_writes;
+use byte_bite::opml::import_opml;
+use byte_bite::read_articles_db;
+use common::{block_on, fixture_path, numbered_rss_document, setup, TestServer};
+use std::fs;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+#[test]
+fn importing_100_feeds_fetches_at_most_max_concurrency_feeds_at_a_time() {
+    let _test_lock = setup(
+        "max_concurrency",
+        "\"max_concurrency\": 4, \"host_request_delay_ms\": 0",
+    );
+
+    block_on(async {
+        let server = TestServer::start(Duration::from_millis(20), |path| {
+            Some(numbered_rss_document(path.trim_start_matches('/'), 2))
+        })
+        .await;
+        // The outlines of the fixture point to "SERVER", replaced with the test server address
+        let opml = fs::read_to_string(fixture_path("large_import.opml"))
+            .unwrap()
+            .replace("SERVER", &server.addr.to_string());
+        let opml_path = data_path("large_import.opml");
+        fs::write(&opml_path, opml).unwrap();
+        let article_db_writes = count_db_writes(&data_path("article_db.json"));
+        let rss_db_writes = count_db_writes(&data_path("rss_db.json"));
+
+        let import_report = import_opml(&opml_path).await.unwrap();
+        assert_eq!(import_report.imported.len(), 100);
+        assert_eq!(read_articles_db().len(), 1 + 100 * 2);
+        assert_eq!(server.stats.requests.lock().unwrap().len(), 100);
+
+        let max_active = server.stats.max_active.load(Ordering::SeqCst);
+        assert!(max_active <= get_config().max_concurrency, "{}", max_active);
+        assert!(max_active > 1, "RSS feeds were fetched one at a time");
+
+        // The new RSS feeds are stored at once, and so are the articles and the refresh times
+        // (e.g. last refresh) of the refresh
+        assert_eq!(
+            count_db_writes(&data_path("article_db.json")) - article_db_writes,
+            1
+        );
+        assert_eq!(
+            count_db_writes(&data_path("rss_db.json")) - rss_db_writes,
+            2
+        );
+    });
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use byte_bite::error_db::ErrorCodes;
+use byte_bite::feed_status::read_feed_error;
+use byte_bite::{add_feed, read_articles_db, refresh_rss_feed};
+use common::{block_on, numbered_rss_document, setup, TestServer};
+use std::time::Duration;
+
+/// Builds a valid feed document, padded to 2 MB with an XML comment
+fn large_rss_document() -> String {
+    format!(
+        "{}<!-- {} -->",
+        numbered_rss_document("large", 5),
+        "x".repeat(2 * 1024 * 1024)
+    )
+}
+
+#[test]
+fn responses_larger_than_the_cap_are_given_up() {
+    let _test_lock = setup("max_response_size", "\"max_response_size_mb\": 1");
+
+    block_on(async {
+        let server = TestServer::start(Duration::ZERO, |path| match path {
+            "/large.xml" => Some(large_rss_document()),
+            _ => Some(numbered_rss_document("small", 5)),
+        })
+        .await;
+        add_feed("Test", "Large feed", &server.url("/large.xml"))
+            .await
+            .unwrap();
+        add_feed("Test", "Small feed", &server.url("/small.xml"))
+            .await
+            .unwrap();
+
+        let err_msg = refresh_rss_feed(1).await.unwrap_err();
+        assert_eq!(err_msg.error_code, ErrorCodes::E0048_RESPONSE_TOO_LARGE);
+        assert_eq!(
+            read_feed_error(1).unwrap().error_code,
+            ErrorCodes::E0048_RESPONSE_TOO_LARGE
+        );
+        assert!(read_feed_error(2).is_none());
+
+        let articles = read_articles_db();
+        assert!(!articles.iter().any(|r| r.rss_id == 1));
+        assert_eq!(articles.iter().filter(|r| r.rss_id == 2).count(), 5);
+    });
+}
+
+#[test]
+fn streamed_responses_are_given_up_once_larger_than_the_cap() {
+    let _test_lock = setup("max_response_size", "\"max_response_size_mb\": 1");
+
+    block_on(async {
+        // Without Content-Length, the size is only known while reading the body
+        let server = TestServer::start_streaming(Duration::ZERO, |path| match path {
+            "/large.xml" => Some(large_rss_document()),
+            _ => Some(numbered_rss_document("small", 5)),
+        })
+        .await;
+        add_feed("Test", "Large feed", &server.url("/large.xml"))
+            .await
+            .unwrap();
+        add_feed("Test", "Small feed", &server.url("/small.xml"))
+            .await
+            .unwrap();
+
+        let err_msg = refresh_rss_feed(1).await.unwrap_err();
+        assert_eq!(err_msg.error_code, ErrorCodes::E0048_RESPONSE_TOO_LARGE);
+
+        let articles = read_articles_db();
+        assert!(!articles.iter().any(|r| r.rss_id == 1));
+        assert_eq!(articles.iter().filter(|r| r.rss_id == 2).count(), 5);
+    });
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use byte_bite::opml::{export_opml, parse_opml, store_opml_feeds};
+use byte_bite::read_rss_db;
+use common::{fixture_path, setup};
+
+/// Lists the given feeds as "category|name"
+fn listed<I: IntoIterator<Item = (String, String)>>(feeds: I) -> Vec<String> {
+    feeds
+        .into_iter()
+        .map(|(category, name)| format!("{}|{}", category, name))
+        .collect()
+}
+
+const NESTED_FEEDS: [&str; 6] = [
+    "News|Headlines",
+    "News/World|Europe",
+    "News/World|Asia",
+    "News/Sports|Football",
+    "Technology/Rust|This Week in Rust",
+    "Imported|Unfiled",
+];
+
+#[test]
+fn imports_nested_outlines_as_category_paths() {
+    let _test_lock = setup("opml", "");
+
+    let import_report = store_opml_feeds(&fixture_path("nested.opml")).unwrap();
+    assert_eq!(import_report.imported.len(), 6);
+    assert!(import_report.duplicates.is_empty() && import_report.invalid.is_empty());
+
+    let rss_feeds = read_rss_db()
+        .into_iter()
+        .skip(1)
+        .map(|r| (r.category, r.name));
+    assert_eq!(listed(rss_feeds), NESTED_FEEDS);
+}
+
+#[test]
+fn exports_category_paths_as_nested_outlines() {
+    let _test_lock = setup("opml", "");
+    store_opml_feeds(&fixture_path("nested.opml")).unwrap();
+
+    let opml = export_opml();
+    assert!(opml.contains("<outline text=\"World\""));
+    let exported = parse_opml(&opml)
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.category, r.name));
+    assert_eq!(listed(exported), NESTED_FEEDS);
+}
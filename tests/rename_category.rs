@@ -0,0 +1,48 @@
+mod common;
+
+use byte_bite::{add_feed, read_rss_db, rename_category};
+use common::{block_on, setup};
+
+fn categories() -> Vec<String> {
+    read_rss_db()
+        .into_iter()
+        .skip(1)
+        .map(|r| r.category)
+        .collect()
+}
+
+fn add_feeds(feeds: &[(&str, &str)]) {
+    // Nothing listens on the discard port, so the refresh following each addition fails fast
+    for (category, name) in feeds {
+        let url = format!("http://127.0.0.1:9/{}.xml", name.to_lowercase());
+        block_on(add_feed(category, name, &url)).unwrap();
+    }
+}
+
+#[test]
+fn renames_the_category_of_all_feeds_sharing_it() {
+    let _test_lock = setup("rename_category", "");
+    add_feeds(&[
+        ("Tech", "Rust"),
+        ("News", "World"),
+        ("Tech", "Linux"),
+        ("Tech", "Python"),
+    ]);
+
+    assert_eq!(rename_category("Tech", "Technology"), 3);
+    assert_eq!(
+        categories(),
+        vec!["Technology", "News", "Technology", "Technology"]
+    );
+}
+
+#[test]
+fn renaming_to_an_empty_or_same_category_changes_nothing() {
+    let _test_lock = setup("rename_category", "");
+    add_feeds(&[("Tech", "Rust"), ("Tech", "Linux")]);
+
+    assert_eq!(rename_category("Tech", "  "), 0);
+    assert_eq!(rename_category("Tech", "Tech"), 0);
+    assert_eq!(rename_category("Unknown", "Technology"), 0);
+    assert_eq!(categories(), vec!["Tech", "Tech"]);
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use byte_bite::{add_feed, read_rss_db, update_rss_db};
+use common::{block_on, data_file, setup};
+use std::fs;
+use tui::widgets::ListState;
+
+// Nothing listens on the discard port, so the refresh following each addition fails fast
+const UNREACHABLE_URL: &str = "http://127.0.0.1:9/feed.xml";
+
+fn rss_ids() -> Vec<usize> {
+    read_rss_db().iter().map(|r| r.rss_id).collect()
+}
+
+#[test]
+fn rss_ids_of_deleted_feeds_are_not_reused() {
+    let _test_lock = setup("rss_ids", "");
+
+    for name in ["First", "Second", "Third"] {
+        block_on(add_feed("Test", name, UNREACHABLE_URL)).unwrap();
+    }
+    assert_eq!(rss_ids(), vec![0, 1, 2, 3]);
+
+    let mut rss_list_state = ListState::default();
+    rss_list_state.select(Some(3));
+    update_rss_db(&mut rss_list_state);
+    assert_eq!(rss_ids(), vec![0, 1, 2]);
+
+    block_on(add_feed("Test", "Fourth", UNREACHABLE_URL)).unwrap();
+    assert_eq!(rss_ids(), vec![0, 1, 2, 4]);
+}
+
+#[test]
+fn feeds_can_be_added_to_an_empty_list() {
+    let _test_lock = setup("rss_ids", "");
+    fs::write(data_file("rss_db.json"), "[]").unwrap();
+    fs::write(data_file("article_db.json"), "[]").unwrap();
+
+    block_on(add_feed("Test", "First", UNREACHABLE_URL)).unwrap();
+    assert_eq!(rss_ids(), vec![1]);
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use byte_bite::feed_layers::write_feed_layers;
+use byte_bite::{read_articles_db, read_rss_db, store_fetched_feeds, FetchedFeed, RSSFeed};
+use common::{data_file, numbered_rss_document, setup};
+use rss::Channel;
+use std::fs;
+use std::time::{Duration, Instant};
+
+const NUM_FEEDS: usize = 50;
+const ITEMS_PER_FEED: usize = 40;
+const STORED_ARTICLES: usize = 2_000;
+/// Items of each RSS feed already stored, among its first ones
+const STORED_ITEMS_PER_FEED: usize = 10;
+
+/// Stores `STORED_ARTICLES` articles, including the first `STORED_ITEMS_PER_FEED` items of
+/// each RSS feed, in the articles database
+fn seed_articles_db() {
+    let mut articles_list: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(data_file("article_db.json")).unwrap()).unwrap();
+    for n in 0..STORED_ARTICLES {
+        let rss_id = 1 + n % NUM_FEEDS;
+        let item = n / NUM_FEEDS;
+        let article_link = match item < STORED_ITEMS_PER_FEED {
+            true => format!("https://example.com/feed{}/{}", rss_id, item),
+            false => format!("https://example.com/older/{}", n),
+        };
+        articles_list.push(serde_json::json!({
+            "article_id": n + 1,
+            "rss_id": rss_id,
+            "title": format!("Stored article {}", n),
+            "summary": "",
+            "article_link": article_link,
+            "author": "",
+            "pub_date": "2023-01-01T12:00:00Z",
+            "created_at": "2023-01-01T12:00:00Z",
+            "read": true
+        }));
+    }
+    fs::write(
+        data_file("article_db.json"),
+        serde_json::to_string(&articles_list).unwrap(),
+    )
+    .unwrap();
+}
+
+/// Builds the fetched RSS feeds of the batch, `ITEMS_PER_FEED` items each
+fn fetched_feeds() -> Vec<FetchedFeed> {
+    (1..=NUM_FEEDS)
+        .map(|rss_id| {
+            let url = format!("https://example.com/feed{}.xml", rss_id);
+            let rss_xml = numbered_rss_document(&format!("feed{}", rss_id), ITEMS_PER_FEED);
+            FetchedFeed {
+                rss_feed: RSSFeed::new(rss_id, "Test", "Test feed", &url),
+                channel: Some(Channel::read_from(rss_xml.as_bytes()).unwrap()),
+                last_modified: None,
+            }
+        })
+        .collect()
+}
+
+/// Stores given RSS feeds into the seeded databases, returning the time taken and the number
+/// of new articles of each RSS feed
+fn store_batches(batches: Vec<Vec<FetchedFeed>>) -> (Duration, Vec<usize>) {
+    let started_at = Instant::now();
+    let new_articles: Vec<usize> = batches
+        .into_iter()
+        .flat_map(store_fetched_feeds)
+        .map(|(_, result)| result.unwrap())
+        .collect();
+    (started_at.elapsed(), new_articles)
+}
+
+#[test]
+fn stores_a_large_batch_faster_than_feed_by_feed() {
+    let _test_lock = setup("store_fetched_feeds", "");
+    seed_articles_db();
+    let mut rss_feed_list = read_rss_db();
+    rss_feed_list.extend(fetched_feeds().into_iter().map(|r| r.rss_feed));
+    write_feed_layers(&rss_feed_list);
+    let seeded_articles_db = fs::read_to_string(data_file("article_db.json")).unwrap();
+    let stored_articles =
+        1 + STORED_ARTICLES + NUM_FEEDS * (ITEMS_PER_FEED - STORED_ITEMS_PER_FEED);
+
+    // Before batching: the articles database is read and written once per RSS feed
+    let feed_by_feed = fetched_feeds().into_iter().map(|r| vec![r]).collect();
+    let (feed_by_feed_time, feed_by_feed_articles) = store_batches(feed_by_feed);
+    assert_eq!(read_articles_db().len(), stored_articles);
+
+    // After: read and written once for all RSS feeds
+    fs::write(data_file("article_db.json"), &seeded_articles_db).unwrap();
+    let (batch_time, batch_articles) = store_batches(vec![fetched_feeds()]);
+    assert_eq!(read_articles_db().len(), stored_articles);
+
+    assert_eq!(
+        batch_articles,
+        vec![ITEMS_PER_FEED - STORED_ITEMS_PER_FEED; NUM_FEEDS]
+    );
+    assert_eq!(batch_articles, feed_by_feed_articles);
+    // Each of the 50 rewrites of the database costs about as much as the whole batch, so a
+    // regression to a write per RSS feed fails by a wide margin
+    assert!(
+        batch_time * 5 < feed_by_feed_time,
+        "batch: {:?}, feed by feed: {:?}",
+        batch_time,
+        feed_by_feed_time
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use byte_bite::browser::mark_opened_articles_read;
+use byte_bite::{add_feed, mark_articles_unread, refresh_rss_feed, toggle_article_read};
+use common::{
+    block_on, is_read, numbered_rss_document, setup, store_articles, stored_article, TestServer,
+};
+use std::time::Duration;
+
+const CONFIG_ENTRIES: &str = "\"mark_read_on_open\": true";
+
+#[test]
+fn toggles_articles_read_both_ways() {
+    let _test_lock = setup("toggle_read", CONFIG_ENTRIES);
+    store_articles(&[stored_article(1, 0, "2024-01-01T12:00:00Z")]);
+    assert!(!is_read(1));
+
+    assert!(toggle_article_read(1));
+    assert!(is_read(1));
+    assert!(!toggle_article_read(1));
+    assert!(!is_read(1));
+
+    assert!(!toggle_article_read(9999));
+}
+
+#[test]
+fn marking_unread_after_opening_survives_a_refresh() {
+    let _test_lock = setup("toggle_read", CONFIG_ENTRIES);
+
+    block_on(async {
+        let server =
+            TestServer::start(Duration::ZERO, |_path| Some(numbered_rss_document("a", 2))).await;
+        add_feed("Test", "Test feed", &server.url("/feed.xml"))
+            .await
+            .unwrap();
+        // Article ids of the two articles of the feed, after the welcome article
+        let (first, second) = (1, 2);
+
+        mark_opened_articles_read(&[first, second]);
+        assert!(is_read(first) && is_read(second));
+
+        // U on the first article, mark unread on the second one
+        assert!(!toggle_article_read(first));
+        mark_articles_unread(&[second]);
+
+        assert_eq!(refresh_rss_feed(1).await.unwrap(), 0);
+        assert!(!is_read(first) && !is_read(second));
+    });
+}